@@ -1,8 +1,8 @@
 #!/usr/bin/env expect
 
 # Check for required arguments
-if {$argc != 4} {
-    send_user "Usage: $argv0 <user> <pass> <host> <command>\n"
+if {$argc < 4 || $argc > 5} {
+    send_user "Usage: $argv0 <user> <pass> <host> <command> \[timeout\]\n"
     exit 1
 }
 
@@ -12,8 +12,12 @@ set pass [lindex $argv 1]
 set host [lindex $argv 2]
 set command [lindex $argv 3]
 
-# Set timeout for the overall connection
-set timeout 30
+# Set timeout for the overall connection (seconds without output)
+if {$argc == 5} {
+    set timeout [lindex $argv 4]
+} else {
+    set timeout 30
+}
 log_user 1
 
 # Attempt counter
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+use crate::server::collectors::OsFamily;
+use crate::server::groups::records_in_group;
+use crate::server::inventory::{ all_records, render_export, ExportFormat, ExportRequest };
+
+const USAGE: &str = "Usage: it_management export [--format csv|json|xlsx] [--columns host,serial,...] \
+[--os macos|linux] [--group GROUP_ID] [--filter TEXT] [--pending-updates] [--output FILE]";
+
+// Handles command line subcommands. Returns the exit code when one ran, or
+// None to start the app as usual.
+pub fn run(args: &[String]) -> Option<i32> {
+    match args.first().map(String::as_str) {
+        Some("export") => Some(match export(&args[1..]) {
+            Ok(message) => {
+                eprintln!("{}", message);
+                0
+            }
+            Err(e) => {
+                eprintln!("{}\n{}", e, USAGE);
+                1
+            }
+        }),
+        _ => None,
+    }
+}
+
+pub fn parse_export_args(args: &[String]) -> Result<(ExportRequest, Option<PathBuf>), String> {
+    let mut request = ExportRequest::default();
+    let mut output = None;
+    let mut format_given = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().cloned().ok_or_else(|| format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--format" => {
+                request.format = value()?.parse()?;
+                format_given = true;
+            }
+            "--columns" => {
+                request.columns = value()?
+                    .split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect();
+            }
+            "--os" => {
+                request.filter.os_family = Some(match value()?.to_lowercase().as_str() {
+                    "macos" | "mac" => OsFamily::MacOs,
+                    "linux" => OsFamily::Linux,
+                    other => {
+                        return Err(format!("Unknown OS family: {}", other));
+                    }
+                });
+            }
+            "--group" => {
+                request.group = Some(value()?);
+            }
+            "--filter" => {
+                request.filter.text = Some(value()?);
+            }
+            "--pending-updates" => {
+                request.filter.pending_updates_only = true;
+            }
+            "--output" | "-o" => {
+                output = Some(PathBuf::from(value()?));
+            }
+            other => {
+                return Err(format!("Unknown option: {}", other));
+            }
+        }
+    }
+
+    // The extension picks the format when --format isn't given
+    if !format_given {
+        if let Some(format) = output
+            .as_ref()
+            .and_then(|path| path.extension())
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse::<ExportFormat>().ok())
+        {
+            request.format = format;
+        }
+    }
+    Ok((request, output))
+}
+
+fn export(args: &[String]) -> Result<String, String> {
+    let (request, output) = parse_export_args(args)?;
+    let records = records_in_group(all_records(), request.group.as_deref())?;
+    let file = render_export(&request, &records)?;
+    let path = output.unwrap_or_else(|| PathBuf::from(&file.filename));
+    std::fs::write(&path, &file.data).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(format!("Exported inventory to {}", path.display()))
+}
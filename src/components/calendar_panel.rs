@@ -0,0 +1,131 @@
+use chrono::Local;
+use dioxus::prelude::*;
+use crate::server::{ create_calendar_token, list_calendar_tokens, list_people, revoke_calendar_token };
+use crate::server::calendar::CalendarToken;
+
+fn feed_url(token: &CalendarToken, per_day: bool) -> String {
+    let grouping = if per_day { "&grouping=per_day" } else { "" };
+    format!("/api/calendar.ics?token={}{}", token.token, grouping)
+}
+
+// Per-person links to the ICS feed of upcoming license and warranty expirations
+#[component]
+pub fn CalendarPanel() -> Element {
+    let people = use_resource(|| async move { list_people().await.unwrap_or_default() });
+    let mut tokens = use_resource(|| async move { list_calendar_tokens().await.unwrap_or_default() });
+    let mut person_id = use_signal(String::new);
+    let mut per_day = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    // The only time the full link is shown
+    let mut created = use_signal(|| None::<CalendarToken>);
+
+    let create = move |_| {
+        let id = person_id();
+        spawn(async move {
+            match create_calendar_token(id).await {
+                Ok(token) => {
+                    error.set(None);
+                    created.set(Some(token));
+                    tokens.restart();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let people_list = people.read().clone().unwrap_or_default();
+    let person_name = |id: &str| {
+        people_list
+            .iter()
+            .find(|p| p.id == id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    rsx!(
+        details {
+            class: "mt-6 text-sm",
+            summary { class: "cursor-pointer font-semibold", "Calendar feed" }
+            p {
+                class: "mt-2 text-gray-500",
+                "Subscribe to a link in a calendar app to see upcoming license and warranty expirations, with reminders at the notice and warning thresholds."
+            }
+            div {
+                class: "mt-2 flex flex-wrap items-center gap-2",
+                select {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    onchange: move |evt: Event<FormData>| person_id.set(evt.value()),
+                    option { value: "", "Select person" }
+                    for person in people_list.iter() {
+                        option { key: "{person.id}", value: "{person.id}", {person.name.clone()} }
+                    }
+                }
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                    disabled: person_id.read().is_empty(),
+                    onclick: create,
+                    "Create link"
+                }
+                label {
+                    class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: per_day(),
+                        onchange: move |evt: Event<FormData>| per_day.set(evt.checked()),
+                    }
+                    "One event per day"
+                }
+                if let Some(e) = error.read().as_ref() {
+                    span { class: "text-red-500", {e.clone()} }
+                }
+            }
+            if let Some(token) = created.read().as_ref() {
+                div {
+                    class: "mt-2 rounded bg-yellow-50 px-3 py-2",
+                    div { class: "font-medium", {format!("Link for {}", person_name(&token.person_id))} }
+                    a { class: "text-blue-600 hover:underline break-all", href: feed_url(token, per_day()), {feed_url(token, per_day())} }
+                    div { class: "text-yellow-800", "Copy it now, it won't be shown again." }
+                }
+            }
+            ul {
+                class: "mt-2",
+                for token in tokens.read().iter().flatten() {
+                    li {
+                        key: "{token.id}",
+                        class: "py-1 flex flex-wrap items-center gap-2",
+                        span { class: "font-medium", {person_name(&token.person_id)} }
+                        span { class: "font-mono text-gray-500", {token.token.clone()} }
+                        span {
+                            class: "text-gray-500",
+                            match token.last_used_at {
+                                Some(t) => format!("last used {}", t.with_timezone(&Local).format("%Y-%m-%d %H:%M")),
+                                None => "never used".to_string(),
+                            }
+                        }
+                        button {
+                            class: "text-red-500 hover:underline",
+                            onclick: {
+                                let id = token.id.clone();
+                                move |_| {
+                                    let id = id.clone();
+                                    spawn(async move {
+                                        match revoke_calendar_token(id.clone()).await {
+                                            Ok(_) => {
+                                                if created.read().as_ref().is_some_and(|t| t.id == id) {
+                                                    created.set(None);
+                                                }
+                                                tokens.restart();
+                                            }
+                                            Err(e) => error.set(Some(e.to_string())),
+                                        }
+                                    });
+                                }
+                            },
+                            "Revoke"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
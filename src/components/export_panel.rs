@@ -0,0 +1,132 @@
+use std::collections::BTreeSet;
+use dioxus::prelude::*;
+use crate::server::{ export_inventory, get_export_columns };
+use crate::server::collectors::OsFamily;
+use crate::server::inventory::{ ExportFilter, ExportFormat, ExportRequest };
+use crate::utils::download_file;
+use super::group_select::GroupSelect;
+
+#[component]
+pub fn ExportPanel() -> Element {
+    let columns = use_resource(|| async move { get_export_columns().await.unwrap_or_default() });
+    let mut format = use_signal(ExportFormat::default);
+    let mut excluded = use_signal(BTreeSet::<String>::new);
+    let mut filter = use_signal(ExportFilter::default);
+    let mut group = use_signal(|| None::<String>);
+    let mut exporting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let all_columns = columns.read().clone().unwrap_or_default();
+
+    let export = move |_| {
+        let selected: Vec<String> = columns
+            .read()
+            .iter()
+            .flatten()
+            .filter(|c| !excluded.read().contains(&c.key))
+            .map(|c| c.key.clone())
+            .collect();
+        let request = ExportRequest {
+            format: format(),
+            columns: selected,
+            filter: filter.read().clone(),
+            group: group(),
+        };
+        spawn(async move {
+            exporting.set(true);
+            let result = match export_inventory(request).await {
+                Ok(file) => download_file(&file.filename, &file.content_type, &file.data),
+                Err(e) => Err(e.to_string()),
+            };
+            error.set(result.err());
+            exporting.set(false);
+        });
+    };
+
+    rsx!(
+        details {
+            class: "mb-4 text-sm",
+            summary { class: "cursor-pointer font-semibold", "Export" }
+            div {
+                class: "mt-2 flex flex-wrap items-center gap-4",
+                select {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    onchange: move |evt: Event<FormData>| {
+                        if let Ok(value) = evt.value().parse() {
+                            format.set(value);
+                        }
+                    },
+                    option { value: "csv", "CSV" }
+                    option { value: "json", "JSON" }
+                    option { value: "xlsx", "Excel (XLSX)" }
+                }
+                input {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    placeholder: "Filter host, title, serial, user",
+                    oninput: move |evt| filter.with_mut(|f| f.text = Some(evt.value())),
+                }
+                select {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    onchange: move |evt: Event<FormData>| {
+                        let os_family = match evt.value().as_str() {
+                            "macos" => Some(OsFamily::MacOs),
+                            "linux" => Some(OsFamily::Linux),
+                            _ => None,
+                        };
+                        filter.with_mut(|f| f.os_family = os_family);
+                    },
+                    option { value: "", "All OS" }
+                    option { value: "macos", "macOS" }
+                    option { value: "linux", "Linux" }
+                }
+                GroupSelect { on_change: move |value| group.set(value) }
+                label {
+                    class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        onchange: move |evt: Event<FormData>| {
+                            filter.with_mut(|f| f.pending_updates_only = evt.checked())
+                        },
+                    }
+                    "Pending updates only"
+                }
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600 transition duration-300 ease-in-out",
+                    disabled: *exporting.read(),
+                    onclick: export,
+                    if *exporting.read() { "Exporting..." } else { "Download" }
+                }
+                if let Some(e) = error.read().as_ref() {
+                    span { class: "text-red-500", {e.clone()} }
+                }
+            }
+            div {
+                class: "mt-2 flex flex-wrap gap-3",
+                for column in all_columns.iter() {
+                    label {
+                        key: "{column.key}",
+                        class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: !excluded.read().contains(&column.key),
+                            onchange: {
+                                let key = column.key.clone();
+                                move |evt: Event<FormData>| {
+                                    let checked = evt.checked();
+                                    excluded.with_mut(|keys| {
+                                        if checked {
+                                            keys.remove(&key);
+                                        } else {
+                                            keys.insert(key.clone());
+                                        }
+                                    });
+                                }
+                            },
+                        }
+                        {column.label.clone()}
+                    }
+                }
+            }
+        }
+    )
+}
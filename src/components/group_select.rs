@@ -0,0 +1,26 @@
+use dioxus::prelude::*;
+use crate::server::list_groups;
+
+// Picks a host group; an empty selection means all hosts
+#[component]
+pub fn GroupSelect(on_change: EventHandler<Option<String>>) -> Element {
+    let groups = use_resource(|| async move { list_groups().await.unwrap_or_default() });
+
+    rsx!(
+        select {
+            class: "rounded border border-gray-300 px-2 py-1",
+            onchange: move |evt: Event<FormData>| {
+                let value = evt.value();
+                on_change.call(if value.is_empty() { None } else { Some(value) });
+            },
+            option { value: "", "All hosts" }
+            for summary in groups.read().iter().flatten() {
+                option {
+                    key: "{summary.group.id}",
+                    value: "{summary.group.id}",
+                    {format!("{} ({})", summary.group.name, summary.members.len())}
+                }
+            }
+        }
+    )
+}
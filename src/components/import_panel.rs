@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+use crate::server::import_inventory;
+use crate::server::inventory::{ ExportFormat, InventoryImportSummary };
+
+#[component]
+pub fn ImportPanel(on_imported: EventHandler<()>) -> Element {
+    let mut result = use_signal(|| None::<Result<InventoryImportSummary, String>>);
+
+    let import_file = move |evt: Event<FormData>| async move {
+        let Some(files) = evt.files() else {
+            return;
+        };
+        for name in files.files() {
+            let format = if name.to_lowercase().ends_with(".json") {
+                ExportFormat::Json
+            } else {
+                ExportFormat::Csv
+            };
+            let Some(data) = files.read_file_to_string(&name).await else {
+                result.set(Some(Err(format!("Could not read {}", name))));
+                continue;
+            };
+            let summary = import_inventory(data, format).await.map_err(|e| e.to_string());
+            if summary.is_ok() {
+                on_imported.call(());
+            }
+            result.set(Some(summary));
+        }
+    };
+
+    rsx!(
+        details {
+            class: "mb-4 text-sm",
+            summary { class: "cursor-pointer font-semibold", "Import" }
+            div {
+                class: "mt-2 text-gray-500",
+                "CSV or JSON in the export format. Rows are matched by serial number; new machines are added as manual records."
+            }
+            input { class: "mt-2", r#type: "file", accept: ".csv,.json", onchange: import_file }
+            match &*result.read() {
+                Some(Ok(summary)) => rsx!(
+                    div {
+                        class: "mt-2",
+                        div { {format!("Imported: {} new, {} updated", summary.created, summary.updated)} }
+                        for error in summary.errors.iter() {
+                            div {
+                                class: "text-red-500",
+                                if error.line == 0 {
+                                    {error.message.clone()}
+                                } else {
+                                    {format!("Row {}: {}", error.line, error.message)}
+                                }
+                            }
+                        }
+                    }
+                ),
+                Some(Err(e)) => rsx!(div { class: "mt-2 text-red-500", {e.clone()} }),
+                None => rsx!(),
+            }
+        }
+    )
+}
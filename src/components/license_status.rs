@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use crate::server::license::LicenseStatus;
+
+pub fn status_label(status: LicenseStatus) -> &'static str {
+    match status {
+        LicenseStatus::Valid => "OK",
+        LicenseStatus::Notice => "NOTICE",
+        LicenseStatus::Warning => "WARNING",
+        LicenseStatus::Expired => "EXPIRED",
+        LicenseStatus::NotFound => "NOT FOUND",
+        LicenseStatus::Unreadable => "UNREADABLE",
+        LicenseStatus::ParseError => "PARSE ERROR",
+        LicenseStatus::HostUnreachable => "UNREACHABLE",
+    }
+}
+
+// e.g. "[WARNING] Expires 2025-03-01 (28 days remaining)"
+pub fn describe_status(status: LicenseStatus, expiry: Option<NaiveDate>, days_remaining: Option<i64>) -> String {
+    let label = status_label(status);
+    match (expiry, days_remaining) {
+        (Some(expiry), Some(days)) =>
+            match status {
+                LicenseStatus::Expired => format!("[{}] {} ({} days overdue)", label, expiry, days.abs()),
+                LicenseStatus::Valid => format!("[{}] Valid until {} ({} days remaining)", label, expiry, days),
+                _ => format!("[{}] Expires {} ({} days remaining)", label, expiry, days),
+            }
+        _ =>
+            match status {
+                LicenseStatus::NotFound => format!("[{}] License file not found", label),
+                LicenseStatus::Unreadable => format!("[{}] License file can't be read", label),
+                LicenseStatus::ParseError => format!("[{}] No valid expiry date", label),
+                LicenseStatus::HostUnreachable => format!("[{}] Host unreachable over SSH", label),
+                LicenseStatus::Valid => format!("[{}] Perpetual license", label),
+                _ => format!("[{}]", label),
+            }
+    }
+}
+
+// One colour per expiry band; a failed check is grey so an offline host
+// doesn't read as an expired license
+pub fn status_class(status: LicenseStatus) -> &'static str {
+    match status {
+        LicenseStatus::Valid => "text-green-600",
+        LicenseStatus::Notice => "text-yellow-600",
+        LicenseStatus::Warning => "text-orange-500",
+        LicenseStatus::Expired => "text-red-500",
+        LicenseStatus::NotFound |
+        LicenseStatus::Unreadable |
+        LicenseStatus::ParseError |
+        LicenseStatus::HostUnreachable => "text-gray-500",
+    }
+}
+
+#[component]
+pub fn LicenseStatusText(
+    status: LicenseStatus,
+    expiry: Option<NaiveDate>,
+    days_remaining: Option<i64>,
+    detail: Option<String>
+) -> Element {
+    let text = describe_status(status, expiry, days_remaining);
+    let title = detail.unwrap_or_default();
+
+    rsx!(
+        div { class: "text-sm {status_class(status)}", title: "{title}", {text} }
+    )
+}
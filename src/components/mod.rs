@@ -2,4 +2,9 @@
 //! They can be used to defined common UI elements like buttons, forms, and modals. In this template, we define a Hero
 //! component and an Echo component for fullstack apps to be used in our app.
 
-pub(crate) mod navbar;
\ No newline at end of file
+pub(crate) mod navbar;
+pub(crate) mod export_panel;
+pub(crate) mod import_panel;
+pub(crate) mod group_select;
+pub(crate) mod license_status;
+pub(crate) mod calendar_panel;
\ No newline at end of file
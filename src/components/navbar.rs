@@ -39,9 +39,63 @@ pub fn Navbar() -> Element {
                             } else {
                                 "text-gray-800 hover:text-primary transition-colors"
                             },
-                            to: Route::UserList,
+                            to: Route::UserList { q: String::new() },
                             "Users"
                         }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::People,
+                            "People"
+                        }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::Groups,
+                            "Groups"
+                        }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::Licenses,
+                            "Licenses"
+                        }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::Entitlements,
+                            "Entitlements"
+                        }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::Updates,
+                            "Updates"
+                        }
+                        Link {
+                            class: if theme.read().is_dark {
+                                "text-white hover:text-primary transition-colors"
+                            } else {
+                                "text-gray-800 hover:text-primary transition-colors"
+                            },
+                            to: Route::Assets,
+                            "Assets"
+                        }
                     }
                     button {
                         class: if theme.read().is_dark {
@@ -12,3 +12,125 @@ pub fn get_ssh_password() -> Result<String, std::env::VarError> {
 
     Ok(password)
 }
+
+// Default macOS administrator account used for every SSH login
+pub const DEFAULT_SSH_USER: &str = "ph-admin";
+
+pub fn get_ssh_user() -> String {
+    // Account to log in as over SSH, the same on every managed host
+    std::env::var("SSH_USER")
+        .ok()
+        .filter(|user| !user.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_SSH_USER.to_string())
+}
+
+pub fn get_data_dir() -> std::path::PathBuf {
+    // Directory holding persisted server state (inventory, history, ...)
+    std::env::var("DATA_DIR")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| std::path::PathBuf::from("data"))
+}
+
+pub fn get_low_disk_free_threshold() -> u64 {
+    // Machines with less free space than this on the boot volume get flagged
+    const DEFAULT_LOW_DISK_FREE_GB: u64 = 20;
+    let gigabytes = std::env::var("LOW_DISK_FREE_GB")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_LOW_DISK_FREE_GB);
+    gigabytes * 1_000_000_000
+}
+
+pub fn get_max_uptime_days() -> u64 {
+    // Machines that haven't rebooted for longer than this get flagged
+    const DEFAULT_MAX_UPTIME_DAYS: u64 = 30;
+    std::env::var("MAX_UPTIME_DAYS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MAX_UPTIME_DAYS)
+}
+
+pub fn get_collectors_config_path() -> std::path::PathBuf {
+    // JSON file declaring user-defined inventory collectors
+    std::env::var("COLLECTORS_CONFIG")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| std::path::PathBuf::from("collectors.json"))
+}
+
+pub fn get_license_rules_config_path() -> std::path::PathBuf {
+    // JSON file declaring which license files to check and how to read them
+    std::env::var("LICENSES_CONFIG")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| std::path::PathBuf::from("licenses.json"))
+}
+
+pub fn get_refresh_interval_minutes() -> u64 {
+    // Minutes between scheduled inventory refreshes, 0 disables the schedule
+    const DEFAULT_REFRESH_INTERVAL_MINUTES: u64 = 240;
+    std::env::var("REFRESH_INTERVAL_MINUTES")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_REFRESH_INTERVAL_MINUTES)
+}
+
+pub fn get_refresh_quiet_hours() -> Option<(u32, u32)> {
+    // Local hours during which scheduled refreshes don't run, e.g. "22-6"
+    let value = std::env::var("REFRESH_QUIET_HOURS").ok()?;
+    let (start, end) = value.trim().split_once('-')?;
+    let start = start.trim().parse::<u32>().ok().filter(|h| *h < 24)?;
+    let end = end.trim().parse::<u32>().ok().filter(|h| *h < 24)?;
+    Some((start, end))
+}
+
+pub fn get_refresh_max_concurrency() -> usize {
+    const DEFAULT_REFRESH_MAX_CONCURRENCY: usize = 4;
+    std::env::var("REFRESH_MAX_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|n| *n > 0)
+        .unwrap_or(DEFAULT_REFRESH_MAX_CONCURRENCY)
+}
+
+pub fn get_license_check_interval_minutes() -> u64 {
+    // Minutes between scheduled fleet-wide license checks, 0 disables the schedule
+    const DEFAULT_LICENSE_CHECK_INTERVAL_MINUTES: u64 = 24 * 60;
+    std::env::var("LICENSE_CHECK_INTERVAL_MINUTES")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_LICENSE_CHECK_INTERVAL_MINUTES)
+}
+
+pub fn get_notify_webhook_url() -> Option<String> {
+    // License expiry notifications are POSTed here as JSON
+    std::env::var("NOTIFY_WEBHOOK_URL")
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+pub fn get_smtp_server() -> Option<(String, u16)> {
+    // Plain SMTP relay for notification emails, e.g. "mail.local:25"
+    let value = std::env::var("SMTP_SERVER").ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    match value.rsplit_once(':') {
+        Some((host, port)) => Some((host.to_string(), port.parse::<u16>().ok()?)),
+        None => Some((value.to_string(), 25)),
+    }
+}
+
+pub fn get_notify_email_from() -> String {
+    std::env::var("NOTIFY_EMAIL_FROM").unwrap_or_else(|_| "it-management@localhost".to_string())
+}
+
+pub fn get_notify_email_to() -> Vec<String> {
+    // Comma-separated recipients of notification emails
+    std::env::var("NOTIFY_EMAIL_TO")
+        .unwrap_or_default()
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
@@ -2,6 +2,7 @@ mod views;
 mod components;
 mod utils;
 pub mod server;
+pub mod cli;
 mod routes;
 mod configs;
 mod tests;
@@ -15,6 +15,13 @@ fn main() {
             std::env::set_var("RUST_LOG", "info");
         }
         env_logger::init();
+
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        if let Some(code) = it_management::cli::run(&args) {
+            std::process::exit(code);
+        }
+        it_management::server::inventory::ensure_scheduler_started();
+        it_management::server::license::ensure_license_scheduler_started();
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use crate::views::{ Home, User, UserList };
+use crate::views::{ Assets, Entitlements, Groups, Home, LicenseDeploy, LicenseHost, Licenses, People, Updates, User, UserList };
 use crate::components::navbar::Navbar;
 
 #[derive(Clone, Routable, Debug, PartialEq)]
@@ -8,11 +8,31 @@ pub enum Route {
     #[layout(Navbar)]
     #[route("/")]
     Home,
-    #[route("/user")]
-    UserList,
+    #[route("/user?:q")]
+    UserList {
+        q: String,
+    },
     #[route("/user/:id")] User {
         id: String,
     },
+    #[route("/updates")]
+    Updates,
+    #[route("/assets")]
+    Assets,
+    #[route("/people")]
+    People,
+    #[route("/groups")]
+    Groups,
+    #[route("/licenses")]
+    Licenses,
+    #[route("/licenses/deploy")]
+    LicenseDeploy,
+    #[route("/licenses/host/:host")]
+    LicenseHost {
+        host: String,
+    },
+    #[route("/entitlements")]
+    Entitlements,
 }
 
 //192.168.10.179
@@ -0,0 +1,193 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use chrono::{ DateTime, Days, Local, NaiveDate, Utc };
+use super::tokens::authorize;
+use super::types::{ ExpiryEvent, FeedGrouping };
+use crate::server::inventory::{ all_assets, all_records, AssetMetadata, InventoryRecord };
+use crate::server::license::{ dashboard_rows, latest_results, load_license_rules, ExpiryThresholds, LicenseRow, LicenseRule };
+
+const PRODUCT_ID: &str = "-//it-management//Expiry Calendar//EN";
+const UID_DOMAIN: &str = "it-management";
+// RFC 5545 lines are folded after 75 octets
+const MAX_LINE_OCTETS: usize = 75;
+
+// Keeps UIDs stable and free of characters calendar clients choke on
+fn uid_part(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+        .collect()
+}
+
+// Upcoming license expirations from the latest checks, with the reminder
+// thresholds of the rule that found each license
+pub fn license_events(rows: &[LicenseRow], rules: &[LicenseRule], today: &NaiveDate) -> Vec<ExpiryEvent> {
+    let mut seen = BTreeSet::new();
+    rows.iter()
+        .filter_map(|row| {
+            let expiry = row.expiry.filter(|expiry| expiry >= today)?;
+            let mut uid = format!(
+                "license-{}-{}-{}",
+                uid_part(&row.host),
+                uid_part(&row.product),
+                uid_part(row.path.as_deref().unwrap_or_default())
+            );
+            // Further licenses in the same file are told apart by their line
+            if !seen.insert(uid.clone()) {
+                uid.push_str(&format!("-{}", row.line.unwrap_or_default()));
+            }
+            let thresholds = rules
+                .iter()
+                .find(|rule| rule.product == row.product)
+                .map(|rule| rule.thresholds)
+                .unwrap_or_default();
+            let mut description = vec![format!("Host: {}", row.host), format!("Product: {}", row.product)];
+            if let Some(path) = &row.path {
+                description.push(format!("File: {}", path));
+            }
+            Some(ExpiryEvent {
+                uid: format!("{}@{}", uid, UID_DOMAIN),
+                date: expiry,
+                summary: format!("{} license expires on {}", row.product, row.host),
+                description: description.join("\n"),
+                thresholds,
+            })
+        })
+        .collect()
+}
+
+// Upcoming warranty and AppleCare expirations, named after the host with
+// the asset's serial number when it is in the inventory
+pub fn warranty_events(assets: &[AssetMetadata], records: &[InventoryRecord], today: &NaiveDate) -> Vec<ExpiryEvent> {
+    assets
+        .iter()
+        .flat_map(|asset| {
+            let host = records
+                .iter()
+                .find(|r| !asset.serial.is_empty() && r.info.serial == asset.serial)
+                .map(|r| r.host.clone());
+            [("Warranty", asset.warranty_expiry), ("AppleCare", asset.applecare_expiry)]
+                .into_iter()
+                .filter_map(move |(coverage, expiry)| {
+                    let expiry = expiry.filter(|expiry| expiry >= today)?;
+                    let target = host.clone().unwrap_or_else(|| format!("serial {}", asset.serial));
+                    let mut description = vec![format!("Serial: {}", asset.serial)];
+                    if let Some(host) = &host {
+                        description.push(format!("Host: {}", host));
+                    }
+                    if let Some(supplier) = &asset.supplier {
+                        description.push(format!("Supplier: {}", supplier));
+                    }
+                    Some(ExpiryEvent {
+                        uid: format!("{}-{}@{}", coverage.to_lowercase(), uid_part(&asset.serial), UID_DOMAIN),
+                        date: expiry,
+                        summary: format!("{} expires on {}", coverage, target),
+                        description: description.join("\n"),
+                        thresholds: ExpiryThresholds::default(),
+                    })
+                })
+        })
+        .collect()
+}
+
+// Folds events into one per day. Reminders use the longest thresholds of
+// the day so nothing is announced later than on its own.
+pub fn group_by_day(events: Vec<ExpiryEvent>) -> Vec<ExpiryEvent> {
+    let mut days: BTreeMap<NaiveDate, Vec<ExpiryEvent>> = BTreeMap::new();
+    for event in events {
+        days.entry(event.date).or_default().push(event);
+    }
+    days.into_iter()
+        .map(|(date, mut events)| {
+            if events.len() == 1 {
+                let mut event = events.remove(0);
+                event.uid = format!("day-{}@{}", date.format("%Y%m%d"), UID_DOMAIN);
+                return event;
+            }
+            ExpiryEvent {
+                uid: format!("day-{}@{}", date.format("%Y%m%d"), UID_DOMAIN),
+                date,
+                summary: format!("{} expirations", events.len()),
+                description: events
+                    .iter()
+                    .map(|e| format!("- {}", e.summary))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                thresholds: ExpiryThresholds {
+                    warning_days: events.iter().map(|e| e.thresholds.warning_days).max().unwrap_or_default(),
+                    notice_days: events.iter().map(|e| e.thresholds.notice_days).max().unwrap_or_default(),
+                },
+            }
+        })
+        .collect()
+}
+
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// Appends a content line, folding it without splitting a UTF-8 character
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space counts towards the folded line
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+pub fn render_calendar(events: &[ExpiryEvent], now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODUCT_ID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(&mut out, "X-WR-CALNAME:License and warranty expirations");
+    for event in events {
+        let next_day = event.date.checked_add_days(Days::new(1)).unwrap_or(event.date);
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", event.uid));
+        push_line(&mut out, &format!("DTSTAMP:{}", stamp));
+        push_line(&mut out, &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")));
+        push_line(&mut out, &format!("DTEND;VALUE=DATE:{}", next_day.format("%Y%m%d")));
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(&event.summary)));
+        push_line(&mut out, &format!("DESCRIPTION:{}", escape_text(&event.description)));
+        push_line(&mut out, "TRANSP:TRANSPARENT");
+        let mut reminders = vec![event.thresholds.notice_days, event.thresholds.warning_days];
+        reminders.dedup();
+        for days in reminders.into_iter().filter(|days| *days > 0) {
+            push_line(&mut out, "BEGIN:VALARM");
+            push_line(&mut out, "ACTION:DISPLAY");
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape_text(&event.summary)));
+            push_line(&mut out, &format!("TRIGGER:-P{}D", days));
+            push_line(&mut out, "END:VALARM");
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+// The ICS document served to a calendar subscription
+pub fn build_feed(token: &str, grouping: FeedGrouping) -> Result<String, String> {
+    authorize(token)?;
+    let today = Local::now().date_naive();
+    let rows = dashboard_rows(&latest_results());
+    let mut events = license_events(&rows, &load_license_rules(), &today);
+    events.extend(warranty_events(&all_assets(), &all_records(), &today));
+    events.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.uid.cmp(&b.uid)));
+    if grouping == FeedGrouping::PerDay {
+        events = group_by_day(events);
+    }
+    Ok(render_calendar(&events, Utc::now()))
+}
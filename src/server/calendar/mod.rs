@@ -0,0 +1,8 @@
+pub mod types;
+pub mod tokens;
+pub mod feed;
+
+// Re-export commonly used items
+pub use types::{ CalendarToken, ExpiryEvent, FeedGrouping };
+pub use tokens::{ authorize, create_token, find_token, list_tokens, mask, remove_token, revoke_token };
+pub use feed::{ build_feed, group_by_day, license_events, render_calendar, warranty_events };
@@ -0,0 +1,125 @@
+use std::fs::File;
+use std::io::Read;
+use std::sync::Mutex;
+use chrono::Utc;
+use super::types::CalendarToken;
+use crate::server::people::get_person;
+use crate::server::people::store::make_id;
+use crate::server::store;
+
+const CALENDAR_TOKENS_FILE: &str = "calendar_tokens.json";
+// Feed polls within this long of the recorded use don't rewrite the file
+const LAST_USED_INTERVAL_MINUTES: i64 = 60;
+
+lazy_static::lazy_static! {
+    static ref TOKENS: Mutex<Vec<CalendarToken>> = Mutex::new(with_ids(store::load(CALENDAR_TOKENS_FILE)));
+}
+
+// Tokens created before they had ids get one from their person, in file
+// order, so the id stays the same until the file is next saved
+fn with_ids(mut tokens: Vec<CalendarToken>) -> Vec<CalendarToken> {
+    for i in 0..tokens.len() {
+        if tokens[i].id.is_empty() {
+            let taken: Vec<&str> = tokens.iter().map(|t| t.id.as_str()).collect();
+            tokens[i].id = make_id(&tokens[i].person_id, &taken);
+        }
+    }
+    tokens
+}
+
+// Only the last characters, enough to tell a person's links apart
+pub fn mask(token: &CalendarToken) -> CalendarToken {
+    let start = token.token.len().saturating_sub(4);
+    CalendarToken { token: format!("…{}", &token.token[start..]), ..token.clone() }
+}
+
+// 192 random bits, hex encoded so the token can go straight into a URL
+fn generate_token() -> Result<String, String> {
+    let mut bytes = [0u8; 24];
+    File::open("/dev/urandom")
+        .and_then(|mut f| f.read_exact(&mut bytes))
+        .map_err(|e| format!("Failed to generate token: {}", e))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+// Masked; the full token is only returned when it's created
+pub fn list_tokens() -> Result<Vec<CalendarToken>, String> {
+    Ok(TOKENS.lock().map_err(|e| e.to_string())?.iter().map(mask).collect())
+}
+
+pub fn create_token(person_id: &str) -> Result<CalendarToken, String> {
+    if get_person(person_id)?.is_none() {
+        return Err(format!("Unknown person: {}", person_id));
+    }
+    let mut tokens = TOKENS.lock().map_err(|e| e.to_string())?;
+    let taken: Vec<&str> = tokens.iter().map(|t| t.id.as_str()).collect();
+    let token = CalendarToken {
+        id: make_id(person_id, &taken),
+        token: generate_token()?,
+        person_id: person_id.to_string(),
+        created_at: Utc::now(),
+        last_used_at: None,
+    };
+    let mut updated = tokens.clone();
+    updated.push(token.clone());
+    store::save(CALENDAR_TOKENS_FILE, &updated)?;
+    *tokens = updated;
+    Ok(token)
+}
+
+pub fn remove_token(tokens: &mut Vec<CalendarToken>, id: &str) -> Result<(), String> {
+    let before = tokens.len();
+    tokens.retain(|t| t.id != id);
+    if tokens.len() == before {
+        return Err("Unknown calendar token".to_string());
+    }
+    Ok(())
+}
+
+pub fn revoke_token(id: &str) -> Result<(), String> {
+    let mut tokens = TOKENS.lock().map_err(|e| e.to_string())?;
+    let mut updated = tokens.clone();
+    remove_token(&mut updated, id)?;
+    store::save(CALENDAR_TOKENS_FILE, &updated)?;
+    *tokens = updated;
+    Ok(())
+}
+
+// The entry for a presented token. Revoked tokens are gone from the list, and
+// tokens of deleted people stop working.
+pub fn find_token<'a, F>(tokens: &'a [CalendarToken], token: &str, person_exists: F) -> Result<&'a CalendarToken, String>
+    where F: Fn(&str) -> Result<bool, String>
+{
+    let entry = tokens
+        .iter()
+        .find(|t| !token.is_empty() && t.token == token)
+        .ok_or("Invalid calendar token")?;
+    if !person_exists(&entry.person_id)? {
+        return Err("Invalid calendar token".to_string());
+    }
+    Ok(entry)
+}
+
+// Looks up the token and records its use. Recording is best effort: a feed
+// poll never fails because the file couldn't be written.
+pub fn authorize(token: &str) -> Result<CalendarToken, String> {
+    let mut tokens = TOKENS.lock().map_err(|e| e.to_string())?;
+    let entry = find_token(&tokens, token, |id| Ok(get_person(id)?.is_some()))?.clone();
+
+    let now = Utc::now();
+    let recent = entry.last_used_at
+        .is_some_and(|at| now - at < chrono::Duration::minutes(LAST_USED_INTERVAL_MINUTES));
+    if !recent {
+        let mut updated = tokens.clone();
+        if let Some(stored) = updated.iter_mut().find(|t| t.id == entry.id) {
+            stored.last_used_at = Some(now);
+        }
+        match store::save(CALENDAR_TOKENS_FILE, &updated) {
+            Ok(()) => {
+                *tokens = updated;
+            }
+            Err(e) => log::warn!("Failed to record calendar token use: {}", e),
+        }
+    }
+    Ok(entry)
+}
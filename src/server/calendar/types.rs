@@ -0,0 +1,36 @@
+use chrono::{ DateTime, NaiveDate, Utc };
+use serde::{ Serialize, Deserialize };
+use crate::server::license::ExpiryThresholds;
+
+// Grants one person read access to the expiry calendar feed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CalendarToken {
+    // Names the token for revoking, so listings never need the token itself
+    #[serde(default)]
+    pub id: String,
+    pub token: String,
+    pub person_id: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedGrouping {
+    // One event per product per host
+    #[default]
+    PerLicense,
+    // One event per day listing everything that expires on it
+    PerDay,
+}
+
+// An all-day calendar event for something that expires
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExpiryEvent {
+    pub uid: String,
+    pub date: NaiveDate,
+    pub summary: String,
+    pub description: String,
+    // Reminders fire this many days before the event
+    pub thresholds: ExpiryThresholds,
+}
@@ -0,0 +1,123 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct UserAccount {
+    pub name: String,
+    pub uid: Option<u32>,
+    pub real_name: String,
+    pub home_directory: String,
+    pub is_admin: bool,
+    pub last_login: Option<String>,
+    pub hidden: bool,
+    pub disabled: bool,
+    pub secure_token: Option<bool>,
+}
+
+pub fn get_user_accounts_command() -> &'static str {
+    r#"for u in $(dscl . list /Users | grep -v '^_' | grep -v 'daemon' | grep -v 'nobody'); do
+         echo "=== User: $u ===";
+         dscl . -read "/Users/$u" UniqueID RealName NFSHomeDirectory IsHidden AuthenticationAuthority 2>/dev/null;
+         sysadminctl -secureTokenStatus "$u" 2>&1;
+         echo "LastLogin: $(last -1 "$u" | head -1)";
+       done;
+       echo '=== Admins ===';
+       dscl . -read /Groups/admin GroupMembership"#
+}
+
+pub fn parse_user_accounts(output: &str) -> Vec<UserAccount> {
+    let (users_output, admins_output) = output.split_once("=== Admins ===").unwrap_or((output, ""));
+    let admins: Vec<&str> = admins_output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("GroupMembership:"))
+        .map(|members| members.split_whitespace().collect())
+        .unwrap_or_default();
+
+    let mut accounts: Vec<UserAccount> = Vec::new();
+    let mut continued_key: Option<String> = None;
+
+    for line in users_output.lines() {
+        let trimmed_line = line.trim();
+        if let Some(name) = trimmed_line
+            .strip_prefix("=== User: ")
+            .and_then(|rest| rest.strip_suffix(" ==="))
+        {
+            accounts.push(UserAccount {
+                name: name.to_string(),
+                is_admin: admins.contains(&name),
+                ..Default::default()
+            });
+            continued_key = None;
+            continue;
+        }
+
+        let Some(account) = accounts.last_mut() else {
+            continue;
+        };
+
+        // dscl wraps long values onto an indented line after "Key:"
+        if line.starts_with(' ') && !trimmed_line.is_empty() {
+            if let Some(key) = continued_key.take() {
+                apply_attribute(account, &key, trimmed_line);
+            }
+            continue;
+        }
+
+        if trimmed_line.contains("Secure token is") {
+            account.secure_token = Some(trimmed_line.contains("ENABLED"));
+        } else if let Some(value) = trimmed_line.strip_prefix("LastLogin:") {
+            account.last_login = parse_last_login(value);
+        } else if let Some((key, value)) = trimmed_line.split_once(':') {
+            let value = value.trim();
+            if value.is_empty() {
+                continued_key = Some(key.to_string());
+            } else {
+                apply_attribute(account, key, value);
+            }
+        }
+    }
+
+    for account in &mut accounts {
+        // Accounts below 500 are system accounts hidden from the login window
+        if account.uid.is_some_and(|uid| uid < 500) {
+            account.hidden = true;
+        }
+    }
+
+    accounts
+}
+
+fn apply_attribute(account: &mut UserAccount, key: &str, value: &str) {
+    match key {
+        "UniqueID" => {
+            account.uid = value.parse().ok();
+        }
+        "RealName" => {
+            account.real_name = value.to_string();
+        }
+        "NFSHomeDirectory" => {
+            account.home_directory = value.to_string();
+        }
+        "IsHidden" => {
+            account.hidden = value == "1" || value.eq_ignore_ascii_case("yes");
+        }
+        "AuthenticationAuthority" => {
+            account.disabled = value.contains(";DisabledUser;");
+        }
+        _ => {}
+    }
+}
+
+// `last -1 <user>` prints the remote host between the tty and the date for
+// SSH sessions, so locate the date by its weekday instead of by column:
+//   fluke     console                   Mon May 13 09:12   still logged in
+//   ph-admin  ttys000  192.168.10.179   Sun May 12 18:40 - 18:52  (00:11)
+fn parse_last_login(line: &str) -> Option<String> {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    let line = line.trim();
+    if line.starts_with("wtmp begins") {
+        return None;
+    }
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let start = parts.iter().skip(2).position(|p| WEEKDAYS.contains(p))? + 2;
+    parts.get(start..start + 4).map(|date| date.join(" "))
+}
@@ -0,0 +1,164 @@
+use serde::{ Serialize, Deserialize };
+
+// Product name of the Comet plugin, as used by the default license rule
+pub const COMET_PRODUCT: &str = "priint:comet";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AdobeApp {
+    // Bundle name, e.g. "Adobe InDesign 2024"
+    pub name: String,
+    pub version: String,
+    pub bundle_id: String,
+    pub path: String,
+}
+
+impl AdobeApp {
+    // The name without the release year, so "Adobe InDesign 2024" and
+    // "Adobe InDesign 2025" count as the same product
+    pub fn product(&self) -> String {
+        let mut words: Vec<&str> = self.name.split_whitespace().collect();
+        while words.last().is_some_and(|w| *w == "CC" || (w.len() == 4 && w.chars().all(|c| c.is_ascii_digit()))) {
+            words.pop();
+        }
+        words.join(" ")
+    }
+}
+
+// One priint:comet installation inside an InDesign `Plug-Ins` directory
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CometPlugin {
+    pub version: String,
+    // InDesign folder the plugin belongs to, e.g. "Adobe InDesign 2024"
+    pub indesign: String,
+    pub path: String,
+}
+
+// A product in Adobe's install records (`/Library/Application Support/Adobe/Uninstall`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AdobeInstall {
+    pub sap_code: String,
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CreativeCloudUser {
+    // Local account the Creative Cloud app runs under
+    pub account: String,
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AdobeInfo {
+    pub apps: Vec<AdobeApp>,
+    pub comet_plugins: Vec<CometPlugin>,
+    pub installs: Vec<AdobeInstall>,
+    pub cc_users: Vec<CreativeCloudUser>,
+}
+
+impl AdobeInfo {
+    // Product names found on the host, comparable with license and entitlement products
+    pub fn installed_products(&self) -> Vec<String> {
+        let mut products: Vec<String> = self.apps.iter().map(AdobeApp::product).collect();
+        if !self.comet_plugins.is_empty() {
+            products.push(COMET_PRODUCT.to_string());
+        }
+        products.sort();
+        products.dedup();
+        products
+    }
+}
+
+// Prints one tab-separated line per finding:
+//   APP     <bundle path> <name> <version> <bundle id>
+//   PLUGIN  <plugin directory> <version>
+//   INSTALL <SAP code> <version> <product name>
+//   CCUSER  <local account> <Adobe ID email>
+// The Adobe ID comes from the Creative Cloud sign-in database of each account.
+pub fn get_adobe_command() -> &'static str {
+    r#"plist() { /usr/libexec/PlistBuddy -c "Print :$1" "$2" 2>/dev/null; }
+       for app in /Applications/Adobe*.app /Applications/Adobe*/Adobe*.app; do
+           [ -d "$app" ] || continue
+           info="$app/Contents/Info.plist"
+           printf 'APP\t%s\t%s\t%s\t%s\n' "$app" "$(plist CFBundleName "$info")" \
+               "$(plist CFBundleShortVersionString "$info")" "$(plist CFBundleIdentifier "$info")"
+       done
+       for dir in /Applications/Adobe\ InDesign*/Plug-Ins/priint.comet*; do
+           [ -d "$dir" ] || continue
+           version=""
+           for plugin in "$dir"/*.InDesignPlugin; do
+               version=$(plist CFBundleShortVersionString "$plugin/Contents/Info.plist")
+               [ -n "$version" ] && break
+           done
+           printf 'PLUGIN\t%s\t%s\n' "$dir" "$version"
+       done
+       for record in "/Library/Application Support/Adobe/Uninstall/"*.adbarg; do
+           [ -f "$record" ] || continue
+           arg() { grep -m1 "^--$1=" "$record" | cut -d= -f2-; }
+           printf 'INSTALL\t%s\t%s\t%s\n' "$(arg sapCode)" "$(arg productVersion)" "$(arg productName)"
+       done
+       for home in /Users/*; do
+           db="$home/Library/Application Support/Adobe/OOBE/opm.db"
+           [ -f "$db" ] || continue
+           email=$(grep -aoE '[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}' "$db" | head -1)
+           [ -n "$email" ] && printf 'CCUSER\t%s\t%s\n' "${home##*/}" "$email"
+       done
+       true"#
+}
+
+fn file_name(path: &str) -> &str {
+    path.trim_end_matches('/').rsplit('/').next().unwrap_or(path)
+}
+
+// "priint.comet 4.3.1 R31245" -> "4.3.1", for plugins without a readable Info.plist
+fn version_from_dir(dir: &str) -> String {
+    file_name(dir)
+        .split_whitespace()
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+        .unwrap_or_default()
+        .to_string()
+}
+
+pub fn parse_adobe_info(output: &str) -> AdobeInfo {
+    let mut adobe = AdobeInfo::default();
+
+    for line in output.lines() {
+        let fields: Vec<&str> = line.trim_end_matches('\r').split('\t').map(str::trim).collect();
+        match fields.as_slice() {
+            ["APP", path, name, version, bundle_id] => {
+                let name = if name.is_empty() { file_name(path).trim_end_matches(".app") } else { name };
+                adobe.apps.push(AdobeApp {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    bundle_id: bundle_id.to_string(),
+                    path: path.to_string(),
+                });
+            }
+            ["PLUGIN", path, version] => {
+                // <InDesign>/Plug-Ins/<plugin>
+                let indesign = path.rsplitn(3, '/').nth(2).map(file_name).unwrap_or_default();
+                adobe.comet_plugins.push(CometPlugin {
+                    version: if version.is_empty() { version_from_dir(path) } else { version.to_string() },
+                    indesign: indesign.to_string(),
+                    path: path.to_string(),
+                });
+            }
+            ["INSTALL", sap_code, version, name] if !sap_code.is_empty() => {
+                adobe.installs.push(AdobeInstall {
+                    sap_code: sap_code.to_string(),
+                    name: name.to_string(),
+                    version: version.to_string(),
+                });
+            }
+            ["CCUSER", account, email] => {
+                adobe.cc_users.push(CreativeCloudUser {
+                    account: account.to_string(),
+                    email: email.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    adobe
+}
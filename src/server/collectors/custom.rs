@@ -0,0 +1,111 @@
+use std::fs;
+use regex::Regex;
+use serde::{ Serialize, Deserialize };
+use serde_json::Value;
+use super::os::OsFamily;
+use super::output::extract_json;
+use crate::configs::get_collectors_config_path;
+
+const DEFAULT_CUSTOM_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Extraction {
+    // First capture group, or the whole match when the pattern has none
+    Regex(String),
+    // Dotted path into the JSON output, e.g. `$.SPHardwareDataType[0].boot_rom_version`
+    JsonPath(String),
+}
+
+// A user-defined collector from the collectors config file:
+//   { "field": "filevault", "label": "FileVault", "command": "fdesetup status",
+//     "os": ["MacOs"], "extract": { "regex": "FileVault is (On|Off)" } }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomCollector {
+    pub field: String,
+    #[serde(default)]
+    pub label: String,
+    pub command: String,
+    #[serde(default)]
+    pub os: Vec<OsFamily>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    pub extract: Extraction,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CustomFieldColumn {
+    pub field: String,
+    pub label: String,
+}
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_CUSTOM_TIMEOUT_SECS
+}
+
+impl CustomCollector {
+    pub fn column(&self) -> CustomFieldColumn {
+        CustomFieldColumn {
+            field: self.field.clone(),
+            label: if self.label.is_empty() { self.field.clone() } else { self.label.clone() },
+        }
+    }
+}
+
+pub fn load_custom_collectors() -> Vec<CustomCollector> {
+    let path = get_collectors_config_path();
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::error!("Invalid collectors config {}: {}", path.display(), e);
+        Vec::new()
+    })
+}
+
+pub fn extract_value(extraction: &Extraction, output: &str) -> Option<String> {
+    match extraction {
+        Extraction::Regex(pattern) => {
+            let re = Regex::new(&format!("(?m){}", pattern))
+                .map_err(|e| log::error!("Invalid collector regex '{}': {}", pattern, e))
+                .ok()?;
+            let captures = re.captures(output)?;
+            captures
+                .get(1)
+                .or_else(|| captures.get(0))
+                .map(|m| m.as_str().trim().to_string())
+        }
+        Extraction::JsonPath(path) => {
+            let json = extract_json(output)?;
+            let value = json_path_lookup(&json, path)?;
+            match value {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            }
+        }
+    }
+}
+
+fn json_path_lookup<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.trim().trim_start_matches('$').trim_start_matches('.');
+    let mut current = json;
+
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        // `items[0][1]` -> key "items", then indices 0 and 1
+        let mut parts = segment.split('[');
+        let key = parts.next().unwrap_or_default();
+        if !key.is_empty() {
+            current = match current {
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => current.get(key)?,
+            };
+        }
+        for index in parts {
+            let index = index.strip_suffix(']')?.parse::<usize>().ok()?;
+            current = current.get(index)?;
+        }
+    }
+
+    Some(current)
+}
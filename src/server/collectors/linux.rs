@@ -0,0 +1,277 @@
+use log::{ info, warn };
+use serde_json::Value;
+use super::accounts::UserAccount;
+use super::network::{ NetworkInterface, primary_addresses };
+use super::output::extract_json;
+use super::storage::{ DiskType, StorageDetails, VolumeInfo, format_bytes };
+use crate::server::resolve_computer::ComputerInfo;
+
+// Linux counterparts of the macOS collectors. Each parser fills the same
+// `ComputerInfo` fields as its macOS equivalent.
+
+pub fn get_hardware_command() -> &'static str {
+    r#"lscpu &&
+       echo '=== Memory ===' &&
+       cat /proc/meminfo &&
+       echo '=== DMI ===' &&
+       echo "Product: $(cat /sys/class/dmi/id/product_name 2>/dev/null)" &&
+       echo "Serial: $(cat /sys/class/dmi/id/product_serial 2>/dev/null)""#
+}
+
+pub fn get_system_command() -> &'static str {
+    r#"echo "Hostname: $(hostname)" &&
+       echo "User: $(who | awk 'NR==1 {print $1}')" &&
+       cat /etc/os-release"#
+}
+
+pub fn get_network_command() -> &'static str {
+    "ip -j addr"
+}
+
+pub fn get_storage_command() -> &'static str {
+    "lsblk -J -b -o NAME,SIZE,TYPE,MOUNTPOINT,ROTA,MODEL,FSAVAIL,FSUSED"
+}
+
+pub fn get_users_command() -> &'static str {
+    r#"getent passwd &&
+       echo '=== Admins ===' &&
+       getent group sudo wheel admin"#
+}
+
+// lspci isn't installed on minimal images; an empty result is not an error
+pub fn get_graphics_command() -> &'static str {
+    "lspci 2>/dev/null | grep -Ei 'vga|3d|display' || true"
+}
+
+pub fn parse_hardware_info(info: &mut ComputerInfo, output: &str) {
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim() {
+            "Model name" => {
+                info.processor = value.to_string();
+            }
+            "Architecture" => {
+                info.architecture = value.to_string();
+            }
+            "MemTotal" => {
+                // /proc/meminfo reports kB
+                if let Some(kb) = value.split_whitespace().next().and_then(|v| v.parse::<u64>().ok()) {
+                    info.memory = format_bytes(kb * 1024);
+                }
+            }
+            "Product" => {
+                info.product_name = value.to_string();
+            }
+            "Serial" => {
+                info.serial = value.to_string();
+            }
+            _ => {}
+        }
+    }
+    info!("Found Linux hardware: {} ({}, {})", info.processor, info.architecture, info.memory);
+}
+
+pub fn parse_system_info(info: &mut ComputerInfo, output: &str) {
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if let Some(value) = trimmed_line.strip_prefix("Hostname:") {
+            info.title = value.trim().to_string();
+        } else if let Some(value) = trimmed_line.strip_prefix("User:") {
+            info.user = value.trim().to_string();
+        } else if let Some(value) = trimmed_line.strip_prefix("PRETTY_NAME=") {
+            info.version = value.trim_matches('"').to_string();
+        }
+    }
+    info!("Found Linux system: {} ({})", info.title, info.version);
+}
+
+pub fn parse_network_info(info: &mut ComputerInfo, output: &str) {
+    info.network_interfaces = parse_ip_addr(output);
+    let (lan_ip, wifi_ip, wifi_name) = primary_addresses(&info.network_interfaces);
+    info.lan_ip = lan_ip;
+    info.wifi_ip = wifi_ip;
+    info.wifi_name = wifi_name;
+    info!("Found {} network interfaces (LAN IP: {})", info.network_interfaces.len(), info.lan_ip);
+}
+
+pub fn parse_storage_info(info: &mut ComputerInfo, output: &str) {
+    info.storage_details = parse_lsblk(output);
+    match info.storage_details.root_volume() {
+        Some(root) => {
+            info.storage = format_bytes(root.size_bytes);
+            info!("Found storage size: {}", info.storage);
+        }
+        None => warn!("Could not parse lsblk output"),
+    }
+}
+
+pub fn parse_users_info(info: &mut ComputerInfo, output: &str) {
+    info.user_accounts = parse_passwd(output);
+    info.home_users = info.user_accounts
+        .iter()
+        .filter(|account| !account.hidden)
+        .map(|account| account.name.clone())
+        .collect();
+    info!("Found {} home users: {:?}", info.home_users.len(), info.home_users);
+}
+
+pub fn parse_graphics_info(info: &mut ComputerInfo, output: &str) {
+    // 01:00.0 VGA compatible controller: NVIDIA Corporation GA102 [GeForce RTX 3090] (rev a1)
+    if let Some(line) = output.lines().find(|line| !line.trim().is_empty()) {
+        let device = line.splitn(3, ':').nth(2).unwrap_or(line);
+        info.graphics = device.trim().to_string();
+        info!("Found graphics chipset: {}", info.graphics);
+    } else {
+        warn!("No graphics chipset information found");
+    }
+}
+
+fn parse_ip_addr(output: &str) -> Vec<NetworkInterface> {
+    let Some(Value::Array(links)) = extract_json(output) else {
+        warn!("Could not parse `ip -j addr` output");
+        return Vec::new();
+    };
+
+    links
+        .iter()
+        .filter(|link| link["link_type"] != "loopback")
+        .map(|link| {
+            let device = link["ifname"].as_str().unwrap_or_default().to_string();
+            let addresses = |family: &str| -> Vec<String> {
+                link["addr_info"]
+                    .as_array()
+                    .map(|addrs| {
+                        addrs
+                            .iter()
+                            .filter(|a| a["family"] == family)
+                            .filter_map(|a| a["local"].as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            };
+            // Predictable interface names: wl* is wireless, en*/eth* is wired
+            let hardware_port = if device.starts_with("wl") {
+                "Wi-Fi"
+            } else if device.starts_with("en") || device.starts_with("eth") {
+                "Ethernet"
+            } else {
+                ""
+            };
+
+            NetworkInterface {
+                hardware_port: hardware_port.to_string(),
+                mac_address: link["address"].as_str().unwrap_or_default().to_string(),
+                ipv4: addresses("inet"),
+                ipv6: addresses("inet6"),
+                active: link["operstate"] == "UP",
+                ssid: None,
+                device,
+            }
+        })
+        .collect()
+}
+
+fn parse_lsblk(output: &str) -> StorageDetails {
+    let mut details = StorageDetails::default();
+    let Some(parsed) = extract_json(output) else {
+        return details;
+    };
+
+    for disk in parsed["blockdevices"].as_array().into_iter().flatten() {
+        if disk["type"] != "disk" || json_u64(&disk["size"]).unwrap_or(0) == 0 {
+            continue;
+        }
+        collect_volumes(disk, &mut details.volumes);
+
+        // Report the disk holding the root filesystem, or the first real disk
+        let holds_root = details.volumes.iter().any(|v| v.mount_point == "/");
+        if details.media_name.is_empty() || holds_root {
+            details.media_name = disk["model"].as_str().unwrap_or_default().trim().to_string();
+            // Older lsblk prints ROTA as "0"/"1" strings
+            details.disk_type = match &disk["rota"] {
+                Value::Bool(true) => DiskType::Hdd,
+                Value::Bool(false) => DiskType::Ssd,
+                Value::String(s) if s == "1" => DiskType::Hdd,
+                Value::String(s) if s == "0" => DiskType::Ssd,
+                _ => DiskType::Unknown,
+            };
+        }
+        if holds_root {
+            break;
+        }
+    }
+
+    details
+}
+
+fn collect_volumes(device: &Value, volumes: &mut Vec<VolumeInfo>) {
+    if let Some(mount_point) = device["mountpoint"].as_str() {
+        let free_bytes = json_u64(&device["fsavail"]);
+        let used_bytes = json_u64(&device["fsused"]);
+        if let (Some(free_bytes), Some(used_bytes)) = (free_bytes, used_bytes) {
+            volumes.push(VolumeInfo {
+                device: format!("/dev/{}", device["name"].as_str().unwrap_or_default()),
+                mount_point: mount_point.to_string(),
+                size_bytes: json_u64(&device["size"]).unwrap_or(free_bytes + used_bytes),
+                used_bytes,
+                free_bytes,
+            });
+        }
+    }
+    for child in device["children"].as_array().into_iter().flatten() {
+        collect_volumes(child, volumes);
+    }
+}
+
+fn json_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// getent passwd: name:x:uid:gid:gecos:home:shell
+fn parse_passwd(output: &str) -> Vec<UserAccount> {
+    let (passwd_output, groups_output) = output.split_once("=== Admins ===").unwrap_or((output, ""));
+    // getent group: sudo:x:27:fluke,render
+    let admins: Vec<&str> = groups_output
+        .lines()
+        .filter_map(|line| line.trim().rsplit_once(':'))
+        .flat_map(|(_, members)| members.split(','))
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+
+    passwd_output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.trim().split(':').collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            let uid = fields[2].parse::<u32>().ok();
+            let shell = fields[6];
+            let login_shell = !shell.ends_with("nologin") && !shell.ends_with("false");
+            Some(UserAccount {
+                name: fields[0].to_string(),
+                uid,
+                real_name: fields[4].split(',').next().unwrap_or_default().to_string(),
+                home_directory: fields[5].to_string(),
+                is_admin: fields[0] == "root" || admins.contains(&fields[0]),
+                last_login: None,
+                // Regular accounts start at UID 1000 on Debian/Ubuntu
+                hidden: uid.is_none_or(|uid| uid < 1000 || uid == 65534),
+                disabled: !login_shell,
+                secure_token: None,
+            })
+        })
+        .filter(|account| !account.hidden || account.name == "root")
+        .collect()
+}
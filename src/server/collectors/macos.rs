@@ -0,0 +1,184 @@
+use log::{ info, warn };
+use super::accounts::parse_user_accounts;
+use super::network::{ parse_network_interfaces, primary_addresses };
+use super::{ adobe, power, software_updates };
+use super::storage::{ format_bytes, parse_storage_details };
+use crate::configs::{ get_low_disk_free_threshold, get_max_uptime_days };
+use crate::server::resolve_computer::ComputerInfo;
+
+// macOS collectors. Commands for the sections that have their own module
+// (network, storage, accounts, power, updates) live next to their parsers.
+
+pub fn get_hardware_command() -> &'static str {
+    "system_profiler SPHardwareDataType"
+}
+
+pub fn get_system_command() -> &'static str {
+    "system_profiler SPSoftwareDataType"
+}
+
+pub fn get_graphics_command() -> &'static str {
+    "system_profiler SPDisplaysDataType"
+}
+
+// Parsing helpers
+fn get_value_after_first_colon(line: &str) -> Option<String> {
+    line.split_once(':')
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+// Data parsing methods
+pub fn parse_hardware_info(info: &mut ComputerInfo, output: &str) {
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if trimmed_line.starts_with("Model Name:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.product_name = val;
+                info!("Found product name: {}", info.product_name);
+            }
+        } else if trimmed_line.starts_with("Serial Number") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.serial = val;
+                info!("Found serial number: {}", info.serial);
+            }
+        } else if trimmed_line.starts_with("Processor ") {
+            // Catches "Processor Name:" or "Processor Speed:"
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.processor = val;
+                info!("Found processor: {}", info.processor);
+            }
+        } else if trimmed_line.starts_with("Memory:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.memory = val;
+                info!("Found memory: {}", info.memory);
+            }
+        } else if trimmed_line.starts_with("Chip:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.architecture = match val.to_lowercase() {
+                    s if s.contains("arm") => "aarch64".to_string(),
+                    s if s.contains("intel") => "x86_64".to_string(),
+                    _ => val,
+                };
+                info!("Found architecture: {}", info.architecture);
+            }
+        }
+    }
+}
+
+pub fn parse_system_info(info: &mut ComputerInfo, output: &str) {
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if trimmed_line.starts_with("System Version:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.version = val;
+                info!("Found system version: {}", info.version);
+            }
+        } else if trimmed_line.starts_with("Computer Name:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.title = val;
+                info!("Found computer name: {}", info.title);
+            }
+        } else if trimmed_line.starts_with("User Name:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.user = val;
+                info!("Found user name: {}", info.user);
+            }
+        }
+    }
+}
+
+pub fn parse_network_info(info: &mut ComputerInfo, output: &str) {
+    info.network_interfaces = parse_network_interfaces(output);
+    let (lan_ip, wifi_ip, wifi_name) = primary_addresses(&info.network_interfaces);
+    info.lan_ip = lan_ip;
+    info.wifi_ip = wifi_ip;
+    info.wifi_name = wifi_name;
+    info!(
+        "Found {} network interfaces (LAN IP: {}, WiFi IP: {}, WiFi name: {})",
+        info.network_interfaces.len(),
+        info.lan_ip,
+        info.wifi_ip,
+        info.wifi_name
+    );
+}
+
+pub fn parse_storage_info(info: &mut ComputerInfo, output: &str) {
+    let mut details = parse_storage_details(output);
+    details.low_free_space = details.is_low_on_space(get_low_disk_free_threshold());
+
+    if let Some(root) = details.root_volume() {
+        info.storage = format_bytes(root.size_bytes);
+        info!(
+            "Found storage: {} ({} free, {} volumes)",
+            info.storage,
+            format_bytes(root.free_bytes),
+            details.volumes.len()
+        );
+    } else {
+        warn!(
+            "Could not parse storage information. Output: {:?}",
+            output.lines().take(2).collect::<Vec<_>>()
+        );
+    }
+    info.storage_details = details;
+}
+
+pub fn parse_users_info(info: &mut ComputerInfo, output: &str) {
+    info.user_accounts = parse_user_accounts(output);
+    info.home_users = info.user_accounts
+        .iter()
+        .map(|account| account.name.clone())
+        .collect();
+    let admins: Vec<&str> = info.user_accounts
+        .iter()
+        .filter(|account| account.is_admin)
+        .map(|account| account.name.as_str())
+        .collect();
+    info!("Found {} home users: {:?} (admins: {:?})", info.home_users.len(), info.home_users, admins);
+}
+
+pub fn parse_graphics_info(info: &mut ComputerInfo, output: &str) {
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if trimmed_line.starts_with("Chipset Model:") {
+            if let Some(val) = get_value_after_first_colon(trimmed_line) {
+                info.graphics = val;
+                info!("Found graphics chipset: {}", info.graphics);
+                break;
+            }
+        }
+    }
+    if info.graphics.is_empty() {
+        warn!("No graphics chipset information found");
+    }
+}
+
+pub fn parse_power_info(info: &mut ComputerInfo, output: &str) {
+    let mut power_info = power::parse_power_info(output);
+    power_info.apply_uptime_threshold(get_max_uptime_days());
+    if power_info.has_battery {
+        info!(
+            "Found battery: {} ({:?} cycles, {:?}% capacity)",
+            power_info.condition,
+            power_info.cycle_count,
+            power_info.maximum_capacity
+        );
+    }
+    info!("Found uptime: {:?} days", power_info.uptime_days());
+    info.power = power_info;
+}
+
+pub fn parse_software_updates_info(info: &mut ComputerInfo, output: &str) {
+    info.software_updates = software_updates::parse_software_updates(output);
+    info!("Found {} pending software updates", info.software_updates.len());
+}
+
+pub fn parse_adobe_info(info: &mut ComputerInfo, output: &str) {
+    info.adobe = adobe::parse_adobe_info(output);
+    info!(
+        "Found {} Adobe apps and {} Comet plugins",
+        info.adobe.apps.len(),
+        info.adobe.comet_plugins.len()
+    );
+}
@@ -0,0 +1,23 @@
+pub mod software_updates;
+pub mod storage;
+pub mod power;
+pub mod network;
+pub mod accounts;
+pub mod os;
+pub mod linux;
+pub mod macos;
+pub mod output;
+pub mod custom;
+pub mod registry;
+pub mod adobe;
+
+// Re-export commonly used items
+pub use software_updates::{ SoftwareUpdate, parse_software_updates };
+pub use storage::{ DiskType, StorageDetails, VolumeInfo, format_bytes };
+pub use power::PowerInfo;
+pub use network::NetworkInterface;
+pub use accounts::UserAccount;
+pub use os::OsFamily;
+pub use custom::{ CustomCollector, CustomFieldColumn, Extraction };
+pub use adobe::{ AdobeInfo, COMET_PRODUCT };
+pub use registry::{ Collector, CollectorResult, CollectorStatus, all_collectors, collectors_for };
@@ -0,0 +1,205 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct NetworkInterface {
+    pub device: String,
+    pub hardware_port: String,
+    pub mac_address: String,
+    pub ipv4: Vec<String>,
+    pub ipv6: Vec<String>,
+    pub active: bool,
+    pub ssid: Option<String>,
+}
+
+impl NetworkInterface {
+    pub fn is_wifi(&self) -> bool {
+        self.hardware_port == "Wi-Fi" || self.hardware_port == "AirPort"
+    }
+
+    // Built-in, Thunderbolt and USB adapters ("USB 10/100/1000 LAN")
+    pub fn is_wired(&self) -> bool {
+        !self.is_wifi() &&
+            (self.hardware_port.contains("Ethernet") || self.hardware_port.contains("LAN"))
+    }
+}
+
+// `airport -I` was removed in macOS 14.4, the SSID is read from
+// `system_profiler SPAirPortDataType` instead.
+pub fn get_network_interfaces_command() -> &'static str {
+    r#"echo '=== Hardware Ports ===' &&
+       networksetup -listallhardwareports &&
+       echo '=== Interfaces ===' &&
+       ifconfig &&
+       echo '=== Wifi Status ===' &&
+       system_profiler SPAirPortDataType"#
+}
+
+pub fn parse_network_interfaces(output: &str) -> Vec<NetworkInterface> {
+    let (ports_output, rest) = output.split_once("=== Interfaces ===").unwrap_or((output, ""));
+    let (ifconfig_output, wifi_output) = rest.split_once("=== Wifi Status ===").unwrap_or((rest, ""));
+
+    let mut interfaces = parse_hardware_ports(ports_output);
+
+    for parsed in parse_ifconfig(ifconfig_output) {
+        if let Some(interface) = interfaces.iter_mut().find(|i| i.device == parsed.device) {
+            if interface.mac_address.is_empty() {
+                interface.mac_address = parsed.mac_address;
+            }
+            interface.ipv4 = parsed.ipv4;
+            interface.ipv6 = parsed.ipv6;
+            interface.active = parsed.active;
+        } else if parsed.device != "lo0" && !parsed.ipv4.is_empty() {
+            // Virtual interfaces (VPN tunnels, bridges) only matter when addressed
+            interfaces.push(parsed);
+        }
+    }
+
+    for (device, ssid) in parse_wifi_networks(wifi_output) {
+        if let Some(interface) = interfaces.iter_mut().find(|i| i.device == device) {
+            interface.ssid = Some(ssid);
+        }
+    }
+
+    interfaces
+}
+
+// Picks the LAN and Wi-Fi addresses by hardware port type rather than by the
+// order ifconfig happens to list the interfaces in.
+pub fn primary_addresses(interfaces: &[NetworkInterface]) -> (String, String, String) {
+    let lan_ip = interfaces
+        .iter()
+        .filter(|i| i.is_wired())
+        .find_map(|i| i.ipv4.first().cloned())
+        .unwrap_or_default();
+    let wifi_ip = interfaces
+        .iter()
+        .filter(|i| i.is_wifi())
+        .find_map(|i| i.ipv4.first().cloned())
+        .unwrap_or_default();
+    let wifi_name = interfaces
+        .iter()
+        .filter(|i| i.is_wifi())
+        .find_map(|i| i.ssid.clone())
+        .unwrap_or_default();
+
+    (lan_ip, wifi_ip, wifi_name)
+}
+
+// networksetup -listallhardwareports:
+//   Hardware Port: Wi-Fi
+//   Device: en0
+//   Ethernet Address: a4:83:e7:12:34:56
+fn parse_hardware_ports(output: &str) -> Vec<NetworkInterface> {
+    let mut interfaces: Vec<NetworkInterface> = Vec::new();
+
+    for line in output.lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Hardware Port" => {
+                interfaces.push(NetworkInterface {
+                    hardware_port: value.to_string(),
+                    ..Default::default()
+                });
+            }
+            "Device" => {
+                if let Some(interface) = interfaces.last_mut() {
+                    interface.device = value.to_string();
+                }
+            }
+            "Ethernet Address" => {
+                if let Some(interface) = interfaces.last_mut() {
+                    if value != "N/A" {
+                        interface.mac_address = value.to_string();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    interfaces.retain(|i| !i.device.is_empty());
+    interfaces
+}
+
+fn parse_ifconfig(output: &str) -> Vec<NetworkInterface> {
+    let mut interfaces: Vec<NetworkInterface> = Vec::new();
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            if let Some((device, _)) = line.split_once(": flags=") {
+                interfaces.push(NetworkInterface {
+                    device: device.to_string(),
+                    ..Default::default()
+                });
+            }
+            continue;
+        }
+
+        let Some(interface) = interfaces.last_mut() else {
+            continue;
+        };
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        match parts.as_slice() {
+            ["ether", mac, ..] => {
+                interface.mac_address = mac.to_string();
+            }
+            ["inet", addr, ..] => {
+                interface.ipv4.push(addr.to_string());
+            }
+            ["inet6", addr, ..] => {
+                // Drop the zone suffix from link-local addresses (fe80::1%en0)
+                let addr = addr.split('%').next().unwrap_or(addr);
+                interface.ipv6.push(addr.to_string());
+            }
+            ["status:", status, ..] => {
+                interface.active = *status == "active";
+            }
+            _ => {}
+        }
+    }
+
+    interfaces
+}
+
+// system_profiler SPAirPortDataType lists the joined network under each
+// interface:
+//   en0:
+//     Status: Connected
+//     Current Network Information:
+//       Office-5G:
+fn parse_wifi_networks(output: &str) -> Vec<(String, String)> {
+    let mut networks = Vec::new();
+    let mut device: Option<String> = None;
+    let mut expect_ssid = false;
+
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if trimmed_line.is_empty() {
+            continue;
+        }
+        if expect_ssid {
+            expect_ssid = false;
+            if let (Some(device), Some(ssid)) = (&device, trimmed_line.strip_suffix(':')) {
+                networks.push((device.clone(), ssid.to_string()));
+            }
+            continue;
+        }
+        if trimmed_line == "Current Network Information:" {
+            expect_ssid = true;
+        } else if let Some(name) = trimmed_line.strip_suffix(':') {
+            let is_device = ["en", "awdl", "llw"].iter().any(|prefix| {
+                name.strip_prefix(prefix).is_some_and(|n| {
+                    !n.is_empty() && n.chars().all(|c| c.is_ascii_digit())
+                })
+            });
+            if is_device {
+                device = Some(name.to_string());
+            }
+        }
+    }
+
+    networks
+}
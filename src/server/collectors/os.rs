@@ -0,0 +1,49 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OsFamily {
+    MacOs,
+    Linux,
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for OsFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OsFamily::MacOs => write!(f, "macOS"),
+            OsFamily::Linux => write!(f, "Linux"),
+            OsFamily::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+// Appended to the connection test so detection doesn't cost a round trip
+pub fn get_os_detection_command() -> &'static str {
+    "uname -s && cat /etc/os-release 2>/dev/null"
+}
+
+// Returns the OS family and, on Linux, the distribution name from os-release
+pub fn parse_os_detection(output: &str) -> (OsFamily, Option<String>) {
+    let mut family = OsFamily::Unknown;
+    let mut pretty_name = None;
+
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        match trimmed_line {
+            "Darwin" => {
+                family = OsFamily::MacOs;
+            }
+            "Linux" => {
+                family = OsFamily::Linux;
+            }
+            _ => {
+                if let Some(value) = trimmed_line.strip_prefix("PRETTY_NAME=") {
+                    pretty_name = Some(value.trim_matches('"').to_string());
+                }
+            }
+        }
+    }
+
+    (family, pretty_name)
+}
@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+// The expect wrapper echoes password prompts and status lines around the
+// command output, so JSON has to be located inside it before parsing.
+pub fn extract_json(output: &str) -> Option<Value> {
+    let start = output.find(['{', '['])?;
+    let close = if output[start..].starts_with('{') { '}' } else { ']' };
+    let end = output.rfind(close)?;
+    if end < start {
+        return None;
+    }
+    serde_json::from_str(&output[start..=end]).ok()
+}
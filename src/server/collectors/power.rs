@@ -0,0 +1,95 @@
+use chrono::{ DateTime, TimeZone, Utc };
+use serde::{ Serialize, Deserialize };
+
+// Battery conditions reported by macOS that mean the battery needs attention
+const BATTERY_ATTENTION_CONDITIONS: [&str; 4] = [
+    "service recommended",
+    "service battery",
+    "replace soon",
+    "replace now",
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct PowerInfo {
+    pub has_battery: bool,
+    pub cycle_count: Option<u32>,
+    pub condition: String,
+    pub maximum_capacity: Option<u8>,
+    pub boot_time: Option<DateTime<Utc>>,
+    pub uptime_seconds: Option<u64>,
+    pub battery_service_recommended: bool,
+    pub uptime_exceeded: bool,
+}
+
+impl PowerInfo {
+    pub fn uptime_days(&self) -> Option<u64> {
+        self.uptime_seconds.map(|s| s / 86_400)
+    }
+
+    pub fn apply_uptime_threshold(&mut self, max_uptime_days: u64) {
+        self.uptime_exceeded = self.uptime_days().is_some_and(|d| d > max_uptime_days);
+    }
+}
+
+// The host's own clock is used for the uptime so clock drift between the
+// server and the managed machine doesn't skew it.
+pub fn get_power_command() -> &'static str {
+    r#"echo '=== Power ===' &&
+       system_profiler SPPowerDataType &&
+       echo '=== Boot ===' &&
+       sysctl -n kern.boottime &&
+       date +%s"#
+}
+
+pub fn parse_power_info(output: &str) -> PowerInfo {
+    let (power_output, boot_output) = output.split_once("=== Boot ===").unwrap_or((output, ""));
+    let mut power = PowerInfo::default();
+
+    for line in power_output.lines() {
+        let trimmed_line = line.trim();
+        let Some((key, value)) = trimmed_line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Battery Information" => {
+                power.has_battery = true;
+            }
+            "Cycle Count" => {
+                power.has_battery = true;
+                power.cycle_count = value.parse().ok();
+            }
+            "Condition" => {
+                power.has_battery = true;
+                power.condition = value.to_string();
+            }
+            "Maximum Capacity" => {
+                power.maximum_capacity = value.trim_end_matches('%').trim().parse().ok();
+            }
+            _ => {}
+        }
+    }
+
+    let condition = power.condition.to_lowercase();
+    power.battery_service_recommended = BATTERY_ATTENTION_CONDITIONS.iter().any(|c|
+        condition.contains(c)
+    );
+
+    // `sysctl -n kern.boottime`: { sec = 1712345678, usec = 123456 } Thu Apr  4 10:00:00 2024
+    let mut boot_lines = boot_output.lines().map(str::trim).filter(|l| !l.is_empty());
+    let boot_secs = boot_lines
+        .next()
+        .and_then(|l| l.split("sec =").nth(1))
+        .and_then(|s| s.split(',').next())
+        .and_then(|s| s.trim().parse::<i64>().ok());
+    let now_secs = boot_lines.next().and_then(|l| l.parse::<i64>().ok());
+
+    if let Some(boot_secs) = boot_secs {
+        power.boot_time = Utc.timestamp_opt(boot_secs, 0).single();
+        if let Some(now_secs) = now_secs {
+            power.uptime_seconds = u64::try_from(now_secs - boot_secs).ok();
+        }
+    }
+
+    power
+}
@@ -0,0 +1,242 @@
+use serde::{ Serialize, Deserialize };
+use super::custom::{ extract_value, load_custom_collectors, Extraction };
+use super::os::OsFamily;
+use super::{ accounts, adobe, linux, macos, network, power, software_updates, storage };
+use crate::server::resolve_computer::ComputerInfo;
+
+const MACOS: &[OsFamily] = &[OsFamily::MacOs, OsFamily::Unknown];
+const LINUX: &[OsFamily] = &[OsFamily::Linux];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum CollectorStatus {
+    Ok,
+    Failed(String),
+    TimedOut,
+    Skipped(String),
+}
+
+impl CollectorStatus {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CollectorStatus::Ok)
+    }
+
+    // Skipped collectors don't apply to the host, so they aren't failures
+    pub fn is_failure(&self) -> bool {
+        matches!(self, CollectorStatus::Failed(_) | CollectorStatus::TimedOut)
+    }
+}
+
+impl std::fmt::Display for CollectorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CollectorStatus::Ok => write!(f, "ok"),
+            CollectorStatus::Failed(msg) => write!(f, "failed: {}", msg),
+            CollectorStatus::TimedOut => write!(f, "timed out"),
+            CollectorStatus::Skipped(reason) => write!(f, "skipped: {}", reason),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CollectorResult {
+    pub name: String,
+    pub status: CollectorStatus,
+    pub duration_ms: u64,
+}
+
+pub enum Parser {
+    // Built-in parsers fill their own typed section of `ComputerInfo`
+    Builtin(fn(&mut ComputerInfo, &str)),
+    // User-defined collectors store one extracted value under a custom field
+    Custom {
+        field: String,
+        extract: Extraction,
+    },
+}
+
+pub struct Collector {
+    pub name: String,
+    pub command: String,
+    pub os: Vec<OsFamily>,
+    pub timeout_secs: u64,
+    pub parser: Parser,
+}
+
+impl Collector {
+    pub fn applies_to(&self, os_family: OsFamily) -> bool {
+        self.os.is_empty() || self.os.contains(&os_family)
+    }
+
+    pub fn apply(&self, info: &mut ComputerInfo, output: &str) {
+        match &self.parser {
+            Parser::Builtin(parse) => parse(info, output),
+            Parser::Custom { field, extract } => {
+                match extract_value(extract, output) {
+                    Some(value) => {
+                        log::info!("Found custom field {}: {}", field, value);
+                        info.custom_fields.insert(field.clone(), value);
+                    }
+                    None => log::warn!("Custom collector {} extracted no value", field),
+                }
+            }
+        }
+    }
+}
+
+struct BuiltinCollector {
+    name: &'static str,
+    os: &'static [OsFamily],
+    timeout_secs: u64,
+    command: fn() -> &'static str,
+    parser: fn(&mut ComputerInfo, &str),
+}
+
+// Hosts whose OS couldn't be detected get the macOS set, which is what the
+// whole fleet was before Linux support.
+const BUILTIN_COLLECTORS: &[BuiltinCollector] = &[
+    BuiltinCollector {
+        name: "hardware",
+        os: MACOS,
+        timeout_secs: 60,
+        command: macos::get_hardware_command,
+        parser: macos::parse_hardware_info,
+    },
+    BuiltinCollector {
+        name: "system",
+        os: MACOS,
+        timeout_secs: 60,
+        command: macos::get_system_command,
+        parser: macos::parse_system_info,
+    },
+    BuiltinCollector {
+        name: "network",
+        os: MACOS,
+        timeout_secs: 60,
+        command: network::get_network_interfaces_command,
+        parser: macos::parse_network_info,
+    },
+    BuiltinCollector {
+        name: "storage",
+        os: MACOS,
+        timeout_secs: 30,
+        command: storage::get_storage_details_command,
+        parser: macos::parse_storage_info,
+    },
+    BuiltinCollector {
+        name: "users",
+        os: MACOS,
+        timeout_secs: 60,
+        command: accounts::get_user_accounts_command,
+        parser: macos::parse_users_info,
+    },
+    BuiltinCollector {
+        name: "graphics",
+        os: MACOS,
+        timeout_secs: 60,
+        command: macos::get_graphics_command,
+        parser: macos::parse_graphics_info,
+    },
+    BuiltinCollector {
+        // `softwareupdate --list` contacts Apple's catalog and can be slow
+        name: "software_updates",
+        os: MACOS,
+        timeout_secs: 180,
+        command: software_updates::get_software_updates_command,
+        parser: macos::parse_software_updates_info,
+    },
+    BuiltinCollector {
+        name: "power",
+        os: MACOS,
+        timeout_secs: 60,
+        command: power::get_power_command,
+        parser: macos::parse_power_info,
+    },
+    BuiltinCollector {
+        name: "adobe",
+        os: MACOS,
+        timeout_secs: 60,
+        command: adobe::get_adobe_command,
+        parser: macos::parse_adobe_info,
+    },
+    BuiltinCollector {
+        name: "hardware",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_hardware_command,
+        parser: linux::parse_hardware_info,
+    },
+    BuiltinCollector {
+        name: "system",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_system_command,
+        parser: linux::parse_system_info,
+    },
+    BuiltinCollector {
+        name: "network",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_network_command,
+        parser: linux::parse_network_info,
+    },
+    BuiltinCollector {
+        name: "storage",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_storage_command,
+        parser: linux::parse_storage_info,
+    },
+    BuiltinCollector {
+        name: "users",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_users_command,
+        parser: linux::parse_users_info,
+    },
+    BuiltinCollector {
+        name: "graphics",
+        os: LINUX,
+        timeout_secs: 30,
+        command: linux::get_graphics_command,
+        parser: linux::parse_graphics_info,
+    },
+];
+
+pub fn builtin_collectors() -> Vec<Collector> {
+    BUILTIN_COLLECTORS.iter()
+        .map(|builtin| Collector {
+            name: builtin.name.to_string(),
+            command: (builtin.command)().to_string(),
+            os: builtin.os.to_vec(),
+            timeout_secs: builtin.timeout_secs,
+            parser: Parser::Builtin(builtin.parser),
+        })
+        .collect()
+}
+
+pub fn custom_collectors() -> Vec<Collector> {
+    load_custom_collectors()
+        .into_iter()
+        .map(|custom| Collector {
+            name: format!("custom:{}", custom.field),
+            command: custom.command,
+            os: custom.os,
+            timeout_secs: custom.timeout_secs,
+            parser: Parser::Custom {
+                field: custom.field,
+                extract: custom.extract,
+            },
+        })
+        .collect()
+}
+
+pub fn all_collectors() -> Vec<Collector> {
+    builtin_collectors().into_iter().chain(custom_collectors()).collect()
+}
+
+pub fn collectors_for(os_family: OsFamily) -> Vec<Collector> {
+    all_collectors()
+        .into_iter()
+        .filter(|collector| collector.applies_to(os_family))
+        .collect()
+}
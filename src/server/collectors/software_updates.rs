@@ -0,0 +1,105 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SoftwareUpdate {
+    pub label: String,
+    pub title: String,
+    pub version: String,
+    pub size: String,
+    pub recommended: bool,
+    pub restart_required: bool,
+}
+
+// `softwareupdate` prints "No new software available." on stderr, so
+// redirect it to keep the output in a single stream.
+pub fn get_software_updates_command() -> &'static str {
+    "softwareupdate --list 2>&1"
+}
+
+// Parses `softwareupdate --list` output. Two layouts exist:
+//
+// macOS 10.15 and later:
+//   * Label: macOS Sonoma 14.4.1-23E224
+//       Title: macOS Sonoma 14.4.1, Version: 14.4.1, Size: 1024000KiB, Recommended: YES, Action: restart,
+//
+// macOS 10.14 and earlier:
+//    * Security Update 2019-001 (Mojave)-10.14.3
+//       Security Update 2019-001 (Mojave) (10.14.3), 1024000K [recommended] [restart]
+pub fn parse_software_updates(output: &str) -> Vec<SoftwareUpdate> {
+    let mut updates: Vec<SoftwareUpdate> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed_line = line.trim();
+        if let Some(entry) = trimmed_line.strip_prefix('*') {
+            let entry = entry.trim();
+            let label = entry.strip_prefix("Label:").unwrap_or(entry).trim();
+            if !label.is_empty() {
+                updates.push(SoftwareUpdate {
+                    label: label.to_string(),
+                    ..Default::default()
+                });
+            }
+        } else if let Some(update) = updates.last_mut() {
+            if !update.title.is_empty() || trimmed_line.is_empty() {
+                continue;
+            }
+            if trimmed_line.starts_with("Title:") {
+                parse_detail_fields(update, trimmed_line);
+            } else {
+                parse_legacy_detail(update, trimmed_line);
+            }
+        }
+    }
+
+    updates
+}
+
+fn parse_detail_fields(update: &mut SoftwareUpdate, line: &str) {
+    for field in line.split(',') {
+        let Some((key, value)) = field.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Title" => {
+                update.title = value.to_string();
+            }
+            "Version" => {
+                update.version = value.to_string();
+            }
+            "Size" => {
+                update.size = value.to_string();
+            }
+            "Recommended" => {
+                update.recommended = value.eq_ignore_ascii_case("yes");
+            }
+            "Action" => {
+                update.restart_required =
+                    value.eq_ignore_ascii_case("restart") ||
+                    value.eq_ignore_ascii_case("shut down");
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_legacy_detail(update: &mut SoftwareUpdate, line: &str) {
+    let lower = line.to_lowercase();
+    update.recommended = lower.contains("[recommended]");
+    update.restart_required = lower.contains("[restart]") || lower.contains("[shut down]");
+
+    let details = line.split('[').next().unwrap_or(line).trim();
+    let (name, size) = details.rsplit_once(',').unwrap_or((details, ""));
+    update.size = size.trim().to_string();
+
+    let name = name.trim();
+    match name.rsplit_once(" (") {
+        Some((title, version)) if version.ends_with(')') => {
+            update.title = title.trim().to_string();
+            update.version = version.trim_end_matches(')').to_string();
+        }
+        _ => {
+            update.title = name.to_string();
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum DiskType {
+    Ssd,
+    Hdd,
+    Fusion,
+    #[default]
+    Unknown,
+}
+
+impl std::fmt::Display for DiskType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiskType::Ssd => write!(f, "SSD"),
+            DiskType::Hdd => write!(f, "HDD"),
+            DiskType::Fusion => write!(f, "Fusion"),
+            DiskType::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct VolumeInfo {
+    pub device: String,
+    pub mount_point: String,
+    pub size_bytes: u64,
+    pub used_bytes: u64,
+    pub free_bytes: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct StorageDetails {
+    pub volumes: Vec<VolumeInfo>,
+    pub media_name: String,
+    pub smart_status: String,
+    pub disk_type: DiskType,
+    pub low_free_space: bool,
+}
+
+impl StorageDetails {
+    // The boot volume; on Catalina and later user data lives on the Data
+    // volume but shares the container's free space with it.
+    pub fn root_volume(&self) -> Option<&VolumeInfo> {
+        self.volumes
+            .iter()
+            .find(|v| v.mount_point == "/")
+            .or_else(|| self.volumes.first())
+    }
+
+    pub fn is_low_on_space(&self, threshold_bytes: u64) -> bool {
+        self.root_volume().is_some_and(|v| v.free_bytes < threshold_bytes)
+    }
+}
+
+pub fn get_storage_details_command() -> &'static str {
+    r#"echo '=== Volumes ===' &&
+       df -k -P -T apfs &&
+       echo '=== Disk Info ===' &&
+       diskutil info /"#
+}
+
+pub fn parse_storage_details(output: &str) -> StorageDetails {
+    let (df_output, diskutil_output) = output
+        .split_once("=== Disk Info ===")
+        .unwrap_or((output, ""));
+    let mut details = StorageDetails {
+        volumes: parse_df_volumes(df_output),
+        ..Default::default()
+    };
+
+    let mut solid_state = None;
+    let mut fusion = false;
+    for line in diskutil_output.lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "SMART Status" => {
+                details.smart_status = value.to_string();
+            }
+            "Device / Media Name" => {
+                details.media_name = value.to_string();
+            }
+            "Solid State" => {
+                solid_state = Some(value.eq_ignore_ascii_case("yes"));
+            }
+            "Fusion Drive" => {
+                fusion = value.eq_ignore_ascii_case("yes");
+            }
+            _ => {}
+        }
+    }
+
+    details.disk_type = match (fusion, solid_state) {
+        (true, _) => DiskType::Fusion,
+        (false, Some(true)) => DiskType::Ssd,
+        (false, Some(false)) => DiskType::Hdd,
+        (false, None) => DiskType::Unknown,
+    };
+    details
+}
+
+// `df -k -P` rows: Filesystem 1024-blocks Used Available Capacity Mounted on
+fn parse_df_volumes(output: &str) -> Vec<VolumeInfo> {
+    output
+        .lines()
+        .filter(|line| line.trim_start().starts_with("/dev/"))
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 6 {
+                return None;
+            }
+            let blocks = |s: &str| s.parse::<u64>().ok().map(|b| b * 1024);
+            Some(VolumeInfo {
+                device: parts[0].to_string(),
+                size_bytes: blocks(parts[1])?,
+                used_bytes: blocks(parts[2])?,
+                free_bytes: blocks(parts[3])?,
+                mount_point: parts[5..].join(" "),
+            })
+        })
+        .collect()
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
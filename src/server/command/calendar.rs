@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+use server_fn::codec::{ GetUrl, StreamingText, TextStream };
+use crate::server::calendar::{ CalendarToken, FeedGrouping };
+
+// Subscribable ICS feed of upcoming license and warranty expirations, served
+// at /api/calendar.ics?token=...&grouping=per_day
+#[server(endpoint = "calendar.ics", input = GetUrl, output = StreamingText)]
+pub async fn calendar_feed(
+    token: String,
+    grouping: Option<FeedGrouping>
+) -> Result<TextStream, ServerFnError> {
+    let calendar = crate::server::calendar
+        ::build_feed(&token, grouping.unwrap_or_default())
+        .map_err(ServerFnError::new)?;
+    if let Ok(content_type) = "text/calendar; charset=utf-8".parse() {
+        server_context().response_parts_mut().headers.insert("content-type", content_type);
+    }
+    Ok(TextStream::from(futures::stream::iter([calendar])))
+}
+
+// Tokens are masked here; only create_calendar_token returns a usable one
+#[server]
+pub async fn list_calendar_tokens() -> Result<Vec<CalendarToken>, ServerFnError> {
+    crate::server::calendar::list_tokens().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn create_calendar_token(person_id: String) -> Result<CalendarToken, ServerFnError> {
+    crate::server::calendar::create_token(&person_id).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn revoke_calendar_token(id: String) -> Result<(), ServerFnError> {
+    crate::server::calendar::revoke_token(&id).map_err(ServerFnError::new)
+}
@@ -0,0 +1,41 @@
+use dioxus::prelude::*;
+use crate::server::entitlements::{ Entitlement, ReconciliationRow };
+use crate::server::inventory::{ ExportFile, ExportFormat };
+
+#[server]
+pub async fn list_entitlements() -> Result<Vec<Entitlement>, ServerFnError> {
+    crate::server::entitlements::list_entitlements().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn save_entitlement(entitlement: Entitlement) -> Result<Entitlement, ServerFnError> {
+    crate::server::entitlements::save_entitlement(entitlement).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn delete_entitlement(id: String) -> Result<(), ServerFnError> {
+    crate::server::entitlements::delete_entitlement(&id).map_err(ServerFnError::new)
+}
+
+// Seats purchased per product against installations from the latest license
+// checks and inventory collectors
+#[server]
+pub async fn get_seat_reconciliation() -> Result<Vec<ReconciliationRow>, ServerFnError> {
+    let entitlements = crate::server::entitlements::list_entitlements().map_err(ServerFnError::new)?;
+    let tags = crate::server::groups::all_host_tags().map_err(ServerFnError::new)?;
+    crate::server::entitlements
+        ::reconcile(
+            &entitlements,
+            &crate::server::license::latest_results(),
+            &crate::server::inventory::all_records(),
+            &tags,
+            &chrono::Local::now().date_naive()
+        )
+        .map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn export_seat_reconciliation(format: ExportFormat) -> Result<ExportFile, ServerFnError> {
+    let rows = get_seat_reconciliation().await?;
+    crate::server::entitlements::render_reconciliation(&rows, format).map_err(ServerFnError::new)
+}
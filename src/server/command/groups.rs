@@ -0,0 +1,71 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use dioxus::prelude::*;
+use crate::server::groups::{ Group, GroupSummary };
+use crate::server::license::LicenseCheckResult;
+
+#[server]
+pub async fn list_host_tags() -> Result<BTreeMap<String, BTreeSet<String>>, ServerFnError> {
+    crate::server::groups::all_host_tags().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn set_host_tags(host: String, tags: Vec<String>) -> Result<BTreeSet<String>, ServerFnError> {
+    crate::server::groups::set_host_tags(&host, &tags).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn list_groups() -> Result<Vec<GroupSummary>, ServerFnError> {
+    let records = crate::server::inventory::all_records();
+    let tags = crate::server::groups::all_host_tags().map_err(ServerFnError::new)?;
+    crate::server::groups
+        ::list_groups()
+        .map_err(ServerFnError::new)?
+        .into_iter()
+        .map(|group| {
+            let members = crate::server::groups
+                ::group_members(&group, &records, &tags)
+                .map_err(ServerFnError::new)?;
+            Ok(GroupSummary { group, members })
+        })
+        .collect()
+}
+
+#[server]
+pub async fn save_group(group: Group) -> Result<Group, ServerFnError> {
+    crate::server::groups::save_group(group).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn delete_group(id: String) -> Result<(), ServerFnError> {
+    crate::server::groups::delete_group(&id).map_err(ServerFnError::new)
+}
+
+// Hosts a dynamic rule would select right now, for previewing before saving
+#[server]
+pub async fn preview_group_rule(rule: String) -> Result<Vec<String>, ServerFnError> {
+    use crate::server::groups::GroupKind;
+
+    crate::server::groups::validate_rule(&rule).map_err(ServerFnError::new)?;
+    let group = Group { kind: GroupKind::Dynamic(rule), ..Default::default() };
+    let records = crate::server::inventory::all_records();
+    let tags = crate::server::groups::all_host_tags().map_err(ServerFnError::new)?;
+    crate::server::groups::group_members(&group, &records, &tags).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn execute_group_command(
+    group_id: String,
+    command: String,
+    password: String
+) -> Result<Vec<(String, String, String)>, ServerFnError> {
+    let hosts = crate::server::groups::hosts_for_group(&group_id).map_err(ServerFnError::new)?;
+    log::info!("Running command on group {} ({} hosts)", group_id, hosts.len());
+    super::network::execute_concurrent_commands(hosts, command, password).await
+}
+
+#[server]
+pub async fn check_group_licenses(group_id: String) -> Result<Vec<LicenseCheckResult>, ServerFnError> {
+    let hosts = crate::server::groups::hosts_for_group(&group_id).map_err(ServerFnError::new)?;
+    let max_concurrency = crate::configs::get_refresh_max_concurrency();
+    crate::server::license::check_hosts(hosts, max_concurrency).await.map_err(ServerFnError::new)
+}
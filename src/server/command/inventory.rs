@@ -0,0 +1,138 @@
+use dioxus::prelude::*;
+use crate::server::collectors::CustomFieldColumn;
+use crate::server::inventory::{
+    AssetImportSummary,
+    AssetMetadata,
+    CoverageWarning,
+    ExportColumn,
+    ExportFile,
+    ExportFormat,
+    ExportRequest,
+    InventoryImportSummary,
+    InventoryRecord,
+    PendingUpdateGroup,
+    RefreshProgress,
+    SavedSearch,
+};
+
+#[server]
+pub async fn get_pending_updates_report(
+    group: Option<String>
+) -> Result<Vec<PendingUpdateGroup>, ServerFnError> {
+    let records = crate::server::groups
+        ::records_in_group(crate::server::inventory::all_records(), group.as_deref())
+        .map_err(ServerFnError::new)?;
+    Ok(crate::server::inventory::build_pending_updates_report(&records))
+}
+
+#[server]
+pub async fn get_custom_field_columns() -> Result<Vec<CustomFieldColumn>, ServerFnError> {
+    let collectors = crate::server::collectors::custom::load_custom_collectors();
+    Ok(collectors.iter().map(|c| c.column()).collect())
+}
+
+#[server]
+pub async fn get_inventory_records() -> Result<Vec<InventoryRecord>, ServerFnError> {
+    Ok(crate::server::inventory::all_records())
+}
+
+#[server]
+pub async fn refresh_all_inventory() -> Result<RefreshProgress, ServerFnError> {
+    use crate::server::inventory::RefreshTrigger;
+    if !crate::server::inventory::start_refresh(RefreshTrigger::Manual) {
+        log::info!("Inventory refresh already in progress");
+    }
+    Ok(crate::server::inventory::get_refresh_progress())
+}
+
+#[server]
+pub async fn get_refresh_progress() -> Result<RefreshProgress, ServerFnError> {
+    Ok(crate::server::inventory::get_refresh_progress())
+}
+
+#[server]
+pub async fn list_asset_metadata() -> Result<Vec<AssetMetadata>, ServerFnError> {
+    Ok(crate::server::inventory::all_assets())
+}
+
+#[server]
+pub async fn save_asset_metadata(asset: AssetMetadata) -> Result<AssetMetadata, ServerFnError> {
+    crate::server::inventory::save_asset(asset).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn import_asset_metadata_csv(data: String) -> Result<AssetImportSummary, ServerFnError> {
+    let summary = crate::server::inventory::import_assets_csv(&data).map_err(ServerFnError::new)?;
+    log::info!(
+        "Imported asset metadata: {} created, {} updated, {} errors",
+        summary.created,
+        summary.updated,
+        summary.errors.len()
+    );
+    Ok(summary)
+}
+
+#[server]
+pub async fn get_coverage_warnings() -> Result<Vec<CoverageWarning>, ServerFnError> {
+    let today = chrono::Local::now().date_naive();
+    Ok(
+        crate::server::inventory
+            ::all_assets()
+            .iter()
+            .flat_map(|asset| crate::server::inventory::coverage_warnings(asset, &today))
+            .collect()
+    )
+}
+
+#[server]
+pub async fn get_export_columns() -> Result<Vec<ExportColumn>, ServerFnError> {
+    let records = crate::server::inventory::all_records();
+    Ok(crate::server::inventory::export_columns(&records))
+}
+
+#[server]
+pub async fn export_inventory(request: ExportRequest) -> Result<ExportFile, ServerFnError> {
+    let records = crate::server::groups
+        ::records_in_group(crate::server::inventory::all_records(), request.group.as_deref())
+        .map_err(ServerFnError::new)?;
+    crate::server::inventory::render_export(&request, &records).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn import_inventory(
+    data: String,
+    format: ExportFormat
+) -> Result<InventoryImportSummary, ServerFnError> {
+    let summary = crate::server::inventory
+        ::import_inventory(&data, format)
+        .map_err(ServerFnError::new)?;
+    log::info!(
+        "Imported inventory: {} created, {} updated, {} errors",
+        summary.created,
+        summary.updated,
+        summary.errors.len()
+    );
+    Ok(summary)
+}
+
+// Hosts in the stored inventory matching a query such as
+// `version < 14 AND tag == design` or a bare search term
+#[server]
+pub async fn search_inventory(query: String) -> Result<Vec<String>, ServerFnError> {
+    crate::server::inventory::search_inventory(&query).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn list_saved_searches() -> Result<Vec<SavedSearch>, ServerFnError> {
+    crate::server::inventory::list_saved_searches().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn save_search(search: SavedSearch) -> Result<SavedSearch, ServerFnError> {
+    crate::server::inventory::save_search(search).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn delete_saved_search(id: String) -> Result<(), ServerFnError> {
+    crate::server::inventory::delete_saved_search(&id).map_err(ServerFnError::new)
+}
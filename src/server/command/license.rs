@@ -1,73 +1,99 @@
 use dioxus::prelude::*;
-use chrono::NaiveDate;
 use crate::server::network::ssh::ssh_exec;
 use crate::configs::env_validate::get_ssh_password;
-use crate::server::license::types::{ LicenseCheckResult, SoftwareInfo };
-use crate::server::license::checker::analyze_expiration_date;
+use crate::server::license::types::{
+    LicenseCheckResult,
+    LicenseDeployRequest,
+    LicenseDeployment,
+    LicenseRow,
+    LicenseTarget,
+    LicenseTimeline,
+    ProductLicense,
+    SoftwareInfo,
+};
 
+// Checks every product in the license registry (the Comet plugin unless
+// configured otherwise) and reports the license that expires first
 #[server]
 pub async fn check_expired_adobe_plugin_comet_license(
     host: String
 ) -> Result<LicenseCheckResult, ServerFnError> {
-    let command =
-        "cat \'/Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic\' | grep Expires";
-    let password = get_ssh_password().map_err(|e| ServerFnError::new(e))?;
-    match ssh_exec(host.clone(), host.clone(), password, command.to_string()).await {
-        Ok(output) => {
-            // Extract date from output
-            let date_str = output
-                .trim_start_matches("//")
-                .trim()
-                .split(':')
-                .nth(1)
-                .map(|s| s.trim());
-            if let Some(date_str) = date_str {
-                if let Ok(exp_date) = NaiveDate::parse_from_str(date_str, "%Y/%m/%d") {
-                    let today = chrono::Local::now().naive_local().date();
-                    let status = analyze_expiration_date(&exp_date, &today);
-                    Ok(LicenseCheckResult {
-                        host: host,
-                        status: status,
-                        error: None,
-                        debug_log: output,
-                    })
-                } else {
-                    Ok(LicenseCheckResult {
-                        host: host,
-                        status: "[ERROR] Invalid Date Format".to_string(),
-                        error: Some("Could not parse expiration date".to_string()),
-                        debug_log: output,
-                    })
-                }
-            } else {
-                Ok(LicenseCheckResult {
-                    host: host,
-                    status: "[ERROR] Invalid License Format".to_string(),
-                    error: Some("Could not find expiration date in license file".to_string()),
-                    debug_log: output,
-                })
-            }
-        }
-        Err(e) =>
-            Ok(LicenseCheckResult {
-                host: host,
-                status: "[ERROR] License File Not Found".to_string(),
-                error: Some(format!("Failed to read license file: {}", e)),
-                debug_log: format!("spawn ssh -o StrictHostKeyChecking=no ph-admin@vg-ph-fon.local cat '/Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic' | grep Expires\n\n(ph-admin@vg-ph-fon.local) Password:\n{}", e),
-            }),
+    crate::server::license::check_and_record(host).await.map_err(ServerFnError::new)
+}
+
+// One row per host × license file from the latest stored checks
+#[server]
+pub async fn get_license_dashboard(group: Option<String>) -> Result<Vec<LicenseRow>, ServerFnError> {
+    let mut results = crate::server::license::latest_results();
+    if let Some(group) = group {
+        let hosts = crate::server::groups::hosts_for_group(&group).map_err(ServerFnError::new)?;
+        results.retain(|r| hosts.contains(&r.host));
     }
+    Ok(crate::server::license::dashboard_rows(&results))
 }
 
-pub async fn get_software_info(host: String) -> Result<SoftwareInfo, ServerFnError> {
-    // Define all license check functions as futures
-    let comet_check = check_expired_adobe_plugin_comet_license(host.clone()).await?;
+#[server]
+pub async fn recheck_licenses(target: LicenseTarget) -> Result<Vec<LicenseCheckResult>, ServerFnError> {
+    let hosts = crate::server::license::hosts_for_target(&target).map_err(ServerFnError::new)?;
+    log::info!("Re-checking licenses on {} hosts", hosts.len());
+    let max_concurrency = crate::configs::get_refresh_max_concurrency();
+    crate::server::license::check_hosts(hosts, max_concurrency).await.map_err(ServerFnError::new)
+}
+
+// Stored check rows, oldest first, for one host and/or product
+#[server]
+pub async fn get_license_history(
+    host: Option<String>,
+    product: Option<String>
+) -> Result<Vec<LicenseRow>, ServerFnError> {
+    let mut rows = crate::server::license::license_history(host.as_deref());
+    if let Some(product) = product {
+        rows.retain(|row| row.product == product);
+    }
+    Ok(rows)
+}
+
+#[server]
+pub async fn get_license_timeline(host: String) -> Result<LicenseTimeline, ServerFnError> {
+    let rows = crate::server::license::license_history(Some(&host));
+    Ok(crate::server::license::build_timeline(&host, &rows))
+}
 
-    // Currently only have one check, but ready for future expansion
-    // When adding more checks, consider using futures::join! or futures::try_join!
+// Products with a license rule, for choosing what a new license file is for
+#[server]
+pub async fn get_license_products() -> Result<Vec<String>, ServerFnError> {
+    Ok(
+        crate::server::license::load_license_rules()
+            .into_iter()
+            .map(|rule| rule.product)
+            .collect()
+    )
+}
+
+// Parses an uploaded license file the way the license check would
+#[server]
+pub async fn preview_license_file(product: String, content: String) -> Result<ProductLicense, ServerFnError> {
+    crate::server::license::deploy::preview_license_file(&product, &content).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn deploy_license_file(request: LicenseDeployRequest) -> Result<LicenseDeployment, ServerFnError> {
+    crate::server::license::deploy::deploy_license(request).await.map_err(ServerFnError::new)
+}
 
-    Ok(SoftwareInfo {
-        priint_comet_2017_expiry: comet_check.status,
-    })
+#[server]
+pub async fn list_license_deployments() -> Result<Vec<LicenseDeployment>, ServerFnError> {
+    crate::server::license::deploy::list_deployments().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn rollback_license_deployment(id: String) -> Result<LicenseDeployment, ServerFnError> {
+    crate::server::license::deploy::rollback_deployment(&id).await.map_err(ServerFnError::new)
+}
+
+pub async fn get_software_info(host: String) -> Result<SoftwareInfo, ServerFnError> {
+    let check = check_expired_adobe_plugin_comet_license(host).await?;
+    Ok(SoftwareInfo { licenses: check.licenses })
 }
 
 #[server]
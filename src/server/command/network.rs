@@ -89,7 +89,11 @@ pub async fn resolve_network_info() -> Result<DiscoveryResult, ServerFnError> {
 
 #[server]
 pub async fn resolve_computer(host: String) -> Result<ComputerInfo, ServerFnError> {
-    ComputerInfo::resolve(host).await
+    let info = ComputerInfo::resolve(host.clone()).await?;
+    if let Err(e) = crate::server::inventory::record_resolution(&host, &info) {
+        log::error!("Failed to store inventory for {}: {}", host, e);
+    }
+    Ok(info)
 }
 
 #[server]
@@ -160,7 +164,8 @@ pub async fn execute_concurrent_commands(
                 let host_clone = host.clone();
                 async move {
                     let start = std::time::Instant::now();
-                    let result = ssh_exec(host_clone.clone(), host_clone, password, cmd).await;
+                    // Same account as inventory and license checks, not one named after the host
+                    let result = ssh_exec(host_clone, crate::configs::get_ssh_user(), password, cmd).await;
 
                 if let Ok(mut count) = completed.lock() {
                     *count += 1;
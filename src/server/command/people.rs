@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+use crate::server::people::{ Assignment, Location, Person, PersonOverview };
+
+#[server]
+pub async fn list_people() -> Result<Vec<Person>, ServerFnError> {
+    crate::server::people::list_people().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn save_person(person: Person) -> Result<Person, ServerFnError> {
+    crate::server::people::save_person(person).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn delete_person(id: String) -> Result<(), ServerFnError> {
+    crate::server::people::delete_person(&id).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn list_locations() -> Result<Vec<Location>, ServerFnError> {
+    crate::server::people::list_locations().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn save_location(location: Location) -> Result<Location, ServerFnError> {
+    crate::server::people::save_location(location).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn delete_location(id: String) -> Result<(), ServerFnError> {
+    crate::server::people::delete_location(&id).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn check_out_machine(
+    host: String,
+    person_id: Option<String>,
+    location_id: Option<String>,
+    note: Option<String>
+) -> Result<Assignment, ServerFnError> {
+    let serial = crate::server::inventory
+        ::get_record(&host)
+        .map(|record| record.info.serial)
+        .filter(|serial| !serial.is_empty());
+    crate::server::people
+        ::check_out(&host, serial, person_id, location_id, note)
+        .map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn check_in_machine(host: String, note: Option<String>) -> Result<Assignment, ServerFnError> {
+    crate::server::people::check_in(&host, note).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn get_active_assignments() -> Result<Vec<Assignment>, ServerFnError> {
+    crate::server::people::active_assignments().map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn get_assignment_history(host: String) -> Result<Vec<Assignment>, ServerFnError> {
+    crate::server::people::assignment_history(|a| a.host == host).map_err(ServerFnError::new)
+}
+
+#[server]
+pub async fn get_person_overview(id: String) -> Result<PersonOverview, ServerFnError> {
+    use crate::server::people::{ self, AssignedMachine };
+
+    let person = people
+        ::get_person(&id)
+        .map_err(ServerFnError::new)?
+        .ok_or_else(|| ServerFnError::new(format!("Unknown person: {}", id)))?;
+    let location = match &person.location_id {
+        Some(location_id) => people::get_location(location_id).map_err(ServerFnError::new)?,
+        None => None,
+    };
+    let history = people
+        ::assignment_history(|a| a.person_id.as_deref() == Some(id.as_str()))
+        .map_err(ServerFnError::new)?;
+    let machines = history
+        .iter()
+        .filter(|a| a.is_active())
+        .map(|assignment| AssignedMachine {
+            assignment: assignment.clone(),
+            record: crate::server::inventory::get_record(&assignment.host),
+        })
+        .collect();
+
+    let assigned: Vec<String> = people
+        ::active_assignments()
+        .map_err(ServerFnError::new)?
+        .into_iter()
+        .map(|a| a.host)
+        .collect();
+    let suggested_hosts = crate::server::inventory
+        ::all_records()
+        .into_iter()
+        .map(|record| record.host)
+        .filter(|host| !assigned.contains(host))
+        .filter(|host| people::owner_from_hostname(host).as_deref() == Some(id.as_str()))
+        .collect();
+
+    Ok(PersonOverview { person, location, machines, history, suggested_hosts })
+}
@@ -0,0 +1,8 @@
+pub mod types;
+pub mod store;
+pub mod reconcile;
+
+// Re-export commonly used items
+pub use types::{ Entitlement, ReconciliationRow, SeatStatus };
+pub use store::{ delete_entitlement, list_entitlements, save_entitlement };
+pub use reconcile::{ reconcile, reconciliation_table, render_reconciliation };
@@ -0,0 +1,143 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use chrono::NaiveDate;
+use super::types::{ Entitlement, ReconciliationRow, SeatStatus };
+use crate::server::groups::{ field_values, Rule };
+use crate::server::inventory::export::LIST_SEPARATOR;
+use crate::server::inventory::{ render_table, ExportColumn, ExportFile, ExportFormat, InventoryRecord };
+use crate::server::license::{ LicenseCheckResult, LicenseStatus };
+
+#[derive(Default)]
+struct ProductSeats {
+    name: String,
+    vendors: BTreeSet<String>,
+    seats: u32,
+    contract_end: Option<NaiveDate>,
+    hosts: BTreeSet<String>,
+    entitlement_ids: Vec<String>,
+}
+
+// Products are keyed case-insensitively, named as first seen
+fn product<'a>(products: &'a mut BTreeMap<String, ProductSeats>, name: &str) -> &'a mut ProductSeats {
+    let entry = products.entry(name.trim().to_lowercase()).or_default();
+    if entry.name.is_empty() {
+        entry.name = name.trim().to_string();
+    }
+    entry
+}
+
+// Compares active entitlements with where each product was found: hosts whose
+// latest license check found a license file for it, hosts where the Adobe
+// collector found it, and hosts matching the entitlement's installed rule.
+// Products with license files but no entitlement are reported as over-deployed.
+pub fn reconcile(
+    entitlements: &[Entitlement],
+    results: &[LicenseCheckResult],
+    records: &[InventoryRecord],
+    tags: &BTreeMap<String, BTreeSet<String>>,
+    today: &NaiveDate
+) -> Result<Vec<ReconciliationRow>, String> {
+    let mut products: BTreeMap<String, ProductSeats> = BTreeMap::new();
+    for entitlement in entitlements {
+        let seats = product(&mut products, &entitlement.product);
+        seats.entitlement_ids.push(entitlement.id.clone());
+        if let Some(vendor) = &entitlement.vendor {
+            seats.vendors.insert(vendor.clone());
+        }
+        if entitlement.is_active(today) {
+            seats.seats += entitlement.seats;
+            if let Some(end) = entitlement.contract_end {
+                seats.contract_end = Some(seats.contract_end.map_or(end, |current| current.min(end)));
+            }
+        }
+        if let Some(rule) = &entitlement.installed_rule {
+            let rule = Rule::parse(rule)?;
+            seats.hosts.extend(
+                records
+                    .iter()
+                    .filter(|record| rule.matches(&(|field: &str| field_values(field, record, tags))))
+                    .map(|record| record.host.clone())
+            );
+        }
+    }
+
+    // Collectors find every Adobe app, so only products someone bought seats
+    // for are counted from the inventory
+    for record in records {
+        for installed in record.info.adobe.installed_products() {
+            if let Some(seats) = products.get_mut(&installed.to_lowercase()) {
+                seats.hosts.insert(record.host.clone());
+            }
+        }
+    }
+
+    for result in results {
+        for license in &result.licenses {
+            if license.path.is_some() && license.status != LicenseStatus::NotFound {
+                product(&mut products, &license.product).hosts.insert(result.host.clone());
+            }
+        }
+    }
+
+    Ok(
+        products
+            .into_values()
+            .map(|p| {
+                let installed = p.hosts.len() as u32;
+                let status = match installed.cmp(&p.seats) {
+                    std::cmp::Ordering::Greater => SeatStatus::OverDeployed,
+                    std::cmp::Ordering::Less => SeatStatus::Unused,
+                    std::cmp::Ordering::Equal => SeatStatus::Balanced,
+                };
+                ReconciliationRow {
+                    product: p.name,
+                    vendors: p.vendors.into_iter().collect(),
+                    seats: p.seats,
+                    installed,
+                    status,
+                    contract_end: p.contract_end,
+                    hosts: p.hosts.into_iter().collect(),
+                    entitlement_ids: p.entitlement_ids,
+                }
+            })
+            .collect()
+    )
+}
+
+pub fn reconciliation_table(rows: &[ReconciliationRow]) -> (Vec<ExportColumn>, Vec<Vec<String>>) {
+    let columns = [
+        ("product", "Product"),
+        ("vendors", "Vendor"),
+        ("seats", "Seats"),
+        ("installed", "Installed"),
+        ("unused_seats", "Unused seats"),
+        ("over_deployed", "Over-deployed"),
+        ("status", "Status"),
+        ("contract_end", "Contract end"),
+        ("hosts", "Hosts"),
+    ]
+        .iter()
+        .map(|(key, label)| ExportColumn { key: key.to_string(), label: label.to_string() })
+        .collect();
+    let rows = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.product.clone(),
+                row.vendors.join(LIST_SEPARATOR),
+                row.seats.to_string(),
+                row.installed.to_string(),
+                row.unused_seats().to_string(),
+                row.over_deployed().to_string(),
+                format!("{:?}", row.status),
+                row.contract_end.map(|d| d.to_string()).unwrap_or_default(),
+                row.hosts.join(LIST_SEPARATOR),
+            ]
+        })
+        .collect();
+    (columns, rows)
+}
+
+pub fn render_reconciliation(rows: &[ReconciliationRow], format: ExportFormat) -> Result<ExportFile, String> {
+    let (columns, rows) = reconciliation_table(rows);
+    render_table(format, "Seat reconciliation", &columns, &rows)
+}
@@ -0,0 +1,60 @@
+use std::sync::Mutex;
+use super::types::Entitlement;
+use crate::server::groups::validate_rule;
+use crate::server::people::store::make_id;
+use crate::server::store;
+
+const ENTITLEMENTS_FILE: &str = "entitlements.json";
+
+lazy_static::lazy_static! {
+    static ref ENTITLEMENTS: Mutex<Vec<Entitlement>> = Mutex::new(store::load(ENTITLEMENTS_FILE));
+}
+
+pub fn list_entitlements() -> Result<Vec<Entitlement>, String> {
+    let mut entitlements = ENTITLEMENTS.lock().map_err(|e| e.to_string())?.clone();
+    entitlements.sort_by(|a, b| a.product.to_lowercase().cmp(&b.product.to_lowercase()).then(a.id.cmp(&b.id)));
+    Ok(entitlements)
+}
+
+// Creates the entitlement when `id` is empty, otherwise updates it
+pub fn save_entitlement(entitlement: Entitlement) -> Result<Entitlement, String> {
+    let product = entitlement.product.trim().to_string();
+    if product.is_empty() {
+        return Err("Product is required".to_string());
+    }
+    if let (Some(start), Some(end)) = (entitlement.contract_start, entitlement.contract_end) {
+        if end < start {
+            return Err("Contract ends before it starts".to_string());
+        }
+    }
+    let installed_rule = entitlement.installed_rule
+        .as_deref()
+        .map(str::trim)
+        .filter(|rule| !rule.is_empty())
+        .map(|rule| validate_rule(rule).map(|_| rule.to_string()))
+        .transpose()?;
+    let entitlement = Entitlement { product, installed_rule, ..entitlement };
+
+    let mut entitlements = ENTITLEMENTS.lock().map_err(|e| e.to_string())?;
+    let entitlement = if entitlement.id.is_empty() {
+        let taken: Vec<&str> = entitlements.iter().map(|e| e.id.as_str()).collect();
+        let entitlement = Entitlement { id: make_id(&entitlement.product, &taken), ..entitlement };
+        entitlements.push(entitlement.clone());
+        entitlement
+    } else {
+        let existing = entitlements
+            .iter_mut()
+            .find(|e| e.id == entitlement.id)
+            .ok_or_else(|| format!("Unknown entitlement: {}", entitlement.id))?;
+        *existing = entitlement.clone();
+        entitlement
+    };
+    store::save(ENTITLEMENTS_FILE, &*entitlements)?;
+    Ok(entitlement)
+}
+
+pub fn delete_entitlement(id: &str) -> Result<(), String> {
+    let mut entitlements = ENTITLEMENTS.lock().map_err(|e| e.to_string())?;
+    entitlements.retain(|e| e.id != id);
+    store::save(ENTITLEMENTS_FILE, &*entitlements)
+}
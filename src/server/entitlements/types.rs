@@ -0,0 +1,60 @@
+use chrono::NaiveDate;
+use serde::{ Serialize, Deserialize };
+
+// Seats bought for a product under one contract. Several entitlements for
+// the same product add up.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Entitlement {
+    pub id: String,
+    // Matched case-insensitively against license rule products, e.g. "priint:comet"
+    pub product: String,
+    pub vendor: Option<String>,
+    pub seats: u32,
+    pub contract_start: Option<NaiveDate>,
+    pub contract_end: Option<NaiveDate>,
+    // Group rule selecting hosts with the product installed, for products
+    // found by collectors rather than license checks, e.g. `custom.indesign ~ "2024"`
+    pub installed_rule: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl Entitlement {
+    // Seats only count while the contract runs
+    pub fn is_active(&self, today: &NaiveDate) -> bool {
+        self.contract_start.is_none_or(|start| start <= *today) &&
+            self.contract_end.is_none_or(|end| end >= *today)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum SeatStatus {
+    Balanced,
+    // Fewer installations than seats
+    Unused,
+    // More installations than seats, including products with none bought
+    OverDeployed,
+}
+
+// One product's purchased seats against the hosts it was found on
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReconciliationRow {
+    pub product: String,
+    pub vendors: Vec<String>,
+    pub seats: u32,
+    pub installed: u32,
+    pub status: SeatStatus,
+    // Earliest end among the active contracts
+    pub contract_end: Option<NaiveDate>,
+    pub hosts: Vec<String>,
+    pub entitlement_ids: Vec<String>,
+}
+
+impl ReconciliationRow {
+    pub fn unused_seats(&self) -> u32 {
+        self.seats.saturating_sub(self.installed)
+    }
+
+    pub fn over_deployed(&self) -> u32 {
+        self.installed.saturating_sub(self.seats)
+    }
+}
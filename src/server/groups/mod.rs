@@ -0,0 +1,20 @@
+pub mod types;
+pub mod rule;
+pub mod store;
+
+// Re-export commonly used items
+pub use types::{ Group, GroupKind, GroupSummary };
+pub use rule::{ Operator, Rule };
+pub use store::{
+    all_host_tags,
+    delete_group,
+    field_values,
+    get_group,
+    group_members,
+    hosts_for_group,
+    list_groups,
+    records_in_group,
+    save_group,
+    set_host_tags,
+    validate_rule,
+};
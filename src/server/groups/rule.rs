@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+use serde::{ Serialize, Deserialize };
+
+// Rules select hosts by their inventory fields, e.g.
+// `architecture == x86_64 AND (version < 14 OR tag == render)`.
+// A bare word or quoted phrase matches any field containing it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Contains,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Rule {
+    Compare {
+        field: String,
+        op: Operator,
+        value: String,
+    },
+    And(Box<Rule>, Box<Rule>),
+    Or(Box<Rule>, Box<Rule>),
+    Not(Box<Rule>),
+    Text(String),
+}
+
+// Field name `lookup` receives for free-text terms; it should return every value of the host
+pub const ANY_FIELD: &str = "*";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(Operator),
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(ch) if ch == c => break,
+                        Some(ch) => value.push(ch),
+                        None => {
+                            return Err("Unterminated quoted value".to_string());
+                        }
+                    }
+                }
+                tokens.push(Token::Quoted(value));
+            }
+            '=' | '!' | '<' | '>' | '~' => {
+                chars.next();
+                let followed_by_eq = chars.peek() == Some(&'=');
+                if followed_by_eq {
+                    chars.next();
+                }
+                let op = match (c, followed_by_eq) {
+                    ('=', true) => Operator::Eq,
+                    ('!', true) => Operator::NotEq,
+                    ('<', false) => Operator::Lt,
+                    ('<', true) => Operator::LtEq,
+                    ('>', false) => Operator::Gt,
+                    ('>', true) => Operator::GtEq,
+                    ('~', false) => Operator::Contains,
+                    _ => {
+                        return Err(format!("Unknown operator near '{}'", c));
+                    }
+                };
+                tokens.push(Token::Op(op));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || "()=!<>~\"'".contains(ch) {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn or(&mut self) -> Result<Rule, String> {
+        let mut rule = self.and()?;
+        while self.keyword("OR") {
+            self.pos += 1;
+            rule = Rule::Or(Box::new(rule), Box::new(self.and()?));
+        }
+        Ok(rule)
+    }
+
+    fn and(&mut self) -> Result<Rule, String> {
+        let mut rule = self.unary()?;
+        while self.keyword("AND") {
+            self.pos += 1;
+            rule = Rule::And(Box::new(rule), Box::new(self.unary()?));
+        }
+        Ok(rule)
+    }
+
+    fn unary(&mut self) -> Result<Rule, String> {
+        if self.keyword("NOT") {
+            self.pos += 1;
+            return Ok(Rule::Not(Box::new(self.unary()?)));
+        }
+        if self.peek() == Some(&Token::Open) {
+            self.pos += 1;
+            let rule = self.or()?;
+            return match self.next() {
+                Some(Token::Close) => Ok(rule),
+                _ => Err("Missing closing parenthesis".to_string()),
+            };
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Rule, String> {
+        let is_text = !matches!(self.tokens.get(self.pos + 1), Some(Token::Op(_)));
+        let field = match self.next() {
+            Some(Token::Word(text)) | Some(Token::Quoted(text)) if is_text => {
+                return Ok(Rule::Text(text));
+            }
+            Some(Token::Word(field)) => field.to_lowercase(),
+            Some(other) => {
+                return Err(format!("Expected a field name, found {:?}", other));
+            }
+            None => {
+                return Err("Expected a field name".to_string());
+            }
+        };
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            _ => {
+                return Err(format!("Expected an operator after '{}'", field));
+            }
+        };
+        let value = match self.next() {
+            Some(Token::Word(value)) | Some(Token::Quoted(value)) => value,
+            _ => {
+                return Err(format!("Expected a value after '{}'", field));
+            }
+        };
+        Ok(Rule::Compare { field, op, value })
+    }
+}
+
+impl Rule {
+    pub fn parse(input: &str) -> Result<Rule, String> {
+        let mut parser = Parser { tokens: tokenize(input)?, pos: 0 };
+        if parser.tokens.is_empty() {
+            return Err("Rule is empty".to_string());
+        }
+        let rule = parser.or()?;
+        match parser.peek() {
+            None => Ok(rule),
+            Some(token) => Err(format!("Unexpected {:?}", token)),
+        }
+    }
+
+    // Every field the rule refers to, for validation before saving
+    pub fn fields(&self) -> Vec<&str> {
+        match self {
+            Rule::Compare { field, .. } => vec![field.as_str()],
+            Rule::And(a, b) | Rule::Or(a, b) => {
+                let mut fields = a.fields();
+                fields.extend(b.fields());
+                fields
+            }
+            Rule::Not(rule) => rule.fields(),
+            Rule::Text(_) => Vec::new(),
+        }
+    }
+
+    // `lookup` returns the field's values for the host being tested; list
+    // fields such as tags match when any of their values does.
+    pub fn matches(&self, lookup: &dyn Fn(&str) -> Vec<String>) -> bool {
+        match self {
+            Rule::Compare { field, op, value } => {
+                let values = lookup(field);
+                match op {
+                    Operator::NotEq => !values.iter().any(|v| compare(v, Operator::Eq, value)),
+                    _ => values.iter().any(|v| compare(v, *op, value)),
+                }
+            }
+            Rule::And(a, b) => a.matches(lookup) && b.matches(lookup),
+            Rule::Or(a, b) => a.matches(lookup) || b.matches(lookup),
+            Rule::Not(rule) => !rule.matches(lookup),
+            Rule::Text(text) => lookup(ANY_FIELD).iter().any(|v| compare(v, Operator::Contains, text)),
+        }
+    }
+}
+
+// First dotted number in a value, so "macOS 14.4.1 (23E224)" compares as 14.4.1
+fn version_parts(value: &str) -> Option<Vec<u64>> {
+    let start = value.find(|c: char| c.is_ascii_digit())?;
+    let token: String = value[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    token
+        .split('.')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<u64>().ok())
+        .collect()
+}
+
+fn compare_versions(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub fn compare(actual: &str, op: Operator, expected: &str) -> bool {
+    let actual_lower = actual.to_lowercase();
+    let expected_lower = expected.to_lowercase();
+    match op {
+        Operator::Eq => actual_lower == expected_lower,
+        Operator::NotEq => actual_lower != expected_lower,
+        Operator::Contains => actual_lower.contains(&expected_lower),
+        _ => {
+            let ordering = match (version_parts(actual), version_parts(expected)) {
+                (Some(a), Some(b)) => compare_versions(&a, &b),
+                // A host without the value never satisfies a numeric bound
+                (None, Some(_)) => {
+                    return false;
+                }
+                _ => actual_lower.cmp(&expected_lower),
+            };
+            match op {
+                Operator::Lt => ordering == Ordering::Less,
+                Operator::LtEq => ordering != Ordering::Greater,
+                Operator::Gt => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            }
+        }
+    }
+}
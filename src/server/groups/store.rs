@@ -0,0 +1,205 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use std::sync::Mutex;
+use serde::{ Serialize, Deserialize };
+use super::rule::{ Rule, ANY_FIELD };
+use super::types::{ Group, GroupKind };
+use crate::server::inventory::export::{ column_values, export_columns, CUSTOM_PREFIX };
+use crate::server::inventory::InventoryRecord;
+use crate::server::people::store::make_id;
+use crate::server::store;
+
+const GROUPS_FILE: &str = "groups.json";
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+struct GroupsData {
+    tags: BTreeMap<String, BTreeSet<String>>,
+    groups: Vec<Group>,
+}
+
+lazy_static::lazy_static! {
+    static ref GROUPS: Mutex<GroupsData> = Mutex::new(store::load(GROUPS_FILE));
+}
+
+// Changes a copy and only keeps it once it's saved, so a failed save leaves
+// memory matching the file
+fn with_data<T>(update: impl FnOnce(&mut GroupsData) -> Result<T, String>) -> Result<T, String> {
+    let mut data = GROUPS.lock().map_err(|e| e.to_string())?;
+    let mut updated = data.clone();
+    let result = update(&mut updated)?;
+    store::save(GROUPS_FILE, &updated)?;
+    *data = updated;
+    Ok(result)
+}
+
+fn read_data<T>(read: impl FnOnce(&GroupsData) -> T) -> Result<T, String> {
+    let data = GROUPS.lock().map_err(|e| e.to_string())?;
+    Ok(read(&data))
+}
+
+// Tags are trimmed, lowercased and de-duplicated
+pub fn normalize_tags(tags: &[String]) -> BTreeSet<String> {
+    tags.iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+pub fn set_host_tags(host: &str, tags: &[String]) -> Result<BTreeSet<String>, String> {
+    let tags = normalize_tags(tags);
+    with_data(|data| {
+        if tags.is_empty() {
+            data.tags.remove(host);
+        } else {
+            data.tags.insert(host.to_string(), tags.clone());
+        }
+        Ok(tags)
+    })
+}
+
+pub fn all_host_tags() -> Result<BTreeMap<String, BTreeSet<String>>, String> {
+    read_data(|data| data.tags.clone())
+}
+
+pub fn list_groups() -> Result<Vec<Group>, String> {
+    read_data(|data| {
+        let mut groups = data.groups.clone();
+        groups.sort_by_key(|g| g.name.to_lowercase());
+        groups
+    })
+}
+
+pub fn get_group(id: &str) -> Result<Option<Group>, String> {
+    read_data(|data| data.groups.iter().find(|g| g.id == id).cloned())
+}
+
+// Fields a dynamic rule may use: export columns plus `tag`
+pub fn rule_fields() -> Vec<String> {
+    let mut fields: Vec<String> = export_columns(&[])
+        .into_iter()
+        .map(|c| c.key)
+        .collect();
+    fields.push("tag".to_string());
+    fields
+}
+
+pub fn validate_rule(rule: &str) -> Result<Rule, String> {
+    let parsed = Rule::parse(rule)?;
+    let known = rule_fields();
+    for field in parsed.fields() {
+        if !field.starts_with(CUSTOM_PREFIX) && !known.iter().any(|k| k == field) {
+            return Err(format!("Unknown field: {}", field));
+        }
+    }
+    Ok(parsed)
+}
+
+// Creates the group when `id` is empty, otherwise updates it
+pub fn save_group(group: Group) -> Result<Group, String> {
+    if group.name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    let kind = match group.kind {
+        GroupKind::Dynamic(rule) => {
+            validate_rule(&rule)?;
+            GroupKind::Dynamic(rule.trim().to_string())
+        }
+        GroupKind::Static(members) => {
+            let members: BTreeSet<String> = members
+                .iter()
+                .map(|m| m.trim().to_string())
+                .filter(|m| !m.is_empty())
+                .collect();
+            GroupKind::Static(members.into_iter().collect())
+        }
+    };
+    let group = Group { kind, ..group };
+
+    with_data(|data| {
+        if group.id.is_empty() {
+            let taken: Vec<&str> = data.groups.iter().map(|g| g.id.as_str()).collect();
+            let group = Group { id: make_id(&group.name, &taken), ..group };
+            data.groups.push(group.clone());
+            return Ok(group);
+        }
+        let existing = data.groups
+            .iter_mut()
+            .find(|g| g.id == group.id)
+            .ok_or_else(|| format!("Unknown group: {}", group.id))?;
+        *existing = group.clone();
+        Ok(group)
+    })
+}
+
+pub fn delete_group(id: &str) -> Result<(), String> {
+    with_data(|data| {
+        data.groups.retain(|g| g.id != id);
+        Ok(())
+    })
+}
+
+// Hosts in the group. Dynamic groups are evaluated against `records`;
+// static members are returned as listed even if they were never resolved.
+pub fn group_members(
+    group: &Group,
+    records: &[InventoryRecord],
+    tags: &BTreeMap<String, BTreeSet<String>>
+) -> Result<Vec<String>, String> {
+    match &group.kind {
+        GroupKind::Static(members) => Ok(members.clone()),
+        GroupKind::Dynamic(rule) => {
+            let rule = Rule::parse(rule)?;
+            Ok(
+                records
+                    .iter()
+                    .filter(|record| rule.matches(&(|field: &str| field_values(field, record, tags))))
+                    .map(|record| record.host.clone())
+                    .collect()
+            )
+        }
+    }
+}
+
+// Values a rule compares for one host: a column, the host's tags, or for
+// free-text terms everything at once
+pub fn field_values(
+    field: &str,
+    record: &InventoryRecord,
+    tags: &BTreeMap<String, BTreeSet<String>>
+) -> Vec<String> {
+    match field {
+        "tag" =>
+            tags
+                .get(&record.host)
+                .map(|t| t.iter().cloned().collect())
+                .unwrap_or_default(),
+        ANY_FIELD => {
+            let mut values: Vec<String> = export_columns(std::slice::from_ref(record))
+                .iter()
+                .flat_map(|column| column_values(&column.key, record))
+                .collect();
+            values.extend(field_values("tag", record, tags));
+            values
+        }
+        _ => column_values(field, record),
+    }
+}
+
+// Members of the group with the given id, using the stored inventory
+pub fn hosts_for_group(id: &str) -> Result<Vec<String>, String> {
+    let group = get_group(id)?.ok_or_else(|| format!("Unknown group: {}", id))?;
+    let records = crate::server::inventory::all_records();
+    group_members(&group, &records, &all_host_tags()?)
+}
+
+// Narrows records to the group's members; `None` keeps them all
+pub fn records_in_group(
+    records: Vec<InventoryRecord>,
+    group_id: Option<&str>
+) -> Result<Vec<InventoryRecord>, String> {
+    let Some(group_id) = group_id else {
+        return Ok(records);
+    };
+    let group = get_group(group_id)?.ok_or_else(|| format!("Unknown group: {}", group_id))?;
+    let members = group_members(&group, &records, &all_host_tags()?)?;
+    Ok(records.into_iter().filter(|r| members.contains(&r.host)).collect())
+}
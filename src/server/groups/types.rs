@@ -0,0 +1,28 @@
+use serde::{ Serialize, Deserialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum GroupKind {
+    // Explicit list of hosts
+    Static(Vec<String>),
+    // Rule over inventory fields, see `Rule`
+    Dynamic(String),
+}
+
+impl Default for GroupKind {
+    fn default() -> Self {
+        GroupKind::Static(Vec::new())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    pub kind: GroupKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GroupSummary {
+    pub group: Group,
+    pub members: Vec<String>,
+}
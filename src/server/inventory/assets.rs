@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use chrono::NaiveDate;
+use super::types::{ AssetImportSummary, AssetMetadata, CoverageWarning, ImportRowError };
+use crate::server::license::{ analyze_expiration_date, ExpiryThresholds, LicenseStatus };
+use crate::server::store;
+
+const ASSETS_FILE: &str = "assets.json";
+
+// Spreadsheet exports use either ISO or day-first dates
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"];
+
+lazy_static::lazy_static! {
+    static ref ASSETS: Mutex<HashMap<String, AssetMetadata>> = Mutex::new(
+        store::load(ASSETS_FILE)
+    );
+}
+
+pub fn get_asset(serial: &str) -> Option<AssetMetadata> {
+    ASSETS.lock().ok()?.get(serial.trim()).cloned()
+}
+
+pub fn all_assets() -> Vec<AssetMetadata> {
+    let mut assets: Vec<AssetMetadata> = ASSETS.lock()
+        .map(|assets| assets.values().cloned().collect())
+        .unwrap_or_default();
+    assets.sort_by(|a, b| a.serial.cmp(&b.serial));
+    assets
+}
+
+pub fn save_asset(asset: AssetMetadata) -> Result<AssetMetadata, String> {
+    let serial = asset.serial.trim().to_string();
+    if serial.is_empty() {
+        return Err("Serial number is required".to_string());
+    }
+    let asset = AssetMetadata { serial: serial.clone(), ..asset };
+    let mut assets = ASSETS.lock().map_err(|e| e.to_string())?;
+    assets.insert(serial, asset.clone());
+    store::save(ASSETS_FILE, &*assets)?;
+    Ok(asset)
+}
+
+pub fn import_assets_csv(data: &str) -> Result<AssetImportSummary, String> {
+    let (parsed, errors) = parse_assets_csv(data)?;
+    let mut assets = ASSETS.lock().map_err(|e| e.to_string())?;
+    let mut summary = AssetImportSummary { errors, ..Default::default() };
+
+    for asset in parsed {
+        match assets.get_mut(&asset.serial) {
+            Some(existing) => {
+                existing.merge(asset);
+                summary.updated += 1;
+            }
+            None => {
+                assets.insert(asset.serial.clone(), asset);
+                summary.created += 1;
+            }
+        }
+    }
+
+    store::save(ASSETS_FILE, &*assets)?;
+    Ok(summary)
+}
+
+// Parses the asset spreadsheet. Headers are matched case-insensitively and
+// only the serial column is required; bad rows are reported, not fatal.
+pub fn parse_assets_csv(
+    data: &str
+) -> Result<(Vec<AssetMetadata>, Vec<ImportRowError>), String> {
+    let mut reader = csv::ReaderBuilder
+        ::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Failed to read CSV header: {}", e))?
+        .iter()
+        .map(normalize_header)
+        .collect();
+    if !headers.iter().any(|h| h == "serial") {
+        return Err("CSV is missing a serial column".to_string());
+    }
+
+    let mut assets = Vec::new();
+    let mut errors = Vec::new();
+    for (index, row) in reader.records().enumerate() {
+        // Header is line 1
+        let line = index + 2;
+        let row = match row {
+            Ok(row) => row,
+            Err(e) => {
+                errors.push(ImportRowError { line, message: e.to_string() });
+                continue;
+            }
+        };
+        let fields: HashMap<&str, &str> = headers
+            .iter()
+            .map(String::as_str)
+            .zip(row.iter())
+            .filter(|(_, value)| !value.is_empty())
+            .collect();
+        match asset_from_fields(&fields) {
+            Ok(asset) => assets.push(asset),
+            Err(message) => errors.push(ImportRowError { line, message }),
+        }
+    }
+    Ok((assets, errors))
+}
+
+fn normalize_header(header: &str) -> String {
+    let header = header.trim().to_lowercase().replace([' ', '-'], "_");
+    match header.as_str() {
+        "serial_number" | "serial_no" => "serial".to_string(),
+        "invoice" | "invoice_no" => "invoice_number".to_string(),
+        "price" => "cost".to_string(),
+        "applecare" | "apple_care_expiry" | "applecare_expires" => "applecare_expiry".to_string(),
+        "warranty" | "warranty_expires" => "warranty_expiry".to_string(),
+        _ => header,
+    }
+}
+
+fn asset_from_fields(fields: &HashMap<&str, &str>) -> Result<AssetMetadata, String> {
+    let serial = fields
+        .get("serial")
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Missing serial number".to_string())?;
+    let date = |name: &str| -> Result<Option<NaiveDate>, String> {
+        fields
+            .get(name)
+            .map(|value| parse_asset_date(value).ok_or_else(|| format!("Invalid {}: {}", name, value)))
+            .transpose()
+    };
+    let cost = fields
+        .get("cost")
+        .map(|value| parse_cost(value).ok_or_else(|| format!("Invalid cost: {}", value)))
+        .transpose()?;
+
+    Ok(AssetMetadata {
+        serial,
+        purchase_date: date("purchase_date")?,
+        supplier: fields.get("supplier").map(|s| s.to_string()),
+        invoice_number: fields.get("invoice_number").map(|s| s.to_string()),
+        cost,
+        warranty_expiry: date("warranty_expiry")?,
+        applecare_expiry: date("applecare_expiry")?,
+        notes: fields.get("notes").map(|s| s.to_string()),
+    })
+}
+
+pub fn parse_asset_date(value: &str) -> Option<NaiveDate> {
+    DATE_FORMATS.iter().find_map(|format| NaiveDate::parse_from_str(value.trim(), format).ok())
+}
+
+// Accepts spreadsheet-formatted amounts like "฿45,900.00"
+fn parse_cost(value: &str) -> Option<f64> {
+    let digits: String = value
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
+        .collect();
+    digits.parse::<f64>().ok()
+}
+
+// Same thresholds as license expiry: 30 days is a warning, 90 a notice
+pub fn coverage_warnings(asset: &AssetMetadata, today: &NaiveDate) -> Vec<CoverageWarning> {
+    [("Warranty", asset.warranty_expiry), ("AppleCare", asset.applecare_expiry)]
+        .into_iter()
+        .filter_map(|(coverage, expiry)| {
+            let expiry = expiry?;
+            let (status, days_remaining) = analyze_expiration_date(&expiry, today, &ExpiryThresholds::default());
+            if status == LicenseStatus::Valid {
+                return None;
+            }
+            Some(CoverageWarning {
+                serial: asset.serial.clone(),
+                coverage: coverage.to_string(),
+                expires: expiry,
+                status,
+                days_remaining,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,609 @@
+use std::str::FromStr;
+use serde::{ Serialize, Deserialize };
+use super::types::{ InventoryRecord, RecordSource };
+use crate::server::collectors::{ OsFamily, format_bytes };
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+            ExportFormat::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            other => Err(format!("Unknown export format: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ExportFilter {
+    // Case-insensitive match on host, title, serial or user
+    pub text: Option<String>,
+    pub os_family: Option<OsFamily>,
+    pub pending_updates_only: bool,
+}
+
+impl ExportFilter {
+    pub fn matches(&self, record: &InventoryRecord) -> bool {
+        if let Some(os_family) = self.os_family {
+            if record.info.os_family != os_family {
+                return false;
+            }
+        }
+        if self.pending_updates_only && record.info.software_updates.is_empty() {
+            return false;
+        }
+        match self.text.as_deref().map(str::trim) {
+            Some(text) if !text.is_empty() => {
+                let text = text.to_lowercase();
+                [&record.host, &record.info.title, &record.info.serial, &record.info.user]
+                    .iter()
+                    .any(|value| value.to_lowercase().contains(&text))
+            }
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ExportRequest {
+    pub format: ExportFormat,
+    // Column keys in output order; empty means every column
+    pub columns: Vec<String>,
+    pub filter: ExportFilter,
+    // Only members of this group, by group id
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExportColumn {
+    pub key: String,
+    pub label: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExportFile {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+// Lists are flattened into one cell, joined with this separator
+pub const LIST_SEPARATOR: &str = "; ";
+
+pub const CUSTOM_PREFIX: &str = "custom.";
+
+type Getter = fn(&InventoryRecord) -> String;
+type Setter = fn(&mut InventoryRecord, &str) -> Result<(), String>;
+
+pub struct ColumnDef {
+    pub key: &'static str,
+    pub label: &'static str,
+    get: Getter,
+    // Derived columns (admins, battery, ...) have no setter and are ignored on import
+    set: Option<Setter>,
+}
+
+impl ColumnDef {
+    pub fn get(&self, record: &InventoryRecord) -> String {
+        (self.get)(record)
+    }
+
+    pub fn is_importable(&self) -> bool {
+        self.set.is_some()
+    }
+
+    pub fn set(&self, record: &mut InventoryRecord, value: &str) -> Result<(), String> {
+        match self.set {
+            Some(set) => set(record, value),
+            None => Ok(()),
+        }
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(';')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn validate_ip(value: &str) -> Result<String, String> {
+    value
+        .parse::<std::net::IpAddr>()
+        .map(|_| value.to_string())
+        .map_err(|_| format!("Invalid IP address: {}", value))
+}
+
+pub const COLUMNS: &[ColumnDef] = &[
+    ColumnDef {
+        key: "host",
+        label: "Host",
+        get: |r| r.host.clone(),
+        set: Some(|r, v| {
+            r.host = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "title",
+        label: "Title",
+        get: |r| r.info.title.clone(),
+        set: Some(|r, v| {
+            r.info.title = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "product_name",
+        label: "Product",
+        get: |r| r.info.product_name.clone(),
+        set: Some(|r, v| {
+            r.info.product_name = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "serial",
+        label: "Serial",
+        get: |r| r.info.serial.clone(),
+        set: Some(|r, v| {
+            r.info.serial = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "os_family",
+        label: "OS",
+        get: |r| r.info.os_family.to_string(),
+        set: Some(|r, v| {
+            r.info.os_family = match v.to_lowercase().as_str() {
+                "macos" | "mac" => OsFamily::MacOs,
+                "linux" => OsFamily::Linux,
+                "unknown" => OsFamily::Unknown,
+                _ => {
+                    return Err(format!("Unknown OS family: {}", v));
+                }
+            };
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "version",
+        label: "Version",
+        get: |r| r.info.version.clone(),
+        set: Some(|r, v| {
+            r.info.version = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "user",
+        label: "User",
+        get: |r| r.info.user.clone(),
+        set: Some(|r, v| {
+            r.info.user = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "home_users",
+        label: "Home Users",
+        get: |r| r.info.home_users.join(LIST_SEPARATOR),
+        set: Some(|r, v| {
+            r.info.home_users = split_list(v);
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "admins",
+        label: "Admins",
+        get: |r| {
+            r.info.user_accounts
+                .iter()
+                .filter(|a| a.is_admin)
+                .map(|a| a.name.as_str())
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "network_name",
+        label: "Network Name",
+        get: |r| r.info.network_name.clone(),
+        set: Some(|r, v| {
+            r.info.network_name = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "lan_ip",
+        label: "LAN IP",
+        get: |r| r.info.lan_ip.clone(),
+        set: Some(|r, v| {
+            r.info.lan_ip = validate_ip(v)?;
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "wifi_ip",
+        label: "Wi-Fi IP",
+        get: |r| r.info.wifi_ip.clone(),
+        set: Some(|r, v| {
+            r.info.wifi_ip = validate_ip(v)?;
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "wifi_name",
+        label: "Wi-Fi Network",
+        get: |r| r.info.wifi_name.clone(),
+        set: Some(|r, v| {
+            r.info.wifi_name = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "mac_addresses",
+        label: "MAC Addresses",
+        get: |r| {
+            r.info.network_interfaces
+                .iter()
+                .filter(|i| !i.mac_address.is_empty())
+                .map(|i| format!("{}={}", i.device, i.mac_address))
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "processor",
+        label: "CPU",
+        get: |r| r.info.processor.clone(),
+        set: Some(|r, v| {
+            r.info.processor = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "architecture",
+        label: "Arch",
+        get: |r| r.info.architecture.clone(),
+        set: Some(|r, v| {
+            r.info.architecture = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "memory",
+        label: "RAM",
+        get: |r| r.info.memory.clone(),
+        set: Some(|r, v| {
+            r.info.memory = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "graphics",
+        label: "GPU",
+        get: |r| r.info.graphics.clone(),
+        set: Some(|r, v| {
+            r.info.graphics = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "storage",
+        label: "Storage",
+        get: |r| r.info.storage.clone(),
+        set: Some(|r, v| {
+            r.info.storage = v.to_string();
+            Ok(())
+        }),
+    },
+    ColumnDef {
+        key: "storage_free",
+        label: "Storage Free",
+        get: |r| {
+            r.info.storage_details
+                .root_volume()
+                .map(|v| format_bytes(v.free_bytes))
+                .unwrap_or_default()
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "disk_type",
+        label: "Disk Type",
+        get: |r| r.info.storage_details.disk_type.to_string(),
+        set: None,
+    },
+    ColumnDef {
+        key: "battery_condition",
+        label: "Battery",
+        get: |r| r.info.power.condition.clone(),
+        set: None,
+    },
+    ColumnDef {
+        key: "battery_cycles",
+        label: "Battery Cycles",
+        get: |r| r.info.power.cycle_count.map(|c| c.to_string()).unwrap_or_default(),
+        set: None,
+    },
+    ColumnDef {
+        key: "uptime_days",
+        label: "Uptime (days)",
+        get: |r| r.info.power.uptime_days().map(|d| d.to_string()).unwrap_or_default(),
+        set: None,
+    },
+    ColumnDef {
+        key: "pending_updates",
+        label: "Pending Updates",
+        get: |r| {
+            r.info.software_updates
+                .iter()
+                .map(|u| u.label.as_str())
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "adobe_apps",
+        label: "Adobe Apps",
+        get: |r| {
+            r.info.adobe.apps
+                .iter()
+                .map(|a| format!("{} {}", a.name, a.version).trim().to_string())
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "comet_plugins",
+        label: "Comet Plugins",
+        get: |r| {
+            r.info.adobe.comet_plugins
+                .iter()
+                .map(|p| format!("{} ({})", p.version, p.indesign))
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "cc_users",
+        label: "Creative Cloud Users",
+        get: |r| {
+            r.info.adobe.cc_users
+                .iter()
+                .map(|u| u.email.as_str())
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        },
+        set: None,
+    },
+    ColumnDef {
+        key: "last_resolved",
+        label: "Last Refresh",
+        get: |r| r.last_resolved.map(|t| t.to_rfc3339()).unwrap_or_default(),
+        set: None,
+    },
+    ColumnDef {
+        key: "source",
+        label: "Source",
+        get: |r| {
+            match r.source {
+                RecordSource::Ssh => "ssh".to_string(),
+                RecordSource::Manual => "manual".to_string(),
+            }
+        },
+        set: None,
+    },
+];
+
+// Built-in columns followed by one column per custom field seen in `records`
+pub fn export_columns(records: &[InventoryRecord]) -> Vec<ExportColumn> {
+    let mut columns: Vec<ExportColumn> = COLUMNS.iter()
+        .map(|c| ExportColumn { key: c.key.to_string(), label: c.label.to_string() })
+        .collect();
+    let mut custom_fields: Vec<&String> = records
+        .iter()
+        .flat_map(|r| r.info.custom_fields.keys())
+        .collect();
+    custom_fields.sort();
+    custom_fields.dedup();
+    columns.extend(
+        custom_fields.into_iter().map(|field| ExportColumn {
+            key: format!("{}{}", CUSTOM_PREFIX, field),
+            label: field.clone(),
+        })
+    );
+    columns
+}
+
+// Columns whose cell joins several values with LIST_SEPARATOR
+const LIST_COLUMNS: &[&str] = &[
+    "home_users",
+    "admins",
+    "mac_addresses",
+    "pending_updates",
+    "adobe_apps",
+    "comet_plugins",
+    "cc_users",
+];
+
+// A column's values for one record, with list columns split back apart
+pub fn column_values(key: &str, record: &InventoryRecord) -> Vec<String> {
+    let Some(value) = column_value(key, record) else {
+        return Vec::new();
+    };
+    if LIST_COLUMNS.contains(&key) {
+        value
+            .split(LIST_SEPARATOR)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect()
+    } else {
+        vec![value]
+    }
+}
+
+fn column_value(key: &str, record: &InventoryRecord) -> Option<String> {
+    if let Some(field) = key.strip_prefix(CUSTOM_PREFIX) {
+        return Some(record.info.custom_fields.get(field).cloned().unwrap_or_default());
+    }
+    COLUMNS.iter()
+        .find(|c| c.key == key)
+        .map(|c| c.get(record))
+}
+
+// Applies the request's filter and column selection; unknown columns are an error
+pub fn build_export_table(
+    request: &ExportRequest,
+    records: &[InventoryRecord]
+) -> Result<(Vec<ExportColumn>, Vec<Vec<String>>), String> {
+    let available = export_columns(records);
+    let columns: Vec<ExportColumn> = if request.columns.is_empty() {
+        available
+    } else {
+        request.columns
+            .iter()
+            .map(|key| {
+                available
+                    .iter()
+                    .find(|c| &c.key == key)
+                    .cloned()
+                    .or_else(|| {
+                        key.starts_with(CUSTOM_PREFIX).then(|| ExportColumn {
+                            key: key.clone(),
+                            label: key.trim_start_matches(CUSTOM_PREFIX).to_string(),
+                        })
+                    })
+                    .ok_or_else(|| format!("Unknown export column: {}", key))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let rows = records
+        .iter()
+        .filter(|record| request.filter.matches(record))
+        .map(|record| {
+            columns
+                .iter()
+                .map(|c| column_value(&c.key, record).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    Ok((columns, rows))
+}
+
+pub fn render_export(request: &ExportRequest, records: &[InventoryRecord]) -> Result<ExportFile, String> {
+    let (columns, rows) = build_export_table(request, records)?;
+    render_table(request.format, "Inventory", &columns, &rows)
+}
+
+// Renders any report table in one of the export formats, named after `title`
+pub fn render_table(
+    format: ExportFormat,
+    title: &str,
+    columns: &[ExportColumn],
+    rows: &[Vec<String>]
+) -> Result<ExportFile, String> {
+    let data = match format {
+        ExportFormat::Csv => render_csv(columns, rows)?,
+        ExportFormat::Json => render_json(columns, rows)?,
+        ExportFormat::Xlsx => render_xlsx(title, columns, rows)?,
+    };
+    Ok(ExportFile {
+        filename: format!(
+            "{}-{}.{}",
+            title.to_lowercase().replace(' ', "-"),
+            chrono::Local::now().format("%Y-%m-%d"),
+            format.extension()
+        ),
+        content_type: format.content_type().to_string(),
+        data,
+    })
+}
+
+// CSV and JSON use column keys so the file can be imported again
+fn render_csv(columns: &[ExportColumn], rows: &[Vec<String>]) -> Result<Vec<u8>, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(columns.iter().map(|c| c.key.as_str()))
+        .map_err(|e| e.to_string())?;
+    for row in rows {
+        writer.write_record(row).map_err(|e| e.to_string())?;
+    }
+    writer.into_inner().map_err(|e| e.to_string())
+}
+
+fn render_json(columns: &[ExportColumn], rows: &[Vec<String>]) -> Result<Vec<u8>, String> {
+    let objects: Vec<serde_json::Map<String, serde_json::Value>> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .zip(row)
+                .map(|(c, value)| (c.key.clone(), serde_json::Value::String(value.clone())))
+                .collect()
+        })
+        .collect();
+    serde_json::to_vec_pretty(&objects).map_err(|e| e.to_string())
+}
+
+fn render_xlsx(title: &str, columns: &[ExportColumn], rows: &[Vec<String>]) -> Result<Vec<u8>, String> {
+    use rust_xlsxwriter::{ Format, Workbook };
+
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(title).map_err(|e| e.to_string())?;
+    let header = Format::new().set_bold();
+
+    for (col, column) in columns.iter().enumerate() {
+        let col = col as u16;
+        sheet.write_with_format(0, col, &column.label, &header).map_err(|e| e.to_string())?;
+    }
+    for (row_index, row) in rows.iter().enumerate() {
+        for (col, value) in row.iter().enumerate() {
+            sheet
+                .write(row_index as u32 + 1, col as u16, value)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    sheet.set_freeze_panes(1, 0).map_err(|e| e.to_string())?;
+    sheet.autofit();
+    workbook.save_to_buffer().map_err(|e| e.to_string())
+}
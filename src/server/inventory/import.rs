@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use super::export::{ COLUMNS, CUSTOM_PREFIX, ExportFormat, LIST_SEPARATOR };
+use super::store::merge_imported;
+use super::types::{ ImportRowError, InventoryImportSummary, InventoryRecord, RecordSource };
+
+// Where a header points: a built-in column, a custom field, or nothing we import
+enum Target {
+    Column(usize),
+    Custom(String),
+    Ignored,
+}
+
+fn target_for(header: &str) -> Option<Target> {
+    let header = header.trim();
+    if let Some(field) = header.strip_prefix(CUSTOM_PREFIX) {
+        return Some(Target::Custom(field.to_string()));
+    }
+    let index = COLUMNS.iter().position(|c| {
+        c.key.eq_ignore_ascii_case(header) || c.label.eq_ignore_ascii_case(header)
+    })?;
+    Some(if COLUMNS[index].is_importable() { Target::Column(index) } else { Target::Ignored })
+}
+
+// Builds one record from (header, value) pairs, collecting every problem in the row
+fn record_from_row<'a>(
+    cells: impl Iterator<Item = (&'a str, String)>
+) -> Result<InventoryRecord, Vec<String>> {
+    let mut record = InventoryRecord { source: RecordSource::Manual, ..Default::default() };
+    let mut problems = Vec::new();
+
+    for (header, value) in cells {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match target_for(header) {
+            Some(Target::Column(index)) => {
+                if let Err(e) = COLUMNS[index].set(&mut record, value) {
+                    problems.push(e);
+                }
+            }
+            Some(Target::Custom(field)) => {
+                record.info.custom_fields.insert(field, value.to_string());
+            }
+            Some(Target::Ignored) | None => {}
+        }
+    }
+
+    if record.info.serial.is_empty() {
+        problems.push("Missing serial number".to_string());
+    }
+    if problems.is_empty() { Ok(record) } else { Err(problems) }
+}
+
+fn unknown_headers<'a>(headers: impl Iterator<Item = &'a str>, line: usize) -> Vec<ImportRowError> {
+    headers
+        .filter(|h| target_for(h).is_none())
+        .map(|h| ImportRowError { line, message: format!("Unknown column ignored: {}", h) })
+        .collect()
+}
+
+// Parses CSV or JSON in the export schema. Rows with problems are reported and
+// left out; serials repeated within the file keep their first row.
+pub fn parse_inventory_import(
+    data: &str,
+    format: ExportFormat
+) -> Result<(Vec<InventoryRecord>, Vec<ImportRowError>), String> {
+    let (rows, errors) = parse_import_rows(data, format)?;
+    Ok((rows.into_iter().map(|(_, record)| record).collect(), errors))
+}
+
+// Parsed records with the line each came from, and the rows left out
+type ImportRows = (Vec<(usize, InventoryRecord)>, Vec<ImportRowError>);
+
+fn parse_import_rows(data: &str, format: ExportFormat) -> Result<ImportRows, String> {
+    let rows: Vec<(usize, Result<InventoryRecord, Vec<String>>)>;
+    let mut errors;
+
+    match format {
+        ExportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder
+                ::new()
+                .flexible(true)
+                .trim(csv::Trim::All)
+                .from_reader(data.as_bytes());
+            let headers: Vec<String> = reader
+                .headers()
+                .map_err(|e| format!("Failed to read CSV header: {}", e))?
+                .iter()
+                .map(str::to_string)
+                .collect();
+            errors = unknown_headers(headers.iter().map(String::as_str), 1);
+            rows = reader
+                .records()
+                .enumerate()
+                .map(|(index, row)| {
+                    // Header is line 1
+                    let line = index + 2;
+                    let record = row
+                        .map_err(|e| vec![e.to_string()])
+                        .and_then(|row| {
+                            record_from_row(
+                                headers
+                                    .iter()
+                                    .map(String::as_str)
+                                    .zip(row.iter().map(str::to_string))
+                            )
+                        });
+                    (line, record)
+                })
+                .collect();
+        }
+        ExportFormat::Json => {
+            let objects: Vec<serde_json::Map<String, serde_json::Value>> = serde_json
+                ::from_str(data)
+                .map_err(|e| format!("Expected a JSON array of objects: {}", e))?;
+            let mut headers: Vec<&str> = objects
+                .iter()
+                .flat_map(|o| o.keys().map(String::as_str))
+                .collect();
+            headers.sort();
+            headers.dedup();
+            // JSON has no lines; errors refer to the 1-based position in the
+            // array, and 0 to the file as a whole
+            errors = unknown_headers(headers.into_iter(), 0);
+            rows = objects
+                .iter()
+                .enumerate()
+                .map(|(index, object)| {
+                    let cells = object.iter().map(|(key, value)| (key.as_str(), json_cell(value)));
+                    (index + 1, record_from_row(cells))
+                })
+                .collect();
+        }
+        ExportFormat::Xlsx => {
+            return Err("XLSX files can't be imported; export the sheet as CSV first".to_string());
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut records = Vec::new();
+    for (line, result) in rows {
+        match result {
+            Ok(record) if !seen.insert(record.info.serial.clone()) => {
+                errors.push(ImportRowError {
+                    line,
+                    message: format!("Duplicate serial number: {}", record.info.serial),
+                });
+            }
+            Ok(record) => records.push((line, record)),
+            Err(problems) => {
+                errors.extend(problems.into_iter().map(|message| ImportRowError { line, message }));
+            }
+        }
+    }
+    Ok((records, errors))
+}
+
+fn json_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(json_cell)
+            .collect::<Vec<_>>()
+            .join(LIST_SEPARATOR),
+        other => other.to_string(),
+    }
+}
+
+pub fn import_inventory(data: &str, format: ExportFormat) -> Result<InventoryImportSummary, String> {
+    let (records, mut errors) = parse_import_rows(data, format)?;
+    let (created, updated, conflicts) = merge_imported(records)?;
+    errors.extend(conflicts);
+    errors.sort_by_key(|e| e.line);
+    Ok(InventoryImportSummary { created, updated, errors })
+}
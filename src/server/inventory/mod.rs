@@ -0,0 +1,46 @@
+pub mod types;
+pub mod store;
+pub mod report;
+pub mod scheduler;
+pub mod assets;
+pub mod export;
+pub mod import;
+pub mod search;
+
+// Re-export commonly used items
+pub use types::{
+    AssetImportSummary,
+    AssetMetadata,
+    CoverageWarning,
+    ImportRowError,
+    InventoryImportSummary,
+    InventoryRecord,
+    PendingUpdateGroup,
+    RefreshProgress,
+    RecordSource,
+    RefreshTrigger,
+    SavedSearch,
+};
+pub use store::{ all_records, get_record, record_resolution };
+pub use report::build_pending_updates_report;
+pub use scheduler::{ ensure_scheduler_started, get_refresh_progress, start_refresh };
+pub use assets::{ all_assets, coverage_warnings, get_asset, import_assets_csv, save_asset };
+pub use export::{
+    ExportColumn,
+    ExportFile,
+    ExportFilter,
+    ExportFormat,
+    ExportRequest,
+    export_columns,
+    render_export,
+    render_table,
+};
+pub use import::import_inventory;
+pub use search::{
+    compose_query,
+    delete_saved_search,
+    list_saved_searches,
+    save_search,
+    search_inventory,
+    SearchIndex,
+};
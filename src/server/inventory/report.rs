@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+use super::types::{ InventoryRecord, PendingUpdateGroup };
+
+// Groups pending software updates across the fleet by update label, listing
+// the hosts that still need each one.
+pub fn build_pending_updates_report(records: &[InventoryRecord]) -> Vec<PendingUpdateGroup> {
+    let mut groups: BTreeMap<String, PendingUpdateGroup> = BTreeMap::new();
+
+    for record in records {
+        for update in &record.info.software_updates {
+            let group = groups.entry(update.label.clone()).or_insert_with(|| PendingUpdateGroup {
+                label: update.label.clone(),
+                title: update.title.clone(),
+                version: update.version.clone(),
+                recommended: update.recommended,
+                restart_required: update.restart_required,
+                hosts: Vec::new(),
+            });
+            if !group.hosts.contains(&record.host) {
+                group.hosts.push(record.host.clone());
+            }
+        }
+    }
+
+    let mut report: Vec<PendingUpdateGroup> = groups.into_values().collect();
+    for group in &mut report {
+        group.hosts.sort();
+    }
+    report
+}
@@ -0,0 +1,202 @@
+use std::collections::VecDeque;
+use std::sync::{ Arc, Mutex, Once };
+use std::thread;
+use std::time::Duration;
+use chrono::{ Local, Timelike, Utc };
+use log::{ error, info, warn };
+use super::store::{ all_records, record_resolution };
+use super::types::{ RefreshProgress, RefreshTrigger };
+use crate::configs::{
+    get_refresh_interval_minutes,
+    get_refresh_max_concurrency,
+    get_refresh_quiet_hours,
+};
+use crate::server::network::get_scan_state;
+use crate::server::resolve_computer::ComputerInfo;
+
+lazy_static::lazy_static! {
+    static ref REFRESH_PROGRESS: Arc<Mutex<RefreshProgress>> = Arc::new(
+        Mutex::new(RefreshProgress::default())
+    );
+}
+
+pub fn get_refresh_progress() -> RefreshProgress {
+    REFRESH_PROGRESS.lock()
+        .map(|progress| progress.clone())
+        .unwrap_or_default()
+}
+
+// Quiet hours may wrap around midnight ("22-6")
+pub fn is_quiet_hour(hour: u32, quiet_hours: Option<(u32, u32)>) -> bool {
+    match quiet_hours {
+        Some((start, end)) if start <= end => hour >= start && hour < end,
+        Some((start, end)) => hour >= start || hour < end,
+        None => false,
+    }
+}
+
+// Hosts found by the last network scan; before any scan has run, fall back
+// to the hosts already in the inventory.
+pub fn known_online_hosts() -> Vec<String> {
+    let discovered = get_scan_state()
+        .ok()
+        .and_then(|state| state.lock().ok().map(|s| s.discovered_hosts.clone()))
+        .unwrap_or_default();
+    if !discovered.is_empty() {
+        return discovered;
+    }
+    all_records()
+        .into_iter()
+        .map(|record| record.host)
+        .collect()
+}
+
+// Starts a refresh of every known host in the background. Returns false when
+// a refresh is already running.
+pub fn start_refresh(trigger: RefreshTrigger) -> bool {
+    let hosts = known_online_hosts();
+    {
+        let Ok(mut progress) = REFRESH_PROGRESS.lock() else {
+            return false;
+        };
+        if progress.in_progress {
+            return false;
+        }
+        let next_scheduled_run = progress.next_scheduled_run;
+        *progress = RefreshProgress {
+            in_progress: true,
+            trigger: Some(trigger),
+            total: hosts.len(),
+            started_at: Some(Utc::now()),
+            next_scheduled_run,
+            ..Default::default()
+        };
+    }
+
+    info!("Starting {:?} inventory refresh of {} hosts", trigger, hosts.len());
+    thread::spawn(move || {
+        run_batch(hosts, get_refresh_max_concurrency(), &REFRESH_PROGRESS, |host| {
+            let info = futures::executor
+                ::block_on(ComputerInfo::resolve(host.to_string()))
+                .map_err(|e| e.to_string())?;
+            record_resolution(host, &info).map(|_| ())
+        });
+    });
+    true
+}
+
+// Resolves hosts with at most `max_concurrency` SSH sessions at a time.
+pub fn run_batch<F>(
+    hosts: Vec<String>,
+    max_concurrency: usize,
+    progress: &Mutex<RefreshProgress>,
+    resolve: F
+)
+    where F: Fn(&str) -> Result<(), String> + Sync
+{
+    run_workers(hosts, max_concurrency, |host| {
+        if let Ok(mut progress) = progress.lock() {
+            progress.current_hosts.push(host.to_string());
+        }
+
+        let result = resolve(host);
+        if let Err(e) = &result {
+            warn!("Scheduled refresh failed for {}: {}", host, e);
+        }
+
+        if let Ok(mut progress) = progress.lock() {
+            progress.current_hosts.retain(|h| h != host);
+            progress.completed += 1;
+            if result.is_err() {
+                progress.failed.push(host.to_string());
+            }
+        }
+    });
+
+    if let Ok(mut progress) = progress.lock() {
+        progress.in_progress = false;
+        progress.finished_at = Some(Utc::now());
+        info!(
+            "Inventory refresh finished: {}/{} hosts, {} failed",
+            progress.completed,
+            progress.total,
+            progress.failed.len()
+        );
+    }
+}
+
+// Runs `work` for each host with at most `max_concurrency` running at a time.
+// SSH calls block their thread, so each worker gets its own thread. Results
+// come back in the order of `hosts`.
+pub fn run_workers<T, F>(hosts: Vec<String>, max_concurrency: usize, work: F) -> Vec<T>
+    where F: Fn(&str) -> T + Sync, T: Send
+{
+    let workers = max_concurrency.max(1).min(hosts.len());
+    let queue = Mutex::new(hosts.into_iter().enumerate().collect::<VecDeque<_>>());
+    let results = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some((idx, host)) = next_host(&queue) {
+                    let result = work(&host);
+                    if let Ok(mut results) = results.lock() {
+                        results.push((idx, result));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// run_workers for async callers: the batch runs on its own thread, so a
+// server function awaits it instead of holding up a runtime worker
+pub async fn run_workers_async<T, F>(hosts: Vec<String>, max_concurrency: usize, work: F) -> Vec<T>
+    where F: Fn(&str) -> T + Send + Sync + 'static, T: Send + 'static
+{
+    let (tx, rx) = futures::channel::oneshot::channel();
+    thread::spawn(move || {
+        let _ = tx.send(run_workers(hosts, max_concurrency, work));
+    });
+    rx.await.unwrap_or_default()
+}
+
+// Pops in a separate call so the queue isn't locked while a host resolves
+fn next_host(queue: &Mutex<VecDeque<(usize, String)>>) -> Option<(usize, String)> {
+    queue.lock().ok().and_then(|mut q| q.pop_front())
+}
+
+// Runs the periodic refresh on its own thread; called once at server start.
+pub fn ensure_scheduler_started() {
+    static START: Once = Once::new();
+    START.call_once(|| {
+        let interval_minutes = get_refresh_interval_minutes();
+        if interval_minutes == 0 {
+            info!("Scheduled inventory refresh is disabled");
+            return;
+        }
+
+        thread::spawn(move || {
+            let interval = Duration::from_secs(interval_minutes * 60);
+            loop {
+                if let Ok(mut progress) = REFRESH_PROGRESS.lock() {
+                    progress.next_scheduled_run = chrono::Duration
+                        ::from_std(interval)
+                        .ok()
+                        .map(|d| Utc::now() + d);
+                }
+                thread::sleep(interval);
+
+                if is_quiet_hour(Local::now().hour(), get_refresh_quiet_hours()) {
+                    info!("Skipping scheduled inventory refresh during quiet hours");
+                } else if !start_refresh(RefreshTrigger::Scheduled) {
+                    error!("Skipping scheduled inventory refresh, previous refresh still running");
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,189 @@
+use std::collections::{ BTreeMap, BTreeSet, HashMap };
+use std::sync::Mutex;
+use super::export::export_columns;
+use super::types::{ InventoryRecord, SavedSearch };
+use crate::server::groups::rule::{ compare, Operator, Rule };
+use crate::server::groups::{ all_host_tags, field_values, validate_rule };
+use crate::server::people::store::make_id;
+use crate::server::store;
+
+const SEARCHES_FILE: &str = "saved_searches.json";
+
+// Inverted index over the stored inventory: for every field, each distinct
+// (lowercased) value maps to the rows holding it. Queries are answered from
+// the distinct values instead of re-reading every record.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    hosts: Vec<String>,
+    fields: HashMap<String, BTreeMap<String, BTreeSet<usize>>>,
+}
+
+impl SearchIndex {
+    pub fn build(records: &[InventoryRecord], tags: &BTreeMap<String, BTreeSet<String>>) -> Self {
+        let mut keys: Vec<String> = export_columns(records)
+            .into_iter()
+            .map(|c| c.key)
+            .collect();
+        keys.push("tag".to_string());
+
+        let mut index = SearchIndex {
+            hosts: records.iter().map(|r| r.host.clone()).collect(),
+            fields: HashMap::new(),
+        };
+        for (row, record) in records.iter().enumerate() {
+            for key in &keys {
+                for value in field_values(key, record, tags) {
+                    if value.is_empty() {
+                        continue;
+                    }
+                    index.fields
+                        .entry(key.clone())
+                        .or_default()
+                        .entry(value.to_lowercase())
+                        .or_default()
+                        .insert(row);
+                }
+            }
+        }
+        index
+    }
+
+    // Hosts matching the rule, in inventory order
+    pub fn search(&self, rule: &Rule) -> Vec<String> {
+        self.eval(rule)
+            .into_iter()
+            .map(|row| self.hosts[row].clone())
+            .collect()
+    }
+
+    fn all(&self) -> BTreeSet<usize> {
+        (0..self.hosts.len()).collect()
+    }
+
+    fn rows_where(&self, field: &str, op: Operator, expected: &str) -> BTreeSet<usize> {
+        let Some(values) = self.fields.get(field) else {
+            return BTreeSet::new();
+        };
+        if op == Operator::Eq {
+            return values.get(&expected.to_lowercase()).cloned().unwrap_or_default();
+        }
+        values
+            .iter()
+            .filter(|(value, _)| compare(value, op, expected))
+            .flat_map(|(_, rows)| rows.iter().copied())
+            .collect()
+    }
+
+    fn eval(&self, rule: &Rule) -> BTreeSet<usize> {
+        match rule {
+            // A host differs when none of its values are equal, same as Rule::matches
+            Rule::Compare { field, op: Operator::NotEq, value } => {
+                let equal = self.rows_where(field, Operator::Eq, value);
+                self.all().difference(&equal).copied().collect()
+            }
+            Rule::Compare { field, op, value } => self.rows_where(field, *op, value),
+            Rule::And(a, b) => self.eval(a).intersection(&self.eval(b)).copied().collect(),
+            Rule::Or(a, b) => self.eval(a).union(&self.eval(b)).copied().collect(),
+            Rule::Not(rule) => self.all().difference(&self.eval(rule)).copied().collect(),
+            Rule::Text(text) =>
+                self.fields
+                    .keys()
+                    .flat_map(|field| self.rows_where(field, Operator::Contains, text))
+                    .collect(),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    // Rebuilt whenever the data store has been written since it was built
+    static ref INDEX: Mutex<Option<(u64, SearchIndex)>> = Mutex::new(None);
+    static ref SAVED_SEARCHES: Mutex<Vec<SavedSearch>> = Mutex::new(store::load(SEARCHES_FILE));
+}
+
+// Runs a query over the stored inventory and tags, returning matching hosts
+pub fn search_inventory(query: &str) -> Result<Vec<String>, String> {
+    let rule = validate_rule(query)?;
+    let mut cached = INDEX.lock().map_err(|e| e.to_string())?;
+    let revision = store::revision();
+    let stale = !matches!(cached.as_ref(), Some((built, _)) if *built == revision);
+    if stale {
+        let records = super::all_records();
+        *cached = Some((revision, SearchIndex::build(&records, &all_host_tags()?)));
+    }
+    Ok(cached.as_ref().map(|(_, index)| index.search(&rule)).unwrap_or_default())
+}
+
+// Combines the search box with per-column "contains" filters into one query
+pub fn compose_query(text: &str, filters: &BTreeMap<String, String>) -> String {
+    let mut clauses: Vec<String> = Vec::new();
+    let text = text.trim();
+    if !text.is_empty() {
+        clauses.push(if filters.values().any(|v| !v.trim().is_empty()) {
+            format!("({})", text)
+        } else {
+            text.to_string()
+        });
+    }
+    for (field, value) in filters {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let quoted = if value.contains('"') {
+            format!("'{}'", value.replace('\'', ""))
+        } else {
+            format!("\"{}\"", value)
+        };
+        clauses.push(format!("{} ~ {}", field, quoted));
+    }
+    clauses.join(" AND ")
+}
+
+pub fn list_saved_searches() -> Result<Vec<SavedSearch>, String> {
+    let searches = SAVED_SEARCHES.lock().map_err(|e| e.to_string())?;
+    let mut searches = searches.clone();
+    searches.sort_by_key(|s| s.name.to_lowercase());
+    Ok(searches)
+}
+
+// Creates the search when `id` is empty, otherwise updates it
+pub fn save_search(search: SavedSearch) -> Result<SavedSearch, String> {
+    if search.name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    validate_rule(&search.query)?;
+    let search = SavedSearch {
+        name: search.name.trim().to_string(),
+        query: search.query.trim().to_string(),
+        ..search
+    };
+
+    // Changed on a copy that's only kept once it's saved
+    let mut searches = SAVED_SEARCHES.lock().map_err(|e| e.to_string())?;
+    let mut updated = searches.clone();
+    let search = if search.id.is_empty() {
+        let taken: Vec<&str> = updated.iter().map(|s| s.id.as_str()).collect();
+        let search = SavedSearch { id: make_id(&search.name, &taken), ..search };
+        updated.push(search.clone());
+        search
+    } else {
+        let existing = updated
+            .iter_mut()
+            .find(|s| s.id == search.id)
+            .ok_or_else(|| format!("Unknown saved search: {}", search.id))?;
+        *existing = search.clone();
+        search
+    };
+    store::save(SEARCHES_FILE, &updated)?;
+    *searches = updated;
+    Ok(search)
+}
+
+pub fn delete_saved_search(id: &str) -> Result<(), String> {
+    let mut searches = SAVED_SEARCHES.lock().map_err(|e| e.to_string())?;
+    let mut updated = searches.clone();
+    updated.retain(|s| s.id != id);
+    store::save(SEARCHES_FILE, &updated)?;
+    *searches = updated;
+    Ok(())
+}
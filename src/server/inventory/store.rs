@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use chrono::Utc;
+use super::types::{ ImportRowError, InventoryRecord, RecordSource };
+use crate::server::collectors::OsFamily;
+use crate::server::resolve_computer::ComputerInfo;
+use crate::server::store;
+
+const INVENTORY_FILE: &str = "inventory.json";
+
+lazy_static::lazy_static! {
+    static ref INVENTORY: Mutex<HashMap<String, InventoryRecord>> = Mutex::new(
+        store::load(INVENTORY_FILE)
+    );
+}
+
+pub fn record_resolution(host: &str, info: &ComputerInfo) -> Result<InventoryRecord, String> {
+    let mut inventory = INVENTORY.lock().map_err(|e| e.to_string())?;
+    let record = InventoryRecord {
+        host: host.to_string(),
+        info: info.clone(),
+        last_resolved: Some(Utc::now()),
+        source: RecordSource::Ssh,
+    };
+    let mut updated = inventory.clone();
+    // A machine imported by hand is replaced once it's reachable
+    if !info.serial.is_empty() {
+        updated.retain(|key, existing| {
+            key == host || existing.source != RecordSource::Manual || existing.info.serial != info.serial
+        });
+    }
+    updated.insert(host.to_string(), record.clone());
+    store::save(INVENTORY_FILE, &updated)?;
+    *inventory = updated;
+    Ok(record)
+}
+
+pub fn get_record(host: &str) -> Option<InventoryRecord> {
+    INVENTORY.lock().ok()?.get(host).cloned()
+}
+
+pub fn all_records() -> Vec<InventoryRecord> {
+    let mut records: Vec<InventoryRecord> = INVENTORY.lock()
+        .map(|inventory| inventory.values().cloned().collect())
+        .unwrap_or_default();
+    records.sort_by(|a, b| a.host.cmp(&b.host));
+    records
+}
+
+// Merges imported records by serial number. Manual records take the imported
+// values; records collected over SSH only have their empty fields filled in.
+// Returns the number of records created and updated, and the rows that were
+// rejected.
+pub fn merge_imported(
+    records: Vec<(usize, InventoryRecord)>
+) -> Result<(usize, usize, Vec<ImportRowError>), String> {
+    // Merged into a copy that's only kept once it's saved, so a failed save
+    // leaves memory (and the search index built from it) matching the file
+    let mut inventory = INVENTORY.lock().map_err(|e| e.to_string())?;
+    let mut updated = inventory.clone();
+    let merged = merge_records(&mut updated, records);
+    store::save(INVENTORY_FILE, &updated)?;
+    *inventory = updated;
+    Ok(merged)
+}
+
+// A row without a serial match lands under its host (or serial when it has no
+// host). A record already under that key is only merged into when it has no
+// serial of its own; a different machine there is reported instead of replaced.
+pub fn merge_records(
+    inventory: &mut HashMap<String, InventoryRecord>,
+    records: Vec<(usize, InventoryRecord)>
+) -> (usize, usize, Vec<ImportRowError>) {
+    let (mut created, mut updated, mut errors) = (0, 0, Vec::new());
+
+    for (line, imported) in records {
+        let key = inventory
+            .iter()
+            .find(|(_, r)| r.info.serial == imported.info.serial)
+            .map(|(key, _)| key.clone())
+            .unwrap_or_else(|| {
+                if imported.host.is_empty() { imported.info.serial.clone() } else { imported.host.clone() }
+            });
+        match inventory.get_mut(&key) {
+            Some(existing) if existing.info.serial.is_empty() || existing.info.serial == imported.info.serial => {
+                let overwrite = existing.source == RecordSource::Manual;
+                merge_info(&mut existing.info, imported.info, overwrite);
+                updated += 1;
+            }
+            Some(existing) => {
+                errors.push(ImportRowError {
+                    line,
+                    message: format!(
+                        "Host {} already belongs to serial number {}",
+                        key,
+                        existing.info.serial
+                    ),
+                });
+            }
+            None => {
+                inventory.insert(key.clone(), InventoryRecord {
+                    host: key,
+                    source: RecordSource::Manual,
+                    ..imported
+                });
+                created += 1;
+            }
+        }
+    }
+    (created, updated, errors)
+}
+
+pub fn merge_info(existing: &mut ComputerInfo, imported: ComputerInfo, overwrite: bool) {
+    let fields: [(&mut String, String); 14] = [
+        (&mut existing.title, imported.title),
+        (&mut existing.product_name, imported.product_name),
+        (&mut existing.version, imported.version),
+        (&mut existing.user, imported.user),
+        (&mut existing.network_name, imported.network_name),
+        (&mut existing.processor, imported.processor),
+        (&mut existing.architecture, imported.architecture),
+        (&mut existing.memory, imported.memory),
+        (&mut existing.graphics, imported.graphics),
+        (&mut existing.storage, imported.storage),
+        (&mut existing.lan_ip, imported.lan_ip),
+        (&mut existing.wifi_ip, imported.wifi_ip),
+        (&mut existing.wifi_name, imported.wifi_name),
+        (&mut existing.serial, imported.serial),
+    ];
+    for (field, value) in fields {
+        if !value.is_empty() && (overwrite || field.is_empty()) {
+            *field = value;
+        }
+    }
+    if !imported.home_users.is_empty() && (overwrite || existing.home_users.is_empty()) {
+        existing.home_users = imported.home_users;
+    }
+    if imported.os_family != OsFamily::Unknown && (overwrite || existing.os_family == OsFamily::Unknown) {
+        existing.os_family = imported.os_family;
+    }
+    for (field, value) in imported.custom_fields {
+        if overwrite || !existing.custom_fields.contains_key(&field) {
+            existing.custom_fields.insert(field, value);
+        }
+    }
+}
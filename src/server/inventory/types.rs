@@ -0,0 +1,112 @@
+use chrono::{ DateTime, NaiveDate, Utc };
+use serde::{ Serialize, Deserialize };
+use crate::server::license::LicenseStatus;
+use crate::server::resolve_computer::ComputerInfo;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct InventoryRecord {
+    pub host: String,
+    pub info: ComputerInfo,
+    pub last_resolved: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub source: RecordSource,
+}
+
+// Where a record's data came from. Manual records were imported for
+// machines that can't be reached and are replaced once SSH resolves them.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum RecordSource {
+    #[default]
+    Ssh,
+    Manual,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PendingUpdateGroup {
+    pub label: String,
+    pub title: String,
+    pub version: String,
+    pub recommended: bool,
+    pub restart_required: bool,
+    pub hosts: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum RefreshTrigger {
+    Manual,
+    Scheduled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RefreshProgress {
+    pub in_progress: bool,
+    pub trigger: Option<RefreshTrigger>,
+    pub total: usize,
+    pub completed: usize,
+    pub failed: Vec<String>,
+    pub current_hosts: Vec<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub next_scheduled_run: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AssetMetadata {
+    pub serial: String,
+    pub purchase_date: Option<NaiveDate>,
+    pub supplier: Option<String>,
+    pub invoice_number: Option<String>,
+    pub cost: Option<f64>,
+    pub warranty_expiry: Option<NaiveDate>,
+    pub applecare_expiry: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+impl AssetMetadata {
+    // Imported rows only overwrite the columns they actually contain
+    pub fn merge(&mut self, other: AssetMetadata) {
+        self.purchase_date = other.purchase_date.or(self.purchase_date);
+        self.supplier = other.supplier.or(self.supplier.take());
+        self.invoice_number = other.invoice_number.or(self.invoice_number.take());
+        self.cost = other.cost.or(self.cost);
+        self.warranty_expiry = other.warranty_expiry.or(self.warranty_expiry);
+        self.applecare_expiry = other.applecare_expiry.or(self.applecare_expiry);
+        self.notes = other.notes.or(self.notes.take());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CoverageWarning {
+    pub serial: String,
+    pub coverage: String,
+    pub expires: NaiveDate,
+    pub status: LicenseStatus,
+    pub days_remaining: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ImportRowError {
+    pub line: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AssetImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct InventoryImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SavedSearch {
+    pub id: String,
+    pub name: String,
+    pub query: String,
+}
@@ -1,123 +1,152 @@
 use chrono::NaiveDate;
-use super::types::{ LicenseInfo, LicenseCheckResult };
+use super::rules::{ load_license_rules, most_urgent, parse_license_files, remote_command, ExpiryThresholds };
+use super::notify::dispatch;
+use super::store::record_check;
+use super::types::{ LicenseCheckResult, LicenseStatus, ProductLicense };
+use crate::server::inventory::scheduler::run_workers_async;
 use crate::server::network::ssh::ssh_exec;
-use crate::configs::env_validate::get_ssh_password;
+use crate::configs::env_validate::{ get_ssh_password, get_ssh_user };
 
+// The single license engine: used by the license server function, group
+// checks and anything else that needs a host's license state
 #[derive(Debug)]
 pub struct LicenseChecker {
     host: String,
+    username: String,
 }
 
 impl LicenseChecker {
+    // Logs in with the same account as inventory resolution
     pub fn new(host: String) -> Self {
-        Self { host }
+        Self { host, username: get_ssh_user() }
     }
 
-    pub async fn check_comet_license(&self) -> Result<LicenseCheckResult, String> {
-        log::info!("[STEP 1/4] Starting license check for host: {}", self.host);
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub async fn check(&self) -> Result<LicenseCheckResult, String> {
+        log::info!("[STEP 1/3] Starting license check for host: {}", self.host);
 
-        // Test connection first
+        // Test connection first so an offline host isn't reported as missing licenses
         let password = get_ssh_password().map_err(|e| e.to_string())?;
         let test_cmd = "echo 'Connection test'".to_string();
-        if let Err(e) = ssh_exec(self.host.clone(), self.host.clone(), password.clone(), test_cmd.to_string()).await {
-            let error_msg = e.to_string();
-            return Ok(LicenseCheckResult {
-                host: self.host.clone(),
-                status: "[ERROR] SSH Connection Failed".to_string(),
-                error: Some(format!("SSH connection error: {}", error_msg)),
-                debug_log: format!("SSH connection failed to {}\nError: {}", self.host, error_msg),
-            });
+        if let Err(e) = ssh_exec(self.host.clone(), self.username.clone(), password.clone(), test_cmd).await {
+            return Ok(unreachable_result(&self.host, &e.to_string()));
         }
 
-        // Try reading the license file from standard locations
-        log::info!("[STEP 2/4] Reading license file");
-        let license_paths = [
-            "\'/Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic\'",
-        ];
-        let escaped_paths: Vec<String> = license_paths
-            .iter()
-            .map(|path| path.replace(" ", "\\ "))
-            .collect();
-
-        // Create futures for concurrent license file checks
-        let futures: Vec<_> = escaped_paths.iter().map(|path| {
+        // Read every configured product's license files concurrently
+        log::info!("[STEP 2/3] Reading license files");
+        let rules = load_license_rules();
+        let futures: Vec<_> = rules.iter().map(|rule| {
             let host = self.host.clone();
-            let password = get_ssh_password().unwrap_or_default();
-            let cmd = format!(r#"cat {} 2>/dev/null | grep -i "expires""#, path);
-            async move { ssh_exec(host.clone(), host.clone(), password, cmd).await }
+            let username = self.username.clone();
+            let password = password.clone();
+            let cmd = remote_command(rule);
+            async move { ssh_exec(host, username, password, cmd).await }
         }).collect();
-
-        // Execute all checks concurrently
         let results = futures::future::join_all(futures).await;
-        
-        // Process results
-        let mut combined_output = String::new();
-        for result in results {
+
+        let today = chrono::Local::now().date_naive();
+        let mut licenses = Vec::new();
+        let mut debug_log = String::new();
+        let mut errors = Vec::new();
+        for (rule, result) in rules.iter().zip(results) {
             match result {
-                Ok(output) if !output.trim().is_empty() => {
-                    combined_output.push_str(&output);
-                    combined_output.push('\n');
+                Ok(output) => {
+                    debug_log.push_str(&output);
+                    licenses.extend(parse_license_files(rule, &output, &today));
+                }
+                Err(e) => {
+                    errors.push(format!("Failed to read {} license: {}", rule.product, e));
+                    licenses.push(ProductLicense {
+                        product: rule.product.clone(),
+                        status: LicenseStatus::Unreadable,
+                        message: Some(e.to_string()),
+                        ..Default::default()
+                    });
                 }
-                _ => continue,
             }
         }
 
-        if !combined_output.trim().is_empty() {
-            // Found and read at least one license file successfully
-            let license_info = self.parse_license_output(&combined_output)?;
-            log::info!("[STEP 3/4] License check completed successfully");
-            Ok(LicenseCheckResult {
-                host: self.host.clone(),
-                status: license_info.status,
-                error: None,
-                debug_log: String::new(),
-            })
-        } else {
-            // No valid license files found
-            log::warn!("No valid license files found");
-            Ok(LicenseCheckResult {
-                host: self.host.clone(),
-                status: "[ERROR] No Valid License Files".to_string(),
-                error: Some("No valid license files found".to_string()),
-                debug_log: String::new(),
-            })
-        }
+        log::info!("[STEP 3/3] License check completed for host: {}", self.host);
+        let mut result = summarize(&self.host, licenses);
+        result.debug_log = debug_log;
+        result.checked_at = Some(chrono::Utc::now());
+        result.error = if errors.is_empty() { None } else { Some(errors.join("\n")) };
+        Ok(result)
     }
+}
 
-    fn parse_license_output(&self, output: &str) -> Result<LicenseInfo, String> {
-        if let Some(date_str) = output
-            .trim_start_matches("//")
-            .trim()
-            .split(':')
-            .nth(1)
-            .map(|s| s.trim())
-        {
-            if let Ok(exp_date) = NaiveDate::parse_from_str(date_str, "%Y/%m/%d") {
-                return Ok(LicenseInfo {
-                    expiration_date: Some(exp_date),
-                    status: output.trim().to_string(),
-                });
-            }
-        }
-        Ok(LicenseInfo {
-            expiration_date: None,
-            status: output.trim().to_string(),
-        })
+// What a check reports when the connection test fails, so an offline host
+// isn't mistaken for one without licenses
+pub fn unreachable_result(host: &str, error: &str) -> LicenseCheckResult {
+    LicenseCheckResult {
+        host: host.to_string(),
+        status: LicenseStatus::HostUnreachable,
+        error: Some(format!("SSH connection error: {}", error)),
+        debug_log: format!("SSH connection failed to {}\nError: {}", host, error),
+        checked_at: Some(chrono::Utc::now()),
+        ..Default::default()
     }
 }
 
-pub fn analyze_expiration_date(exp_date: &NaiveDate, today: &NaiveDate) -> String {
-    let days_until_expiry = exp_date.signed_duration_since(*today).num_days();
-    match days_until_expiry {
-        d if d < 0 =>
-            format!(
-                "[EXPIRED] {} ({} days overdue) [Countdown: {} days overdue]",
-                exp_date,
-                d.abs(),
-                d.abs()
-            ),
-        d if d <= 30 => format!("[WARNING] Expires {} [Countdown: {} days remaining]", exp_date, d),
-        d if d <= 90 => format!("[NOTICE] Expires {} [Countdown: {} days remaining]", exp_date, d),
-        d => format!("[OK] Valid until {} [Countdown: {} days remaining]", exp_date, d),
+// Checks a host, keeps the result for the dashboard and history, and sends
+// notifications for licenses that crossed a threshold along with any still
+// queued from earlier checks
+pub async fn check_and_record(host: String) -> Result<LicenseCheckResult, String> {
+    let result = LicenseChecker::new(host).check().await?;
+    if let Err(e) = record_check(&result) {
+        log::error!("Failed to store license result for {}: {}", result.host, e);
+    }
+    dispatch();
+    Ok(result)
+}
+
+// Checks several hosts, at most `max_concurrency` at a time
+pub async fn check_hosts(hosts: Vec<String>, max_concurrency: usize) -> Result<Vec<LicenseCheckResult>, String> {
+    check_hosts_with(hosts, max_concurrency, |host| {
+        futures::executor::block_on(check_and_record(host.to_string()))
+    }).await
+}
+
+// SSH calls block, so each check runs on the inventory refresh's worker
+// threads rather than on the caller's task
+pub async fn check_hosts_with<F>(
+    hosts: Vec<String>,
+    max_concurrency: usize,
+    check: F
+) -> Result<Vec<LicenseCheckResult>, String>
+    where F: Fn(&str) -> Result<LicenseCheckResult, String> + Send + Sync + 'static
+{
+    let mut results: Vec<LicenseCheckResult> = run_workers_async(hosts, max_concurrency, check).await
+        .into_iter()
+        .collect::<Result<_, _>>()?;
+    results.sort_by(|a, b| a.host.cmp(&b.host));
+    Ok(results)
+}
+
+// Status and days remaining for an expiry date
+pub fn analyze_expiration_date(
+    exp_date: &NaiveDate,
+    today: &NaiveDate,
+    thresholds: &ExpiryThresholds
+) -> (LicenseStatus, i64) {
+    let days_remaining = exp_date.signed_duration_since(*today).num_days();
+    (LicenseStatus::from_days_remaining(days_remaining, thresholds), days_remaining)
+}
+
+// Host-level result from the per-file results; no licenses at all is NotFound
+pub fn summarize(host: &str, licenses: Vec<ProductLicense>) -> LicenseCheckResult {
+    let urgent = most_urgent(&licenses).cloned().unwrap_or_default();
+    LicenseCheckResult {
+        host: host.to_string(),
+        status: urgent.status,
+        expiry: urgent.expiry,
+        days_remaining: urgent.days_remaining,
+        error: urgent.message,
+        debug_log: String::new(),
+        licenses,
+        checked_at: None,
     }
 }
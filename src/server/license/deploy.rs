@@ -0,0 +1,360 @@
+use std::sync::Mutex;
+use chrono::{ NaiveDate, Utc };
+use super::checker::check_and_record;
+use super::rules::{ load_license_rules, most_urgent, quote_glob, read_license, LicenseRule };
+use super::store::hosts_for_target;
+use super::types::{
+    DeployedFile,
+    DeploymentStatus,
+    HostDeployment,
+    LicenseDeployRequest,
+    LicenseDeployment,
+    ProductLicense,
+};
+use crate::configs::env_validate::{ get_ssh_password, get_ssh_user };
+use crate::configs::get_refresh_max_concurrency;
+use crate::server::inventory::scheduler::run_workers_async;
+use crate::server::network::ssh::ssh_exec;
+use crate::server::people::store::make_id;
+use crate::server::store;
+
+const LICENSE_DEPLOYMENTS_FILE: &str = "license_deployments.json";
+
+// Mark each file the deploy and rollback commands touched
+const DEPLOYED_MARKER: &str = "==> ";
+const FAILED_MARKER: &str = "==! ";
+
+lazy_static::lazy_static! {
+    static ref DEPLOYMENTS: Mutex<Vec<LicenseDeployment>> = Mutex::new(
+        store::load(LICENSE_DEPLOYMENTS_FILE)
+    );
+}
+
+fn find_rule(product: &str) -> Result<LicenseRule, String> {
+    load_license_rules()
+        .into_iter()
+        .find(|rule| rule.product == product)
+        .ok_or_else(|| format!("No license rule for {}", product))
+}
+
+// What the license check would report for the file once it's deployed; the
+// license expiring first when the file holds several
+pub fn preview_license(rule: &LicenseRule, content: &str, today: &NaiveDate) -> ProductLicense {
+    let license = ProductLicense { product: rule.product.clone(), ..Default::default() };
+    let licenses = read_license(rule, license, content, today);
+    most_urgent(&licenses).cloned().unwrap_or_default()
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+// Directory glob and file name for each of the rule's paths. The rule's own
+// file name wins so the check keeps finding the file; the uploaded name is
+// only used when the rule matches names with a wildcard.
+pub fn deploy_targets(rule: &LicenseRule, filename: &str) -> Vec<(String, String)> {
+    rule.paths
+        .iter()
+        .filter_map(|path| {
+            let (dir, name) = path.rsplit_once('/')?;
+            let name = if name.contains(['*', '?', '[']) { filename } else { name };
+            Some((dir.to_string(), name.to_string()))
+        })
+        .collect()
+}
+
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut re = String::from("^");
+    let mut in_class = false;
+    for c in pattern.chars() {
+        match c {
+            '*' if !in_class => re.push_str("[^/]*"),
+            '?' if !in_class => re.push_str("[^/]"),
+            '[' if !in_class => {
+                in_class = true;
+                re.push('[');
+            }
+            ']' if in_class => {
+                in_class = false;
+                re.push(']');
+            }
+            _ if in_class => re.push(c),
+            _ => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+    regex::Regex::new(&re).is_ok_and(|re| re.is_match(name))
+}
+
+// The uploaded name ends up as a path under `sudo`, so it must be a plain
+// file name the rule's wildcard file names would match
+pub fn validate_filename(rule: &LicenseRule, filename: &str) -> Result<(), String> {
+    if filename.is_empty() || filename == "." || filename == ".." || filename.contains(['/', '\0']) {
+        return Err(format!("Invalid license file name: {:?}", filename));
+    }
+    let patterns: Vec<&str> = rule.paths
+        .iter()
+        .filter_map(|path| path.rsplit_once('/').map(|(_, name)| name))
+        .filter(|name| name.contains(['*', '?', '[']))
+        .collect();
+    if !patterns.is_empty() && !patterns.iter().any(|pattern| glob_matches(pattern, filename)) {
+        return Err(format!("{} doesn't match the {} license files ({})", filename, rule.product, patterns.join(", ")));
+    }
+    Ok(())
+}
+
+// Writes the file into every existing license directory of the rule. A file
+// it replaces is kept as `<file>.bak-<stamp>` and its owner and mode carry
+// over; new files take the directory's owner.
+pub fn deploy_command(rule: &LicenseRule, filename: &str, content: &str, stamp: &str) -> Result<String, String> {
+    validate_filename(rule, filename)?;
+    let hex: String = content.bytes().map(|b| format!("{:02x}", b)).collect();
+    let mut cmd = format!(
+        r#"tmp=$(mktemp) || exit 1; printf '%s' '{}' | xxd -r -p > "$tmp"; "#,
+        hex
+    );
+    for (dir, name) in deploy_targets(rule, filename) {
+        cmd.push_str(
+            &format!(
+                r#"for d in {dir}; do [ -d "$d" ] || continue; f="$d/"{name}; b=""; if [ -f "$f" ]; then owner=$(stat -f '%u:%g' "$f"); mode=$(stat -f '%Lp' "$f"); b="$f.bak-{stamp}"; sudo cp -p "$f" "$b" || {{ echo "{failed}$f"; continue; }}; else owner=$(stat -f '%u:%g' "$d"); mode=644; fi; if sudo cp "$tmp" "$f" && sudo chown "$owner" "$f" && sudo chmod "$mode" "$f"; then printf '{deployed}%s\t%s\n' "$f" "$b"; else echo "{failed}$f"; fi; done; "#,
+                dir = quote_glob(&dir),
+                name = shell_quote(&name),
+                stamp = stamp,
+                deployed = DEPLOYED_MARKER,
+                failed = FAILED_MARKER
+            )
+        );
+    }
+    cmd.push_str(r#"rm -f "$tmp""#);
+    Ok(cmd)
+}
+
+// Puts each backup back in place, or removes files that didn't exist before
+pub fn rollback_command(files: &[DeployedFile]) -> String {
+    files
+        .iter()
+        .map(|file| {
+            let path = shell_quote(&file.path);
+            let restore = match &file.backup {
+                Some(backup) => format!("sudo mv {} {}", shell_quote(backup), path),
+                None => format!("sudo rm -f {}", path),
+            };
+            format!(
+                r#"if {}; then echo "{}"{}; else echo "{}"{}; fi"#,
+                restore,
+                DEPLOYED_MARKER,
+                path,
+                FAILED_MARKER,
+                path
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// Files written and paths that failed, from the marker lines of the output
+pub fn parse_deploy_output(output: &str) -> (Vec<DeployedFile>, Vec<String>) {
+    let mut files = Vec::new();
+    let mut failed = Vec::new();
+    for line in output.lines() {
+        let line = line.trim_end_matches('\r');
+        if let Some(rest) = line.strip_prefix(DEPLOYED_MARKER) {
+            let (path, backup) = rest.split_once('\t').unwrap_or((rest, ""));
+            files.push(DeployedFile {
+                path: path.to_string(),
+                backup: Some(backup.to_string()).filter(|b| !b.is_empty()),
+            });
+        } else if let Some(path) = line.strip_prefix(FAILED_MARKER) {
+            failed.push(path.to_string());
+        }
+    }
+    (files, failed)
+}
+
+async fn deploy_host(host: String, password: String, cmd: String) -> HostDeployment {
+    match ssh_exec(host.clone(), get_ssh_user(), password, cmd).await {
+        Ok(output) => {
+            let (files, failed) = parse_deploy_output(&output);
+            let (status, message) = if !failed.is_empty() {
+                (DeploymentStatus::Failed, Some(format!("Failed to write {}", failed.join(", "))))
+            } else if files.is_empty() {
+                (DeploymentStatus::NoTarget, Some("No license directory found".to_string()))
+            } else {
+                (DeploymentStatus::Deployed, None)
+            };
+            HostDeployment { host, status, files, message, check: None }
+        }
+        Err(e) =>
+            HostDeployment {
+                host,
+                status: DeploymentStatus::Failed,
+                files: Vec::new(),
+                message: Some(e.to_string()),
+                check: None,
+            },
+    }
+}
+
+async fn rollback_host(mut deployment: HostDeployment, password: String) -> HostDeployment {
+    let cmd = rollback_command(&deployment.files);
+    match ssh_exec(deployment.host.clone(), get_ssh_user(), password, cmd).await {
+        Ok(output) => {
+            let (_, failed) = parse_deploy_output(&output);
+            if failed.is_empty() {
+                deployment.status = DeploymentStatus::RolledBack;
+                deployment.message = None;
+            } else {
+                deployment.status = DeploymentStatus::RollbackFailed;
+                deployment.message = Some(format!("Failed to restore {}", failed.join(", ")));
+            }
+        }
+        Err(e) => {
+            deployment.status = DeploymentStatus::RollbackFailed;
+            deployment.message = Some(e.to_string());
+        }
+    }
+    deployment
+}
+
+// Changes one stored deployment; memory only changes once the file is saved
+fn update_deployment<F>(id: &str, update: F) -> Result<LicenseDeployment, String>
+    where F: FnOnce(&mut LicenseDeployment)
+{
+    let mut deployments = DEPLOYMENTS.lock().map_err(|e| e.to_string())?;
+    let mut updated = deployments.clone();
+    let deployment = updated
+        .iter_mut()
+        .find(|d| d.id == id)
+        .ok_or_else(|| format!("Unknown deployment: {}", id))?;
+    update(deployment);
+    let deployment = deployment.clone();
+    store::save(LICENSE_DEPLOYMENTS_FILE, &updated)?;
+    *deployments = updated;
+    Ok(deployment)
+}
+
+fn replace_host(deployment: &mut LicenseDeployment, host: &HostDeployment) {
+    if let Some(stored) = deployment.hosts.iter_mut().find(|h| h.host == host.host) {
+        *stored = host.clone();
+    }
+}
+
+// Saved as each host finishes, so a deployment cut short can still be rolled
+// back on the hosts it already wrote to
+fn record_host(id: &str, host: &HostDeployment) {
+    if let Err(e) = update_deployment(id, |deployment| replace_host(deployment, host)) {
+        log::error!("Failed to store license deployment progress for {}: {}", host.host, e);
+    }
+}
+
+// Deploys to every targeted host, re-checks each one and keeps the
+// deployment so it can be rolled back. The deployment is stored with every
+// host pending before anything is written.
+pub async fn deploy_license(request: LicenseDeployRequest) -> Result<LicenseDeployment, String> {
+    let rule = find_rule(&request.product)?;
+    let today = chrono::Local::now().date_naive();
+    let preview = preview_license(&rule, &request.content, &today);
+    if preview.status.is_failure() {
+        return Err(preview.message.unwrap_or_else(|| "No expiry date found".to_string()));
+    }
+    validate_filename(&rule, &request.filename)?;
+    let hosts = hosts_for_target(&request.target)?;
+    if hosts.is_empty() {
+        return Err("No hosts to deploy to".to_string());
+    }
+    let password = get_ssh_password().map_err(|e| e.to_string())?;
+    let now = Utc::now();
+    let cmd = deploy_command(&rule, &request.filename, &request.content, &now.format("%Y%m%d%H%M%S").to_string())?;
+
+    let id = {
+        let mut deployments = DEPLOYMENTS.lock().map_err(|e| e.to_string())?;
+        let taken: Vec<&str> = deployments.iter().map(|d| d.id.as_str()).collect();
+        let deployment = LicenseDeployment {
+            id: make_id(&format!("{} {}", request.product, now.format("%Y%m%d%H%M")), &taken),
+            product: request.product.clone(),
+            filename: request.filename,
+            expiry: preview.expiry,
+            target: request.target,
+            created_at: now,
+            rolled_back_at: None,
+            hosts: hosts
+                .iter()
+                .map(|host| HostDeployment {
+                    host: host.clone(),
+                    status: DeploymentStatus::Pending,
+                    files: Vec::new(),
+                    message: None,
+                    check: None,
+                })
+                .collect(),
+        };
+        let mut updated = deployments.clone();
+        updated.push(deployment.clone());
+        store::save(LICENSE_DEPLOYMENTS_FILE, &updated)?;
+        *deployments = updated;
+        deployment.id
+    };
+    log::info!("Deploying {} license to {} hosts as {}", request.product, hosts.len(), id);
+
+    let worker_id = id.clone();
+    let results = run_workers_async(hosts, get_refresh_max_concurrency(), move |host| {
+        let mut deployment = futures::executor::block_on(deploy_host(host.to_string(), password.clone(), cmd.clone()));
+        record_host(&worker_id, &deployment);
+        if !deployment.files.is_empty() {
+            deployment.check = futures::executor::block_on(check_and_record(host.to_string())).ok();
+        }
+        deployment
+    }).await;
+
+    update_deployment(&id, |deployment| {
+        for host in &results {
+            replace_host(deployment, host);
+        }
+    })
+}
+
+// Restores the files a deployment replaced on all of its hosts
+pub async fn rollback_deployment(id: &str) -> Result<LicenseDeployment, String> {
+    let deployment = DEPLOYMENTS.lock()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .find(|d| d.id == id)
+        .cloned()
+        .ok_or_else(|| format!("Unknown deployment: {}", id))?;
+    let password = get_ssh_password().map_err(|e| e.to_string())?;
+    log::info!("Rolling back license deployment {}", id);
+
+    let pending: Vec<String> = deployment.hosts
+        .iter()
+        .filter(|host| !host.files.is_empty() && host.status != DeploymentStatus::RolledBack)
+        .map(|host| host.host.clone())
+        .collect();
+    let worker_id = id.to_string();
+    let hosts = deployment.hosts;
+    let results = run_workers_async(pending, get_refresh_max_concurrency(), move |name| {
+        let host = hosts.iter().find(|h| h.host == name).cloned()?;
+        let mut host = futures::executor::block_on(rollback_host(host, password.clone()));
+        record_host(&worker_id, &host);
+        host.check = futures::executor::block_on(check_and_record(name.to_string())).ok();
+        Some(host)
+    }).await;
+
+    update_deployment(id, |deployment| {
+        for host in results.iter().flatten() {
+            replace_host(deployment, host);
+        }
+        deployment.rolled_back_at = Some(Utc::now());
+    })
+}
+
+// Newest first
+pub fn list_deployments() -> Result<Vec<LicenseDeployment>, String> {
+    let mut deployments = DEPLOYMENTS.lock().map_err(|e| e.to_string())?.clone();
+    deployments.reverse();
+    Ok(deployments)
+}
+
+pub fn preview_license_file(product: &str, content: &str) -> Result<ProductLicense, String> {
+    let rule = find_rule(product)?;
+    Ok(preview_license(&rule, content, &chrono::Local::now().date_naive()))
+}
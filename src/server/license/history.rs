@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use chrono::{ DateTime, Utc };
+use super::types::{ LicenseRow, LicenseStatus, LicenseTimeline, TimelineEvent, TimelineKind, UnstableLicense };
+
+// Changes to or from Unreadable before a license counts as flapping
+const FLAPPING_CHANGES: usize = 3;
+
+// Product and file; further licenses in the same file are told apart by
+// their line, as notifications do. Failed reads have no line, so keying the
+// first license by line would turn every failure into a new license.
+type LicenseKey = (String, Option<String>, Option<usize>);
+
+fn keys(rows: &[&LicenseRow]) -> Vec<LicenseKey> {
+    let mut seen: BTreeMap<(String, Option<String>), usize> = BTreeMap::new();
+    rows.iter()
+        .map(|row| {
+            let count = seen.entry((row.product.clone(), row.path.clone())).or_default();
+            *count += 1;
+            let nth = (*count > 1).then(|| row.line.unwrap_or(*count));
+            (row.product.clone(), row.path.clone(), nth)
+        })
+        .collect()
+}
+
+// A failed read without a path (the SSH read failed, or no file was found)
+// stands for the product's files the timeline already tracks, so it shows as
+// their status change instead of them disappearing and a new license appearing
+fn resolve_failures(
+    rows: &[&LicenseRow],
+    current: &BTreeMap<LicenseKey, LicenseRow>
+) -> Vec<(LicenseRow, LicenseKey)> {
+    let reported = keys(rows);
+    let mut resolved = Vec::new();
+    for (row, key) in rows.iter().zip(reported.iter()) {
+        let tracked: Vec<&LicenseKey> = if row.path.is_none() && row.status.is_failure() {
+            current
+                .keys()
+                .filter(|k| k.0 == row.product && k.1.is_some() && !reported.contains(k))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        if tracked.is_empty() {
+            resolved.push(((*row).clone(), key.clone()));
+        }
+        for key in tracked {
+            resolved.push((LicenseRow { path: key.1.clone(), ..(*row).clone() }, key.clone()));
+        }
+    }
+    resolved
+}
+
+fn event(at: DateTime<Utc>, row: &LicenseRow, kind: TimelineKind, previous: Option<&LicenseRow>) -> TimelineEvent {
+    TimelineEvent {
+        at,
+        kind,
+        product: row.product.clone(),
+        path: row.path.clone(),
+        status: row.status,
+        previous_status: previous.map(|p| p.status),
+        expiry: row.expiry,
+        previous_expiry: previous.and_then(|p| p.expiry),
+        message: row.message.clone(),
+    }
+}
+
+// Turns a host's check history (oldest first) into what changed between
+// checks. Rows of one check share its timestamp; a check that couldn't reach
+// the host has a single row without a product.
+pub fn build_timeline(host: &str, rows: &[LicenseRow]) -> LicenseTimeline {
+    let mut checks: BTreeMap<DateTime<Utc>, Vec<&LicenseRow>> = BTreeMap::new();
+    for row in rows.iter().filter(|row| row.host == host) {
+        if let Some(at) = row.checked_at {
+            checks.entry(at).or_default().push(row);
+        }
+    }
+
+    let mut timeline = LicenseTimeline { host: host.to_string(), ..Default::default() };
+    let mut current: BTreeMap<LicenseKey, LicenseRow> = BTreeMap::new();
+    let mut unreadable_changes: BTreeMap<LicenseKey, usize> = BTreeMap::new();
+    let mut reachable = None;
+
+    for (at, rows) in checks {
+        timeline.checks += 1;
+        timeline.last_checked = Some(at);
+        if let [row] = rows.as_slice() {
+            if row.product.is_empty() {
+                if reachable != Some(false) {
+                    timeline.events.push(event(at, row, TimelineKind::StoppedReporting, None));
+                }
+                reachable = Some(false);
+                continue;
+            }
+        }
+        if reachable == Some(false) {
+            timeline.events.push(TimelineEvent {
+                at,
+                kind: TimelineKind::ResumedReporting,
+                product: String::new(),
+                path: None,
+                status: LicenseStatus::Valid,
+                previous_status: Some(LicenseStatus::HostUnreachable),
+                expiry: None,
+                previous_expiry: None,
+                message: None,
+            });
+        }
+        reachable = Some(true);
+        timeline.last_reported = Some(at);
+
+        let resolved = resolve_failures(&rows, &current);
+        let seen: Vec<LicenseKey> = resolved.iter().map(|(_, key)| key.clone()).collect();
+        for (row, key) in resolved {
+            let previous = current.get(&key);
+            let kind = match previous {
+                None => Some(TimelineKind::FirstSeen),
+                Some(p) if row.expiry.is_some() && p.expiry.is_some() && row.expiry > p.expiry =>
+                    Some(TimelineKind::Renewed),
+                Some(p) if p.status != row.status => Some(TimelineKind::StatusChanged),
+                _ => None,
+            };
+            if let Some(p) = previous {
+                if p.status != row.status && (p.status == LicenseStatus::Unreadable || row.status == LicenseStatus::Unreadable) {
+                    *unreadable_changes.entry(key.clone()).or_default() += 1;
+                }
+            }
+            if let Some(kind) = kind {
+                timeline.events.push(event(at, &row, kind, previous));
+            }
+            current.insert(key, row);
+        }
+
+        // Licenses a reachable host no longer reports
+        let gone: Vec<LicenseKey> = current.keys().filter(|k| !seen.contains(k)).cloned().collect();
+        for key in gone {
+            if let Some(row) = current.remove(&key) {
+                let removed = LicenseRow { status: LicenseStatus::NotFound, expiry: None, ..row.clone() };
+                timeline.events.push(event(at, &removed, TimelineKind::Disappeared, Some(&row)));
+            }
+        }
+    }
+
+    timeline.unstable = unreadable_changes
+        .into_iter()
+        .filter(|(_, changes)| *changes >= FLAPPING_CHANGES)
+        .map(|((product, path, _), changes)| UnstableLicense { product, path, changes })
+        .collect();
+    timeline.events.reverse();
+    timeline
+}
@@ -1,6 +1,41 @@
 pub mod types;
 pub mod checker;
+pub mod rules;
+pub mod store;
+pub mod notify;
+pub mod scheduler;
+pub mod deploy;
+pub mod history;
 
 // Re-export commonly used items
-pub use types::{ LicenseInfo, LicenseCheckResult, SoftwareInfo };
-pub use checker::{ LicenseChecker, analyze_expiration_date };
+pub use types::{
+    DeployedFile,
+    DeploymentStatus,
+    HostDeployment,
+    LicenseCheckResult,
+    LicenseDeployRequest,
+    LicenseDeployment,
+    LicenseRow,
+    LicenseStatus,
+    LicenseTarget,
+    LicenseTimeline,
+    ProductLicense,
+    SoftwareInfo,
+    TimelineEvent,
+    TimelineKind,
+    UnstableLicense,
+};
+pub use checker::{
+    LicenseChecker,
+    analyze_expiration_date,
+    check_and_record,
+    check_hosts,
+    check_hosts_with,
+    summarize,
+    unreachable_result,
+};
+pub use store::{ dashboard_rows, hosts_for_target, latest_results, license_history, license_hosts, record_check };
+pub use notify::{ LicenseNotification, NotificationSink };
+pub use scheduler::ensure_license_scheduler_started;
+pub use history::build_timeline;
+pub use rules::{ ExpiryThresholds, LicenseRule, load_license_rules, most_urgent };
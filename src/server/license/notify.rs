@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::io::{ BufRead, BufReader, Write };
+use std::net::TcpStream;
+use std::process::{ Command, Stdio };
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use chrono::{ DateTime, NaiveDate, Utc };
+use serde::{ Serialize, Deserialize };
+use super::store::{ pending_notifications, remove_delivered };
+use super::types::{ LicenseCheckResult, LicenseStatus };
+use crate::configs::{
+    get_notify_email_from,
+    get_notify_email_to,
+    get_notify_webhook_url,
+    get_smtp_server,
+};
+
+const SEND_TIMEOUT: Duration = Duration::from_secs(15);
+
+// A license that crossed into the notice, warning or expired range
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LicenseNotification {
+    pub host: String,
+    pub product: String,
+    pub path: Option<String>,
+    pub previous: Option<LicenseStatus>,
+    pub status: LicenseStatus,
+    pub expiry: Option<NaiveDate>,
+    pub days_remaining: Option<i64>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl LicenseNotification {
+    pub fn subject(&self) -> String {
+        format!("License {:?}: {} on {}", self.status, self.product, self.host)
+    }
+
+    pub fn body(&self) -> String {
+        let mut lines = vec![
+            format!("Host: {}", self.host),
+            format!("Product: {}", self.product),
+            format!("Status: {:?}", self.status),
+        ];
+        if let Some(path) = &self.path {
+            lines.push(format!("File: {}", path));
+        }
+        if let Some(expiry) = self.expiry {
+            lines.push(format!("Expires: {}", expiry));
+        }
+        if let Some(days) = self.days_remaining {
+            lines.push(format!("Days remaining: {}", days));
+        }
+        if let Some(previous) = self.previous {
+            lines.push(format!("Previously: {:?}", previous));
+        }
+        lines.join("\n")
+    }
+}
+
+// Compares a check against the statuses already notified, keyed by host,
+// product and file. Each crossing into a threshold notifies once; a renewed
+// license clears its entry so the next expiry notifies again. Failed checks
+// leave the state alone.
+pub fn detect_transitions(
+    notified: &mut BTreeMap<String, LicenseStatus>,
+    result: &LicenseCheckResult
+) -> Vec<LicenseNotification> {
+    let mut notifications = Vec::new();
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    for license in &result.licenses {
+        let mut key = format!("{}|{}|{}", result.host, license.product, license.path.clone().unwrap_or_default());
+        // Further licenses in the same file are told apart by their line
+        let count = seen.entry(key.clone()).or_default();
+        *count += 1;
+        if *count > 1 {
+            key.push_str(&format!("|{}", license.line.unwrap_or(*count)));
+        }
+        let previous = notified.get(&key).copied();
+        match license.status {
+            LicenseStatus::Valid => {
+                notified.remove(&key);
+            }
+            LicenseStatus::Notice | LicenseStatus::Warning | LicenseStatus::Expired => {
+                if previous == Some(license.status) {
+                    continue;
+                }
+                notified.insert(key, license.status);
+                notifications.push(LicenseNotification {
+                    host: result.host.clone(),
+                    product: license.product.clone(),
+                    path: license.path.clone(),
+                    previous,
+                    status: license.status,
+                    expiry: license.expiry,
+                    days_remaining: license.days_remaining,
+                    detected_at: result.checked_at.unwrap_or_else(Utc::now),
+                });
+            }
+            _ => {}
+        }
+    }
+    notifications
+}
+
+pub trait NotificationSink: Send + Sync {
+    fn name(&self) -> String;
+    fn send(&self, notification: &LicenseNotification) -> Result<(), String>;
+}
+
+// POSTs each notification as JSON; curl handles HTTPS and proxies
+pub struct WebhookSink {
+    pub url: String,
+}
+
+impl NotificationSink for WebhookSink {
+    fn name(&self) -> String {
+        format!("webhook {}", self.url)
+    }
+
+    fn send(&self, notification: &LicenseNotification) -> Result<(), String> {
+        let payload = serde_json::to_string(notification).map_err(|e| e.to_string())?;
+        let mut child = Command::new("curl")
+            .args(["-sS", "--fail", "-X", "POST", "-H", "Content-Type: application/json"])
+            .args(["--max-time", &SEND_TIMEOUT.as_secs().to_string()])
+            .args(["--data-binary", "@-", &self.url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to run curl: {}", e))?;
+        child.stdin
+            .take()
+            .ok_or("curl stdin unavailable")?
+            .write_all(payload.as_bytes())
+            .map_err(|e| e.to_string())?;
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+}
+
+// Sends plain-text email through an SMTP relay that accepts mail without
+// authentication or TLS, such as a local Postfix
+pub struct SmtpSink {
+    pub server: String,
+    pub port: u16,
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+// Reads one (possibly multi-line) SMTP reply and checks its code
+fn expect_reply(reader: &mut impl BufRead, code: &str) -> Result<(), String> {
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            return Err("SMTP server closed the connection".to_string());
+        }
+        if !line.starts_with(code) {
+            return Err(format!("Unexpected SMTP reply: {}", line.trim()));
+        }
+        // "250-..." continues, "250 ..." ends the reply
+        if line.as_bytes().get(3) != Some(&b'-') {
+            return Ok(());
+        }
+    }
+}
+
+impl SmtpSink {
+    fn message(&self, notification: &LicenseNotification) -> String {
+        let body: Vec<String> = notification
+            .body()
+            .lines()
+            // Dot-stuffing, so a line with a single "." doesn't end the message
+            .map(|line| if line.starts_with('.') { format!(".{}", line) } else { line.to_string() })
+            .collect();
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}",
+            self.from,
+            self.to.join(", "),
+            notification.subject(),
+            notification.detected_at.to_rfc2822(),
+            body.join("\r\n")
+        )
+    }
+}
+
+impl NotificationSink for SmtpSink {
+    fn name(&self) -> String {
+        format!("smtp {}:{}", self.server, self.port)
+    }
+
+    fn send(&self, notification: &LicenseNotification) -> Result<(), String> {
+        let stream = TcpStream::connect((self.server.as_str(), self.port)).map_err(|e|
+            format!("Failed to connect to {}:{}: {}", self.server, self.port, e)
+        )?;
+        stream.set_read_timeout(Some(SEND_TIMEOUT)).map_err(|e| e.to_string())?;
+        stream.set_write_timeout(Some(SEND_TIMEOUT)).map_err(|e| e.to_string())?;
+        let mut reader = BufReader::new(stream.try_clone().map_err(|e| e.to_string())?);
+        let mut writer = stream;
+
+        expect_reply(&mut reader, "220")?;
+        let mut command = |line: &str, code: &str| -> Result<(), String> {
+            writer.write_all(format!("{}\r\n", line).as_bytes()).map_err(|e| e.to_string())?;
+            expect_reply(&mut reader, code)
+        };
+        command("EHLO it-management", "250")?;
+        command(&format!("MAIL FROM:<{}>", self.from), "250")?;
+        for recipient in &self.to {
+            command(&format!("RCPT TO:<{}>", recipient), "250")?;
+        }
+        command("DATA", "354")?;
+        command(&format!("{}\r\n.", self.message(notification)), "250")?;
+        command("QUIT", "221").ok();
+        Ok(())
+    }
+}
+
+// Sinks enabled through the environment; none means notifications are only logged
+pub fn configured_sinks() -> Vec<Box<dyn NotificationSink>> {
+    let mut sinks: Vec<Box<dyn NotificationSink>> = Vec::new();
+    if let Some(url) = get_notify_webhook_url() {
+        sinks.push(Box::new(WebhookSink { url }));
+    }
+    let recipients = get_notify_email_to();
+    if let Some((server, port)) = get_smtp_server() {
+        if recipients.is_empty() {
+            log::warn!("SMTP_SERVER is set but NOTIFY_EMAIL_TO is empty, email notifications are off");
+        } else {
+            sinks.push(Box::new(SmtpSink { server, port, from: get_notify_email_from(), to: recipients }));
+        }
+    }
+    sinks
+}
+
+// Sends every notification to every sink and returns the ones at least one
+// sink accepted. Without sinks they are only logged, which counts as sent.
+pub fn send_all(sinks: &[Box<dyn NotificationSink>], notifications: &[LicenseNotification]) -> Vec<LicenseNotification> {
+    let mut delivered = Vec::new();
+    for notification in notifications {
+        log::info!("License notification: {}", notification.subject());
+        let mut sent = sinks.is_empty();
+        for sink in sinks {
+            match sink.send(notification) {
+                Ok(()) => {
+                    sent = true;
+                }
+                Err(e) => log::error!("Failed to send license notification via {}: {}", sink.name(), e),
+            }
+        }
+        if sent {
+            delivered.push(notification.clone());
+        }
+    }
+    delivered
+}
+
+lazy_static::lazy_static! {
+    static ref SENDING: Mutex<()> = Mutex::new(());
+}
+
+// Sends the queued notifications in the background so a slow sink never
+// holds up a license check. Whatever no sink accepts stays queued and is
+// retried after the next check.
+pub fn dispatch() {
+    thread::spawn(|| {
+        // One sender at a time; it also picks up what's queued while it runs
+        let Ok(_sending) = SENDING.try_lock() else {
+            return;
+        };
+        let sinks = configured_sinks();
+        let mut attempted: Vec<LicenseNotification> = Vec::new();
+        loop {
+            let batch: Vec<LicenseNotification> = pending_notifications()
+                .into_iter()
+                .filter(|notification| !attempted.contains(notification))
+                .collect();
+            if batch.is_empty() {
+                break;
+            }
+            if let Err(e) = remove_delivered(&send_all(&sinks, &batch)) {
+                log::error!("Failed to update queued license notifications: {}", e);
+            }
+            attempted.extend(batch);
+        }
+    });
+}
@@ -0,0 +1,344 @@
+use std::fs;
+use chrono::{ DateTime, NaiveDate, NaiveDateTime };
+use regex::Regex;
+use serde::{ Serialize, Deserialize };
+use super::types::{ LicenseStatus, ProductLicense };
+use crate::configs::get_license_rules_config_path;
+use crate::server::collectors::COMET_PRODUCT;
+use crate::server::collectors::custom::{ extract_value, Extraction };
+
+// Mark the start of each license file in the remote command's output
+const FILE_MARKER: &str = "==> ";
+const UNREADABLE_MARKER: &str = "==! ";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DateExtraction {
+    // First capture group, or the whole match when the pattern has none
+    Regex(String),
+    // `Key: value` or `Key = value` line, ignoring case and leading `//` or `#`
+    KeyValue(String),
+    // Top-level key of a property list; binary plists are converted on the host
+    PlistKey(String),
+    // Dotted path into a JSON license file, e.g. `$.license.expires`
+    JsonPath(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct ExpiryThresholds {
+    pub warning_days: i64,
+    pub notice_days: i64,
+}
+
+impl Default for ExpiryThresholds {
+    fn default() -> Self {
+        Self { warning_days: 30, notice_days: 90 }
+    }
+}
+
+// A product whose license file is checked over SSH, from the licenses config:
+//   { "product": "priint:comet", "paths": ["/Applications/Adobe InDesign */Plug-Ins/priint.comet*/w2_license.lic"],
+//     "extract": { "key_value": "Expires" }, "date_formats": ["%Y/%m/%d"] }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LicenseRule {
+    pub product: String,
+    pub paths: Vec<String>,
+    pub extract: DateExtraction,
+    #[serde(default = "default_date_formats")]
+    pub date_formats: Vec<String>,
+    #[serde(default)]
+    pub thresholds: ExpiryThresholds,
+}
+
+fn default_date_formats() -> Vec<String> {
+    vec!["%Y/%m/%d".to_string(), "%Y-%m-%d".to_string()]
+}
+
+// Used when no licenses config exists, matching every installed Comet version
+pub fn default_license_rules() -> Vec<LicenseRule> {
+    vec![LicenseRule {
+        product: COMET_PRODUCT.to_string(),
+        paths: vec!["/Applications/Adobe InDesign */Plug-Ins/priint.comet*/w2_license.lic".to_string()],
+        extract: DateExtraction::KeyValue("Expires".to_string()),
+        date_formats: default_date_formats(),
+        thresholds: ExpiryThresholds::default(),
+    }]
+}
+
+pub fn load_license_rules() -> Vec<LicenseRule> {
+    let path = get_license_rules_config_path();
+    let Ok(content) = fs::read_to_string(&path) else {
+        return default_license_rules();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::error!("Invalid licenses config {}: {}", path.display(), e);
+        default_license_rules()
+    })
+}
+
+// Quotes the literal parts of a glob so spaces survive the shell while `*`,
+// `?` and `[...]` still expand
+pub fn quote_glob(pattern: &str) -> String {
+    let mut quoted = String::new();
+    let mut literal = String::new();
+    let mut in_class = false;
+
+    let flush = |literal: &mut String, quoted: &mut String| {
+        if !literal.is_empty() {
+            quoted.push_str(&format!("'{}'", literal.replace('\'', "'\\''")));
+            literal.clear();
+        }
+    };
+
+    for c in pattern.chars() {
+        match c {
+            '*' | '?' if !in_class => {
+                flush(&mut literal, &mut quoted);
+                quoted.push(c);
+            }
+            '[' if !in_class => {
+                flush(&mut literal, &mut quoted);
+                in_class = true;
+                quoted.push(c);
+            }
+            ']' if in_class => {
+                in_class = false;
+                quoted.push(c);
+            }
+            _ if in_class => quoted.push(c),
+            _ => literal.push(c),
+        }
+    }
+    flush(&mut literal, &mut quoted);
+    quoted
+}
+
+// Prints every existing file matching the rule's paths, each preceded by a marker line
+pub fn remote_command(rule: &LicenseRule) -> String {
+    let globs: Vec<String> = rule.paths.iter().map(|p| quote_glob(p)).collect();
+    let read = match rule.extract {
+        DateExtraction::PlistKey(_) => r#"plutil -convert xml1 -o - "$f""#,
+        _ => r#"cat "$f""#,
+    };
+    format!(
+        r#"for f in {}; do [ -f "$f" ] || continue; if [ -r "$f" ]; then echo "{}$f"; {}; echo; else echo "{}$f"; fi; done"#,
+        globs.join(" "),
+        FILE_MARKER,
+        read,
+        UNREADABLE_MARKER
+    )
+}
+
+// Formats tried after the rule's own, covering what license files commonly
+// use. Day/month order is ambiguous with slashes, so those need a rule format.
+const COMMON_DATE_FORMATS: [&str; 16] = [
+    "%Y/%m/%d",
+    "%Y-%m-%d",
+    "%Y.%m.%d",
+    "%d.%m.%Y",
+    "%Y%m%d",
+    "%d-%b-%Y",
+    "%d %B %Y",
+    "%d %b %Y",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%B %d %Y",
+    "%b %d %Y",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%SZ",
+    "%Y-%m-%dT%H:%M:%S%.fZ",
+    "%Y-%m-%d %H:%M:%S",
+];
+
+// Expiry values meaning the license doesn't expire
+const PERPETUAL_VALUES: [&str; 5] = ["perpetual", "never", "unlimited", "permanent", "none"];
+
+// Text found where a rule expects an expiry date, with its 1-based line in
+// the license file when the extraction is line based
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateMatch {
+    pub text: String,
+    pub line: Option<usize>,
+}
+
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+// Every expiry value in the content, in file order, so files holding several
+// licenses report each of them
+pub fn extract_dates(extraction: &DateExtraction, content: &str) -> Vec<DateMatch> {
+    let captures = |pattern: &str| -> Vec<DateMatch> {
+        let Ok(re) = Regex::new(pattern) else {
+            log::error!("Invalid license regex '{}'", pattern);
+            return Vec::new();
+        };
+        re.captures_iter(content)
+            .filter_map(|c| {
+                let m = c.get(1).or_else(|| c.get(0))?;
+                Some(DateMatch { text: m.as_str().trim().to_string(), line: Some(line_at(content, m.start())) })
+            })
+            .collect()
+    };
+
+    match extraction {
+        DateExtraction::Regex(pattern) => captures(&format!("(?m){}", pattern)),
+        DateExtraction::JsonPath(path) =>
+            extract_value(&Extraction::JsonPath(path.clone()), content)
+                .map(|text| DateMatch { text, line: None })
+                .into_iter()
+                .collect(),
+        DateExtraction::KeyValue(key) =>
+            content
+                .lines()
+                .enumerate()
+                .filter_map(|(idx, line)| {
+                    let line = line.trim().trim_start_matches("//").trim_start_matches('#').trim();
+                    let (name, value) = line.split_once([':', '='])?;
+                    let value = value.trim();
+                    (name.trim().eq_ignore_ascii_case(key) && !value.is_empty()).then(|| DateMatch {
+                        text: value.to_string(),
+                        line: Some(idx + 1),
+                    })
+                })
+                .collect(),
+        DateExtraction::PlistKey(key) =>
+            captures(&format!(r"<key>{}</key>\s*<(?:string|date)>([^<]*)</", regex::escape(key))),
+    }
+}
+
+fn parse_date_exact(text: &str, formats: &[String]) -> Option<NaiveDate> {
+    formats
+        .iter()
+        .map(String::as_str)
+        .chain(COMMON_DATE_FORMATS)
+        .find_map(|format| {
+            NaiveDate::parse_from_str(text, format)
+                .ok()
+                .or_else(|| NaiveDateTime::parse_from_str(text, format).ok().map(|dt| dt.date()))
+        })
+        .or_else(|| DateTime::parse_from_rfc3339(text).ok().map(|dt| dt.date_naive()))
+}
+
+// Tries the rule's formats, then the common ones. Surrounding quotes and
+// trailing text such as `2025-06-30 (renewal)` or `2025-06-30; seats=5` are
+// ignored.
+pub fn parse_date(text: &str, formats: &[String]) -> Option<NaiveDate> {
+    let text = text.trim().trim_matches(|c| c == '"' || c == '\'');
+    let words: Vec<&str> = text.split_whitespace().collect();
+    (1..=words.len()).rev().find_map(|n| {
+        let candidate = words[..n].join(" ");
+        let candidate = candidate.trim_end_matches([';', ',', '.']);
+        parse_date_exact(candidate, formats)
+    })
+}
+
+pub fn is_perpetual(text: &str) -> bool {
+    let word = text
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'')
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches([';', ',', '.']);
+    PERPETUAL_VALUES.iter().any(|value| word.eq_ignore_ascii_case(value))
+}
+
+struct LicenseFile {
+    path: String,
+    readable: bool,
+    content: String,
+}
+
+// Splits the remote output into the license files it printed
+fn split_files(output: &str) -> Vec<LicenseFile> {
+    let mut files: Vec<LicenseFile> = Vec::new();
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix(FILE_MARKER) {
+            files.push(LicenseFile { path: path.trim().to_string(), readable: true, content: String::new() });
+        } else if let Some(path) = line.strip_prefix(UNREADABLE_MARKER) {
+            files.push(LicenseFile { path: path.trim().to_string(), readable: false, content: String::new() });
+        } else if let Some(file) = files.last_mut() {
+            file.content.push_str(line);
+            file.content.push('\n');
+        }
+    }
+    files
+}
+
+// One result per license file found, or a single NotFound result
+pub fn parse_license_files(rule: &LicenseRule, output: &str, today: &NaiveDate) -> Vec<ProductLicense> {
+    let files = split_files(output);
+    if files.is_empty() {
+        return vec![ProductLicense {
+            product: rule.product.clone(),
+            status: LicenseStatus::NotFound,
+            message: Some(format!("No file matches {}", rule.paths.join(", "))),
+            ..Default::default()
+        }];
+    }
+
+    files
+        .into_iter()
+        .flat_map(|file| {
+            let license = ProductLicense {
+                product: rule.product.clone(),
+                path: Some(file.path),
+                ..Default::default()
+            };
+            if !file.readable {
+                return vec![ProductLicense {
+                    status: LicenseStatus::Unreadable,
+                    message: Some("Permission denied".to_string()),
+                    ..license
+                }];
+            }
+            read_license(rule, license, &file.content, today)
+        })
+        .collect()
+}
+
+// One result per license in the file's content, each starting from `license`
+pub fn read_license(rule: &LicenseRule, license: ProductLicense, content: &str, today: &NaiveDate) -> Vec<ProductLicense> {
+    let matches = extract_dates(&rule.extract, content);
+    if matches.is_empty() {
+        return vec![ProductLicense {
+            status: LicenseStatus::ParseError,
+            message: Some("No expiry date found".to_string()),
+            ..license
+        }];
+    }
+    matches
+        .into_iter()
+        .map(|DateMatch { text, line }| {
+            let license = ProductLicense { matched: Some(text.clone()), line, ..license.clone() };
+            if is_perpetual(&text) {
+                return ProductLicense {
+                    status: LicenseStatus::Valid,
+                    message: Some("Perpetual license".to_string()),
+                    ..license
+                };
+            }
+            match parse_date(&text, &rule.date_formats) {
+                Some(expiry) => {
+                    let (status, days) = super::checker::analyze_expiration_date(&expiry, today, &rule.thresholds);
+                    ProductLicense { expiry: Some(expiry), days_remaining: Some(days), status, ..license }
+                }
+                None =>
+                    ProductLicense {
+                        status: LicenseStatus::ParseError,
+                        message: Some(format!("Unrecognized date: {}", text)),
+                        ..license
+                    },
+            }
+        })
+        .collect()
+}
+
+// The license needing attention first: the most severe status, so a failed
+// check outranks a healthy license, then the earliest expiry
+pub fn most_urgent(licenses: &[ProductLicense]) -> Option<&ProductLicense> {
+    licenses
+        .iter()
+        .min_by_key(|l| (std::cmp::Reverse(l.status.severity()), l.days_remaining.is_none(), l.days_remaining))
+}
@@ -0,0 +1,35 @@
+use std::sync::Once;
+use std::thread;
+use std::time::Duration;
+use log::{ error, info };
+use super::checker::check_hosts;
+use super::store::license_hosts;
+use crate::configs::{ get_license_check_interval_minutes, get_refresh_max_concurrency };
+
+// Checks every known host's licenses on a fixed interval. Results go through
+// check_and_record, so scheduled runs feed the dashboard, history and notifications.
+pub fn ensure_license_scheduler_started() {
+    static START: Once = Once::new();
+    START.call_once(|| {
+        let interval_minutes = get_license_check_interval_minutes();
+        if interval_minutes == 0 {
+            info!("Scheduled license checks are disabled");
+            return;
+        }
+
+        thread::spawn(move || {
+            let interval = Duration::from_secs(interval_minutes * 60);
+            loop {
+                thread::sleep(interval);
+
+                let hosts = license_hosts();
+                info!("Running scheduled license check on {} hosts", hosts.len());
+                let checked = futures::executor::block_on(check_hosts(hosts, get_refresh_max_concurrency()));
+                match checked {
+                    Ok(results) => info!("Scheduled license check finished for {} hosts", results.len()),
+                    Err(e) => error!("Scheduled license check failed: {}", e),
+                }
+            }
+        });
+    });
+}
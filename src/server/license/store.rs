@@ -0,0 +1,235 @@
+use std::collections::{ BTreeMap, BTreeSet };
+use std::sync::Mutex;
+use super::notify::{ detect_transitions, LicenseNotification };
+use super::types::{ LicenseCheckResult, LicenseRow, LicenseStatus, LicenseTarget };
+use crate::server::inventory::{ InventoryRecord, RecordSource };
+use crate::server::store;
+
+const LICENSE_RESULTS_FILE: &str = "license_results.json";
+const LICENSE_HISTORY_FILE: &str = "license_history.jsonl";
+// Single JSON document the history used to be kept in
+const LEGACY_HISTORY_FILE: &str = "license_history.json";
+const LICENSE_NOTIFIED_FILE: &str = "license_notified.json";
+const LICENSE_PENDING_FILE: &str = "license_pending_notifications.json";
+
+// Oldest history rows of a host's product are dropped beyond this
+const MAX_HISTORY_ROWS_PER_LICENSE: usize = 2_000;
+// Lines of dropped rows the history file may hold before it's rewritten
+const HISTORY_COMPACT_SLACK: usize = 10_000;
+
+// Check history per host, oldest first, and how many rows the file holds
+#[derive(Default)]
+struct History {
+    hosts: BTreeMap<String, Vec<LicenseRow>>,
+    file_rows: usize,
+}
+
+impl History {
+    fn load() -> Self {
+        let mut rows: Vec<LicenseRow> = store::load_lines(LICENSE_HISTORY_FILE);
+        if rows.is_empty() {
+            rows = store::load(LEGACY_HISTORY_FILE);
+            if !rows.is_empty() {
+                match store::save_lines(LICENSE_HISTORY_FILE, &rows) {
+                    Ok(()) => {
+                        let _ = std::fs::remove_file(crate::configs::get_data_dir().join(LEGACY_HISTORY_FILE));
+                    }
+                    Err(e) => log::error!("Failed to convert license history: {}", e),
+                }
+            }
+        }
+        let mut history = History { file_rows: rows.len(), ..Default::default() };
+        for row in rows {
+            history.hosts.entry(row.host.clone()).or_default().push(row);
+        }
+        for rows in history.hosts.values_mut() {
+            cap_history(rows, MAX_HISTORY_ROWS_PER_LICENSE);
+        }
+        history
+    }
+
+    fn retained(&self) -> usize {
+        self.hosts.values().map(Vec::len).sum()
+    }
+
+    fn all_rows(&self) -> Vec<LicenseRow> {
+        let mut rows: Vec<LicenseRow> = self.hosts.values().flatten().cloned().collect();
+        rows.sort_by_key(|row| row.checked_at);
+        rows
+    }
+}
+
+// Keeps the newest `max_per_product` rows of each product in one host's history
+pub fn cap_history(rows: &mut Vec<LicenseRow>, max_per_product: usize) {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut keep: Vec<bool> = rows
+        .iter()
+        .rev()
+        .map(|row| {
+            let count = counts.entry(row.product.clone()).or_default();
+            *count += 1;
+            *count <= max_per_product
+        })
+        .collect();
+    keep.reverse();
+    let mut keep = keep.into_iter();
+    rows.retain(|_| keep.next().unwrap_or(true));
+}
+
+lazy_static::lazy_static! {
+    // Latest check per host
+    static ref RESULTS: Mutex<BTreeMap<String, LicenseCheckResult>> = Mutex::new(
+        store::load(LICENSE_RESULTS_FILE)
+    );
+    static ref HISTORY: Mutex<History> = Mutex::new(History::load());
+    // Last status notified per host, product and file
+    static ref NOTIFIED: Mutex<BTreeMap<String, LicenseStatus>> = Mutex::new(
+        store::load(LICENSE_NOTIFIED_FILE)
+    );
+    // Notifications no sink has accepted yet
+    static ref PENDING: Mutex<Vec<LicenseNotification>> = Mutex::new(
+        store::load(LICENSE_PENDING_FILE)
+    );
+}
+
+// Stores the check as the host's latest result and in the history, and
+// queues the notifications it triggers
+pub fn record_check(result: &LicenseCheckResult) -> Result<Vec<LicenseNotification>, String> {
+    {
+        let mut results = RESULTS.lock().map_err(|e| e.to_string())?;
+        let mut updated = results.clone();
+        updated.insert(result.host.clone(), result.clone());
+        store::save(LICENSE_RESULTS_FILE, &updated)?;
+        *results = updated;
+    }
+    {
+        // Appended per check; the file is only rewritten once enough dropped
+        // rows have piled up in it
+        let rows = dashboard_rows(std::slice::from_ref(result));
+        let mut history = HISTORY.lock().map_err(|e| e.to_string())?;
+        store::append_lines(LICENSE_HISTORY_FILE, &rows)?;
+        history.file_rows += rows.len();
+        let host_rows = history.hosts.entry(result.host.clone()).or_default();
+        host_rows.extend(rows);
+        cap_history(host_rows, MAX_HISTORY_ROWS_PER_LICENSE);
+        if history.file_rows > history.retained() + HISTORY_COMPACT_SLACK {
+            let rows = history.all_rows();
+            store::save_lines(LICENSE_HISTORY_FILE, &rows)?;
+            history.file_rows = rows.len();
+        }
+    }
+    let mut notified = NOTIFIED.lock().map_err(|e| e.to_string())?;
+    let mut updated = notified.clone();
+    let notifications = detect_transitions(&mut updated, result);
+    // Queued before the state moves on, so a failed save can't lose them
+    if !notifications.is_empty() {
+        let mut pending = PENDING.lock().map_err(|e| e.to_string())?;
+        let mut queued = pending.clone();
+        queued.extend(notifications.iter().cloned());
+        store::save(LICENSE_PENDING_FILE, &queued)?;
+        *pending = queued;
+    }
+    // Renewals only clear entries, which has to survive a restart too
+    if updated != *notified {
+        store::save(LICENSE_NOTIFIED_FILE, &updated)?;
+        *notified = updated;
+    }
+    Ok(notifications)
+}
+
+pub fn pending_notifications() -> Vec<LicenseNotification> {
+    PENDING.lock()
+        .map(|pending| pending.clone())
+        .unwrap_or_default()
+}
+
+// Drops notifications a sink has accepted from the queue
+pub fn remove_delivered(delivered: &[LicenseNotification]) -> Result<(), String> {
+    if delivered.is_empty() {
+        return Ok(());
+    }
+    let mut pending = PENDING.lock().map_err(|e| e.to_string())?;
+    let mut remaining = pending.clone();
+    remaining.retain(|notification| !delivered.contains(notification));
+    store::save(LICENSE_PENDING_FILE, &remaining)?;
+    *pending = remaining;
+    Ok(())
+}
+
+// Past checks, oldest first, optionally for one host
+pub fn license_history(host: Option<&str>) -> Vec<LicenseRow> {
+    HISTORY.lock()
+        .map(|history| match host {
+            Some(host) => history.hosts.get(host).cloned().unwrap_or_default(),
+            None => history.all_rows(),
+        })
+        .unwrap_or_default()
+}
+
+pub fn latest_results() -> Vec<LicenseCheckResult> {
+    RESULTS.lock()
+        .map(|results| results.values().cloned().collect())
+        .unwrap_or_default()
+}
+
+// Hosts a fleet-wide check covers: everything in the inventory plus anything
+// the last network scan found
+pub fn license_hosts() -> Vec<String> {
+    fleet_hosts(
+        &crate::server::inventory::all_records(),
+        crate::server::inventory::scheduler::known_online_hosts()
+    )
+}
+
+// Manual records imported without a host are keyed by serial number, which
+// isn't anything SSH can reach
+pub fn fleet_hosts(records: &[InventoryRecord], online: Vec<String>) -> Vec<String> {
+    let hosts: BTreeSet<String> = records
+        .iter()
+        .filter(|record| !(record.source == RecordSource::Manual && record.host == record.info.serial))
+        .map(|record| record.host.clone())
+        .chain(online)
+        .collect();
+    hosts.into_iter().collect()
+}
+
+pub fn hosts_for_target(target: &LicenseTarget) -> Result<Vec<String>, String> {
+    match target {
+        LicenseTarget::Host(host) => Ok(vec![host.clone()]),
+        LicenseTarget::Group(group) => crate::server::groups::hosts_for_group(group),
+        LicenseTarget::All => Ok(license_hosts()),
+    }
+}
+
+// Flattens results into one row per license file. Hosts where nothing could
+// be read still get a row so they show up on the dashboard.
+pub fn dashboard_rows(results: &[LicenseCheckResult]) -> Vec<LicenseRow> {
+    results
+        .iter()
+        .flat_map(|result| {
+            if result.licenses.is_empty() {
+                return vec![LicenseRow {
+                    host: result.host.clone(),
+                    status: result.status,
+                    message: result.error.clone(),
+                    checked_at: result.checked_at,
+                    ..Default::default()
+                }];
+            }
+            result.licenses
+                .iter()
+                .map(|license| LicenseRow {
+                    host: result.host.clone(),
+                    product: license.product.clone(),
+                    path: license.path.clone(),
+                    line: license.line,
+                    status: license.status,
+                    expiry: license.expiry,
+                    days_remaining: license.days_remaining,
+                    message: license.message.clone(),
+                    checked_at: result.checked_at,
+                })
+                .collect()
+        })
+        .collect()
+}
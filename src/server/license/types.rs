@@ -1,21 +1,207 @@
-use chrono::NaiveDate;
+use chrono::{ DateTime, NaiveDate, Utc };
 use serde::{ Serialize, Deserialize };
+use super::rules::ExpiryThresholds;
 
-#[derive(Debug)]
-pub struct LicenseInfo {
-    pub expiration_date: Option<NaiveDate>,
-    pub status: String,
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LicenseStatus {
+    Valid,
+    Notice,
+    Warning,
+    Expired,
+    #[default]
+    NotFound,
+    Unreadable,
+    ParseError,
+    HostUnreachable,
+}
+
+impl LicenseStatus {
+    pub fn from_days_remaining(days: i64, thresholds: &ExpiryThresholds) -> Self {
+        match days {
+            d if d < 0 => LicenseStatus::Expired,
+            d if d <= thresholds.warning_days => LicenseStatus::Warning,
+            d if d <= thresholds.notice_days => LicenseStatus::Notice,
+            _ => LicenseStatus::Valid,
+        }
+    }
+
+    // Higher needs attention sooner; failed checks rank between notices and valid licenses
+    pub fn severity(&self) -> u8 {
+        match self {
+            LicenseStatus::Expired => 7,
+            LicenseStatus::Warning => 6,
+            LicenseStatus::Notice => 5,
+            LicenseStatus::HostUnreachable => 4,
+            LicenseStatus::Unreadable => 3,
+            LicenseStatus::ParseError => 2,
+            LicenseStatus::NotFound => 1,
+            LicenseStatus::Valid => 0,
+        }
+    }
+
+    // The check itself failed, so there's no expiry to report
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            LicenseStatus::NotFound |
+                LicenseStatus::Unreadable |
+                LicenseStatus::ParseError |
+                LicenseStatus::HostUnreachable
+        )
+    }
+}
+
+// One license file found on a host, or a product whose file wasn't found
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ProductLicense {
+    pub product: String,
+    pub path: Option<String>,
+    pub expiry: Option<NaiveDate>,
+    pub days_remaining: Option<i64>,
+    // Text the rule extracted before date parsing
+    pub matched: Option<String>,
+    // Line of the license file the expiry was read from
+    #[serde(default)]
+    pub line: Option<usize>,
+    pub status: LicenseStatus,
+    pub message: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SoftwareInfo {
-    pub priint_comet_2017_expiry: String,
+    pub licenses: Vec<ProductLicense>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+// A host's license check. The top-level status and expiry are those of the
+// license that needs attention first.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct LicenseCheckResult {
     pub host: String,
-    pub status: String,
+    pub status: LicenseStatus,
+    pub expiry: Option<NaiveDate>,
+    pub days_remaining: Option<i64>,
     pub error: Option<String>,
     pub debug_log: String,
+    #[serde(default)]
+    pub licenses: Vec<ProductLicense>,
+    #[serde(default)]
+    pub checked_at: Option<DateTime<Utc>>,
+}
+
+// One host × product line of the license dashboard
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LicenseRow {
+    pub host: String,
+    pub product: String,
+    pub path: Option<String>,
+    #[serde(default)]
+    pub line: Option<usize>,
+    pub status: LicenseStatus,
+    pub expiry: Option<NaiveDate>,
+    pub days_remaining: Option<i64>,
+    pub message: Option<String>,
+    pub checked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum LicenseTarget {
+    Host(String),
+    Group(String),
+    All,
+}
+
+// A renewed license file to push to hosts in place of the product's current one
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LicenseDeployRequest {
+    pub product: String,
+    pub filename: String,
+    pub content: String,
+    pub target: LicenseTarget,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentStatus {
+    // Not written yet; a host left pending was never reached
+    Pending,
+    Deployed,
+    // None of the product's license directories exist on the host
+    NoTarget,
+    Failed,
+    RolledBack,
+    RollbackFailed,
+}
+
+// A license file written to a host, with the copy of the file it replaced
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeployedFile {
+    pub path: String,
+    pub backup: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct HostDeployment {
+    pub host: String,
+    pub status: DeploymentStatus,
+    pub files: Vec<DeployedFile>,
+    pub message: Option<String>,
+    // License check run right after deploying or rolling back
+    pub check: Option<LicenseCheckResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LicenseDeployment {
+    pub id: String,
+    pub product: String,
+    pub filename: String,
+    pub expiry: Option<NaiveDate>,
+    pub target: LicenseTarget,
+    pub created_at: DateTime<Utc>,
+    pub rolled_back_at: Option<DateTime<Utc>>,
+    pub hosts: Vec<HostDeployment>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineKind {
+    FirstSeen,
+    // The expiry moved later
+    Renewed,
+    StatusChanged,
+    // Reachable host no longer has the license file
+    Disappeared,
+    StoppedReporting,
+    ResumedReporting,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TimelineEvent {
+    pub at: DateTime<Utc>,
+    pub kind: TimelineKind,
+    // Empty for host-level events
+    pub product: String,
+    pub path: Option<String>,
+    pub status: LicenseStatus,
+    pub previous_status: Option<LicenseStatus>,
+    pub expiry: Option<NaiveDate>,
+    pub previous_expiry: Option<NaiveDate>,
+    pub message: Option<String>,
+}
+
+// A license that keeps switching between readable and unreadable
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UnstableLicense {
+    pub product: String,
+    pub path: Option<String>,
+    pub changes: usize,
+}
+
+// What changed in a host's licenses over its check history, newest first
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LicenseTimeline {
+    pub host: String,
+    pub checks: usize,
+    pub last_checked: Option<DateTime<Utc>>,
+    // Last check that reached the host
+    pub last_reported: Option<DateTime<Utc>>,
+    pub events: Vec<TimelineEvent>,
+    pub unstable: Vec<UnstableLicense>,
 }
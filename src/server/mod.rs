@@ -2,6 +2,13 @@ pub mod command;
 pub mod network;
 pub mod license;
 pub mod resolve_computer;
+pub mod collectors;
+pub mod inventory;
+pub mod store;
+pub mod people;
+pub mod groups;
+pub mod calendar;
+pub mod entitlements;
 
 // Re-export commonly used functionality from command module
 pub use command::{
@@ -11,7 +18,60 @@ pub use command::{
     execute_ssh_command,
     execute_concurrent_commands,
     check_expired_adobe_plugin_comet_license,
+    get_license_dashboard,
+    recheck_licenses,
+    get_license_history,
+    get_license_timeline,
+    get_license_products,
+    preview_license_file,
+    deploy_license_file,
+    list_license_deployments,
+    rollback_license_deployment,
     get_software_info,
     clear_system_cache,
+    get_pending_updates_report,
+    get_custom_field_columns,
+    get_inventory_records,
+    refresh_all_inventory,
+    get_refresh_progress,
+    list_asset_metadata,
+    save_asset_metadata,
+    import_asset_metadata_csv,
+    get_coverage_warnings,
+    get_export_columns,
+    export_inventory,
+    import_inventory,
+    search_inventory,
+    list_saved_searches,
+    save_search,
+    delete_saved_search,
+    list_people,
+    save_person,
+    delete_person,
+    list_locations,
+    save_location,
+    delete_location,
+    check_out_machine,
+    check_in_machine,
+    get_active_assignments,
+    get_assignment_history,
+    get_person_overview,
+    list_host_tags,
+    set_host_tags,
+    list_groups,
+    save_group,
+    delete_group,
+    preview_group_rule,
+    execute_group_command,
+    check_group_licenses,
+    calendar_feed,
+    list_calendar_tokens,
+    create_calendar_token,
+    revoke_calendar_token,
+    list_entitlements,
+    save_entitlement,
+    delete_entitlement,
+    get_seat_reconciliation,
+    export_seat_reconciliation,
     DiscoveryResult,
 };
@@ -2,5 +2,5 @@ pub mod ssh;
 pub mod scan;
 
 // Re-export commonly used items
-pub use ssh::ssh_exec;
+pub use ssh::{ ssh_exec, ssh_exec_with_timeout };
 pub use scan::{ ScanState, DnsScanner, get_scan_state };
@@ -60,12 +60,27 @@ fn process_ssh_output(output: Vec<u8>, error: Vec<u8>) -> Result<String, SSHErro
     }
 }
 
+pub const DEFAULT_SSH_TIMEOUT_SECS: u64 = 30;
+
 #[server]
 pub async fn ssh_exec(
     host: String,
     username: String,
     password: String,
     cmd: String
+) -> Result<String, ServerFnError> {
+    ssh_exec_with_timeout(host, username, password, cmd, DEFAULT_SSH_TIMEOUT_SECS).await
+}
+
+// The timeout is handed to the expect script, which kills the session once
+// it has seen no output for that many seconds.
+#[server]
+pub async fn ssh_exec_with_timeout(
+    host: String,
+    username: String,
+    password: String,
+    cmd: String,
+    timeout_secs: u64
 ) -> Result<String, ServerFnError> {
     // Get the path to the ssh_script.sh
     let script_path = std::env
@@ -86,6 +101,7 @@ pub async fn ssh_exec(
         .arg(&password)
         .arg(&host)
         .arg(&cmd)
+        .arg(timeout_secs.to_string())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
@@ -95,5 +111,13 @@ pub async fn ssh_exec(
         .wait_with_output()
         .map_err(|e| SSHError::IO(format!("Failed to get command output: {}", e)))?;
 
+    // The script reports its own timeout on stdout before exiting non-zero
+    if !output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim_end().ends_with("Connection timed out") {
+            return Err(SSHError::Connection("Connection timed out".to_string()).into());
+        }
+    }
+
     process_ssh_output(output.stdout, output.stderr).map_err(|e| e.into())
 }
@@ -0,0 +1,20 @@
+pub mod types;
+pub mod store;
+
+// Re-export commonly used items
+pub use types::{ Assignment, AssignedMachine, Location, Person, PersonOverview };
+pub use store::{
+    active_assignments,
+    assignment_history,
+    check_in,
+    check_out,
+    delete_location,
+    delete_person,
+    get_location,
+    get_person,
+    list_locations,
+    list_people,
+    owner_from_hostname,
+    save_location,
+    save_person,
+};
@@ -0,0 +1,247 @@
+use std::sync::Mutex;
+use chrono::{ DateTime, Utc };
+use serde::{ Serialize, Deserialize };
+use super::types::{ Assignment, Location, Person };
+use crate::server::store;
+
+const PEOPLE_FILE: &str = "people.json";
+
+// Hostnames follow "<prefix><owner>.local", e.g. vg-ph-beam.local
+const HOSTNAME_OWNER_PREFIX: &str = "vg-ph-";
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct PeopleData {
+    pub people: Vec<Person>,
+    pub locations: Vec<Location>,
+    pub assignments: Vec<Assignment>,
+}
+
+lazy_static::lazy_static! {
+    static ref PEOPLE: Mutex<PeopleData> = Mutex::new(store::load(PEOPLE_FILE));
+}
+
+// Changes a copy and only keeps it once it's saved, so a failed save leaves
+// memory matching the file
+fn with_data<T>(update: impl FnOnce(&mut PeopleData) -> Result<T, String>) -> Result<T, String> {
+    let mut data = PEOPLE.lock().map_err(|e| e.to_string())?;
+    let mut updated = data.clone();
+    let result = update(&mut updated)?;
+    store::save(PEOPLE_FILE, &updated)?;
+    *data = updated;
+    Ok(result)
+}
+
+fn read_data<T>(read: impl FnOnce(&PeopleData) -> T) -> Result<T, String> {
+    let data = PEOPLE.lock().map_err(|e| e.to_string())?;
+    Ok(read(&data))
+}
+
+// Lowercase, dash-separated id from a display name, made unique among `taken`
+pub fn make_id(name: &str, taken: &[&str]) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let slug = if slug.is_empty() { "item".to_string() } else { slug };
+    let mut id = slug.clone();
+    let mut n = 2;
+    while taken.contains(&id.as_str()) {
+        id = format!("{}-{}", slug, n);
+        n += 1;
+    }
+    id
+}
+
+pub fn owner_from_hostname(host: &str) -> Option<String> {
+    let name = host.trim().trim_end_matches('.').trim_end_matches(".local");
+    let owner = name.to_lowercase().strip_prefix(HOSTNAME_OWNER_PREFIX)?.to_string();
+    if owner.is_empty() { None } else { Some(owner) }
+}
+
+pub fn list_people() -> Result<Vec<Person>, String> {
+    read_data(|data| {
+        let mut people = data.people.clone();
+        people.sort_by_key(|p| p.name.to_lowercase());
+        people
+    })
+}
+
+pub fn get_person(id: &str) -> Result<Option<Person>, String> {
+    read_data(|data| data.people.iter().find(|p| p.id == id).cloned())
+}
+
+// Creates the person when `id` is empty, otherwise updates it
+pub fn save_person(person: Person) -> Result<Person, String> {
+    if person.name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    with_data(|data| {
+        if let Some(location_id) = &person.location_id {
+            if !data.locations.iter().any(|l| &l.id == location_id) {
+                return Err(format!("Unknown location: {}", location_id));
+            }
+        }
+        if person.id.is_empty() {
+            let taken: Vec<&str> = data.people.iter().map(|p| p.id.as_str()).collect();
+            let person = Person { id: make_id(&person.name, &taken), ..person };
+            data.people.push(person.clone());
+            return Ok(person);
+        }
+        let existing = data.people
+            .iter_mut()
+            .find(|p| p.id == person.id)
+            .ok_or_else(|| format!("Unknown person: {}", person.id))?;
+        *existing = person.clone();
+        Ok(person)
+    })
+}
+
+// People with machines still checked out can't be removed
+pub fn delete_person(id: &str) -> Result<(), String> {
+    with_data(|data| {
+        let active = data.assignments
+            .iter()
+            .filter(|a| a.is_active() && a.person_id.as_deref() == Some(id))
+            .count();
+        if active > 0 {
+            return Err(format!("{} still has {} machine(s) checked out", id, active));
+        }
+        data.people.retain(|p| p.id != id);
+        Ok(())
+    })
+}
+
+pub fn list_locations() -> Result<Vec<Location>, String> {
+    read_data(|data| {
+        let mut locations = data.locations.clone();
+        locations.sort_by_key(|l| l.name.to_lowercase());
+        locations
+    })
+}
+
+pub fn get_location(id: &str) -> Result<Option<Location>, String> {
+    read_data(|data| data.locations.iter().find(|l| l.id == id).cloned())
+}
+
+pub fn save_location(location: Location) -> Result<Location, String> {
+    if location.name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    with_data(|data| {
+        if location.id.is_empty() {
+            let taken: Vec<&str> = data.locations.iter().map(|l| l.id.as_str()).collect();
+            let location = Location { id: make_id(&location.name, &taken), ..location };
+            data.locations.push(location.clone());
+            return Ok(location);
+        }
+        let existing = data.locations
+            .iter_mut()
+            .find(|l| l.id == location.id)
+            .ok_or_else(|| format!("Unknown location: {}", location.id))?;
+        *existing = location.clone();
+        Ok(location)
+    })
+}
+
+pub fn delete_location(id: &str) -> Result<(), String> {
+    with_data(|data| {
+        let in_use = data.people.iter().any(|p| p.location_id.as_deref() == Some(id)) ||
+            data.assignments.iter().any(|a| a.is_active() && a.location_id.as_deref() == Some(id));
+        if in_use {
+            return Err(format!("Location {} is still in use", id));
+        }
+        data.locations.retain(|l| l.id != id);
+        Ok(())
+    })
+}
+
+// Checking a machine out closes whatever assignment it had before
+pub fn check_out(
+    host: &str,
+    serial: Option<String>,
+    person_id: Option<String>,
+    location_id: Option<String>,
+    note: Option<String>
+) -> Result<Assignment, String> {
+    with_data(|data| apply_check_out(data, host, serial, person_id, location_id, note, Utc::now()))
+}
+
+pub fn apply_check_out(
+    data: &mut PeopleData,
+    host: &str,
+    serial: Option<String>,
+    person_id: Option<String>,
+    location_id: Option<String>,
+    note: Option<String>,
+    now: DateTime<Utc>
+) -> Result<Assignment, String> {
+    if person_id.is_none() && location_id.is_none() {
+        return Err("Assign the machine to a person or a location".to_string());
+    }
+    if let Some(id) = &person_id {
+        if !data.people.iter().any(|p| &p.id == id) {
+            return Err(format!("Unknown person: {}", id));
+        }
+    }
+    if let Some(id) = &location_id {
+        if !data.locations.iter().any(|l| &l.id == id) {
+            return Err(format!("Unknown location: {}", id));
+        }
+    }
+    for assignment in data.assignments.iter_mut().filter(|a| a.host == host && a.is_active()) {
+        assignment.checked_in_at = Some(now);
+    }
+    let assignment = Assignment {
+        host: host.to_string(),
+        serial,
+        person_id,
+        location_id,
+        checked_out_at: now,
+        checked_in_at: None,
+        note,
+    };
+    data.assignments.push(assignment.clone());
+    Ok(assignment)
+}
+
+pub fn check_in(host: &str, note: Option<String>) -> Result<Assignment, String> {
+    with_data(|data| apply_check_in(data, host, note, Utc::now()))
+}
+
+pub fn apply_check_in(
+    data: &mut PeopleData,
+    host: &str,
+    note: Option<String>,
+    now: DateTime<Utc>
+) -> Result<Assignment, String> {
+    let assignment = data.assignments
+        .iter_mut()
+        .find(|a| a.host == host && a.is_active())
+        .ok_or_else(|| format!("{} is not checked out", host))?;
+    assignment.checked_in_at = Some(now);
+    if note.is_some() {
+        assignment.note = note;
+    }
+    Ok(assignment.clone())
+}
+
+pub fn active_assignments() -> Result<Vec<Assignment>, String> {
+    read_data(|data| data.assignments.iter().filter(|a| a.is_active()).cloned().collect())
+}
+
+// Newest first
+pub fn assignment_history(
+    matches: impl Fn(&Assignment) -> bool
+) -> Result<Vec<Assignment>, String> {
+    read_data(|data| {
+        let mut history: Vec<Assignment> = data.assignments
+            .iter()
+            .filter(|a| matches(a))
+            .cloned()
+            .collect();
+        history.sort_by_key(|a| std::cmp::Reverse(a.checked_out_at));
+        history
+    })
+}
@@ -0,0 +1,53 @@
+use chrono::{ DateTime, Utc };
+use serde::{ Serialize, Deserialize };
+use crate::server::inventory::InventoryRecord;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Person {
+    pub id: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub department: Option<String>,
+    pub location_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+// One check-out of a machine; `checked_in_at` is set when it comes back
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Assignment {
+    pub host: String,
+    pub serial: Option<String>,
+    pub person_id: Option<String>,
+    pub location_id: Option<String>,
+    pub checked_out_at: DateTime<Utc>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+}
+
+impl Assignment {
+    pub fn is_active(&self) -> bool {
+        self.checked_in_at.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AssignedMachine {
+    pub assignment: Assignment,
+    pub record: Option<InventoryRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PersonOverview {
+    pub person: Person,
+    pub location: Option<Location>,
+    pub machines: Vec<AssignedMachine>,
+    pub history: Vec<Assignment>,
+    // Unassigned hosts whose name follows the owner naming convention
+    pub suggested_hosts: Vec<String>,
+}
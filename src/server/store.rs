@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::{ Path, PathBuf };
+use std::sync::Mutex;
+use std::sync::atomic::{ AtomicU64, Ordering };
+use chrono::Utc;
+use serde::{ Serialize, de::DeserializeOwned };
+use crate::configs::get_data_dir;
+
+static REVISION: AtomicU64 = AtomicU64::new(0);
+
+fn file_path(name: &str) -> PathBuf {
+    get_data_dir().join(name)
+}
+
+lazy_static::lazy_static! {
+    // Unparseable documents that couldn't be moved aside; saving over them is refused
+    static ref UNREADABLE: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+}
+
+// Load a JSON document from the data directory, falling back to the default
+// value when the file doesn't exist yet or can't be parsed.
+pub fn load<T: DeserializeOwned + Default>(name: &str) -> T {
+    load_path(&file_path(name))
+}
+
+// A document that can't be parsed (an older schema, a bad hand edit) is
+// renamed to `<name>.corrupt-<timestamp>` so the next save doesn't destroy it
+pub fn load_path<T: DeserializeOwned + Default>(path: &Path) -> T {
+    let Ok(content) = fs::read_to_string(path) else {
+        return T::default();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::error!("Failed to parse {}: {}", path.display(), e);
+        let corrupt = PathBuf::from(
+            format!("{}.corrupt-{}", path.display(), Utc::now().format("%Y%m%d%H%M%S"))
+        );
+        match fs::rename(path, &corrupt) {
+            Ok(()) => log::warn!("Moved {} to {}", path.display(), corrupt.display()),
+            Err(e) => {
+                log::error!("Failed to move {} aside: {}", path.display(), e);
+                if let Ok(mut unreadable) = UNREADABLE.lock() {
+                    unreadable.insert(path.to_path_buf());
+                }
+            }
+        }
+        T::default()
+    })
+}
+
+// Write a JSON document to the data directory. The file is written to a
+// temporary path first so a crash never leaves a truncated document behind.
+pub fn save<T: Serialize>(name: &str, value: &T) -> Result<(), String> {
+    save_path(&file_path(name), value)
+}
+
+pub fn save_path<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if UNREADABLE.lock().map_err(|e| e.to_string())?.contains(path) {
+        return Err(format!("Refusing to overwrite unparseable {}", path.display()));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e|
+            format!("Failed to create {}: {}", dir.display(), e)
+        )?;
+    }
+
+    let content = serde_json
+        ::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, content).map_err(|e|
+        format!("Failed to write {}: {}", tmp_path.display(), e)
+    )?;
+    fs::rename(&tmp_path, path).map_err(|e|
+        format!("Failed to replace {}: {}", path.display(), e)
+    )?;
+    REVISION.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
+
+// Appends values to a JSON lines document in the data directory, one per
+// line, without rewriting what's already there
+pub fn append_lines<T: Serialize>(name: &str, values: &[T]) -> Result<(), String> {
+    append_lines_path(&file_path(name), values)
+}
+
+pub fn append_lines_path<T: Serialize>(path: &Path, values: &[T]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e|
+            format!("Failed to create {}: {}", dir.display(), e)
+        )?;
+    }
+    let mut content = String::new();
+    for value in values {
+        let line = serde_json
+            ::to_string(value)
+            .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to append to {}: {}", path.display(), e))?;
+    REVISION.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
+
+// Every parseable line of a JSON lines document. A line cut short by a crash
+// mid-append is skipped rather than losing the rest of the file.
+pub fn load_lines<T: DeserializeOwned>(name: &str) -> Vec<T> {
+    load_lines_path(&file_path(name))
+}
+
+pub fn load_lines_path<T: DeserializeOwned>(path: &Path) -> Vec<T> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(idx, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| log::warn!("Skipping line {} of {}: {}", idx + 1, path.display(), e))
+                .ok()
+        })
+        .collect()
+}
+
+// Replaces a JSON lines document, e.g. to drop rows past their retention
+pub fn save_lines<T: Serialize>(name: &str, values: &[T]) -> Result<(), String> {
+    save_lines_path(&file_path(name), values)
+}
+
+pub fn save_lines_path<T: Serialize>(path: &Path, values: &[T]) -> Result<(), String> {
+    let tmp_path = path.with_extension("tmp");
+    let _ = fs::remove_file(&tmp_path);
+    append_lines_path(&tmp_path, values)?;
+    fs::rename(&tmp_path, path).map_err(|e|
+        format!("Failed to replace {}: {}", path.display(), e)
+    )
+}
+
+// Bumped after every successful save, so caches built from stored data can
+// tell when they're stale
+pub fn revision() -> u64 {
+    REVISION.load(Ordering::SeqCst)
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{ NaiveDate, TimeZone, Utc };
+    use crate::server::calendar::{ group_by_day, license_events, render_calendar, warranty_events };
+    use crate::server::inventory::{ AssetMetadata, InventoryRecord };
+    use crate::server::license::{ ExpiryThresholds, LicenseRow, LicenseRule, LicenseStatus };
+    use crate::server::license::rules::default_license_rules;
+    use crate::tests::common::fixtures::date;
+
+    fn row(host: &str, product: &str, expiry: NaiveDate) -> LicenseRow {
+        LicenseRow {
+            host: host.to_string(),
+            product: product.to_string(),
+            path: Some(format!("/Applications/{}/license.lic", product)),
+            status: LicenseStatus::Valid,
+            expiry: Some(expiry),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_license_events_use_rule_thresholds() {
+        let rules = vec![LicenseRule {
+            product: "Font Manager".to_string(),
+            thresholds: ExpiryThresholds { warning_days: 14, notice_days: 60 },
+            ..default_license_rules().remove(0)
+        }];
+        let rows = vec![
+            row("vg-ph-beam.local", "priint:comet", date(2025, 3, 1)),
+            row("vg-ph-beam.local", "Font Manager", date(2025, 2, 1)),
+            // Already expired, not upcoming
+            row("vg-ph-fon.local", "priint:comet", date(2024, 12, 1))
+        ];
+        let events = license_events(&rows, &rules, &date(2025, 1, 1));
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].summary, "priint:comet license expires on vg-ph-beam.local");
+        assert_eq!(events[0].thresholds, ExpiryThresholds::default());
+        assert_eq!(events[1].thresholds, ExpiryThresholds { warning_days: 14, notice_days: 60 });
+        assert!(events.iter().all(|e| e.uid.ends_with("@it-management") && !e.uid.contains('/')));
+    }
+
+    #[test]
+    fn test_warranty_events_name_inventory_host() {
+        let mut record = InventoryRecord { host: "vg-ph-beam.local".to_string(), ..Default::default() };
+        record.info.serial = "C02XL0GHJGH5".to_string();
+        let assets = vec![
+            AssetMetadata {
+                serial: "C02XL0GHJGH5".to_string(),
+                warranty_expiry: Some(date(2025, 3, 15)),
+                applecare_expiry: Some(date(2026, 3, 15)),
+                ..Default::default()
+            },
+            AssetMetadata {
+                serial: "FVFGK1Q2Q6L4".to_string(),
+                warranty_expiry: Some(date(2025, 6, 1)),
+                ..Default::default()
+            }
+        ];
+        let events = warranty_events(&assets, &[record], &date(2025, 1, 1));
+        let summaries: Vec<&str> = events.iter().map(|e| e.summary.as_str()).collect();
+
+        assert_eq!(summaries, vec![
+            "Warranty expires on vg-ph-beam.local",
+            "AppleCare expires on vg-ph-beam.local",
+            "Warranty expires on serial FVFGK1Q2Q6L4"
+        ]);
+    }
+
+    #[test]
+    fn test_group_by_day() {
+        let rows = vec![
+            row("vg-ph-beam.local", "priint:comet", date(2025, 3, 1)),
+            row("vg-ph-fon.local", "priint:comet", date(2025, 3, 1)),
+            row("vg-ph-fon.local", "Font Manager", date(2025, 4, 1))
+        ];
+        let events = group_by_day(license_events(&rows, &[], &date(2025, 1, 1)));
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].uid, "day-20250301@it-management");
+        assert_eq!(events[0].summary, "2 expirations");
+        assert_eq!(
+            events[0].description,
+            "- priint:comet license expires on vg-ph-beam.local\n- priint:comet license expires on vg-ph-fon.local"
+        );
+        assert_eq!(events[1].summary, "Font Manager license expires on vg-ph-fon.local");
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        let mut rows = vec![row("vg-ph-beam.local", "priint:comet", date(2025, 3, 1))];
+        rows[0].path = Some("/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3, final; licensed/w2_license.lic".to_string());
+        let events = license_events(&rows, &[], &date(2025, 1, 1));
+        let ics = render_calendar(&events, Utc.with_ymd_and_hms(2025, 1, 1, 6, 0, 0).unwrap());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTAMP:20250101T060000Z\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250301\r\nDTEND;VALUE=DATE:20250302\r\n"));
+        assert!(ics.contains("TRIGGER:-P90D\r\n"));
+        assert!(ics.contains("TRIGGER:-P30D\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= 75));
+
+        // Unfolded, the description keeps its escaped separators
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains("\\nFile: /Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3\\, final\\; licensed/w2_license.lic"));
+    }
+}
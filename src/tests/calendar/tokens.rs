@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{ TimeZone, Utc };
+    use crate::server::calendar::{ find_token, mask, remove_token, CalendarToken };
+
+    fn token(id: &str, value: &str) -> CalendarToken {
+        CalendarToken {
+            id: id.to_string(),
+            token: value.to_string(),
+            person_id: "anna-schmidt".to_string(),
+            created_at: Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(),
+            last_used_at: None,
+        }
+    }
+
+    const VALID: &str = "3f9a0c1d2e4b5a6978c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4";
+
+    fn tokens() -> Vec<CalendarToken> {
+        vec![
+            token("anna-schmidt", VALID),
+            CalendarToken { person_id: "jonas-weber".to_string(), ..token("jonas-weber", "a1b2c3d4e5f6") }
+        ]
+    }
+
+    // Only Anna is still in the people list
+    fn person_exists(id: &str) -> Result<bool, String> {
+        Ok(id == "anna-schmidt")
+    }
+
+    #[test]
+    fn test_valid_token_is_authorized() {
+        let tokens = tokens();
+        let entry = find_token(&tokens, VALID, person_exists).unwrap();
+        assert_eq!(entry.id, "anna-schmidt");
+    }
+
+    #[test]
+    fn test_unknown_token_is_rejected() {
+        let tokens = tokens();
+        assert_eq!(find_token(&tokens, "0000", person_exists).unwrap_err(), "Invalid calendar token");
+        // A masked token from the list is not a usable token
+        assert!(find_token(&tokens, &mask(&tokens[0]).token, person_exists).is_err());
+    }
+
+    #[test]
+    fn test_empty_token_is_rejected() {
+        let mut tokens = tokens();
+        assert!(find_token(&tokens, "", person_exists).is_err());
+        // Not even when a stored token is empty
+        tokens[0].token = String::new();
+        assert!(find_token(&tokens, "", person_exists).is_err());
+    }
+
+    #[test]
+    fn test_revoked_token_is_rejected() {
+        let mut tokens = tokens();
+        remove_token(&mut tokens, "anna-schmidt").unwrap();
+        assert!(find_token(&tokens, VALID, person_exists).is_err());
+        assert_eq!(remove_token(&mut tokens, "anna-schmidt").unwrap_err(), "Unknown calendar token");
+    }
+
+    #[test]
+    fn test_token_of_deleted_person_is_rejected() {
+        let tokens = tokens();
+        assert!(find_token(&tokens, "a1b2c3d4e5f6", person_exists).is_err());
+        // A failing people lookup doesn't let the token through either
+        assert!(find_token(&tokens, VALID, |_| Err("people store unavailable".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_mask_keeps_only_the_last_characters() {
+        let masked = mask(&token("anna-schmidt", VALID));
+        assert_eq!(masked.token, "…d3e4");
+        assert_eq!(masked.id, "anna-schmidt");
+        assert_eq!(masked.person_id, "anna-schmidt");
+        assert_eq!(mask(&token("short", "ab")).token, "…ab");
+    }
+}
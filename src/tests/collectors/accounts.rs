@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::accounts::{ parse_user_accounts, UserAccount };
+
+    const DSCL: &str = include_str!("../fixtures/accounts/dscl.txt");
+
+    #[test]
+    fn test_parse_account_details() {
+        let accounts = parse_user_accounts(DSCL);
+        assert_eq!(accounts.len(), 4);
+        assert_eq!(accounts[0], UserAccount {
+            name: "fluke".to_string(),
+            uid: Some(501),
+            real_name: "Fluke Jesadakorn".to_string(),
+            home_directory: "/Users/fluke".to_string(),
+            is_admin: true,
+            last_login: Some("Mon May 13 09:12".to_string()),
+            hidden: false,
+            disabled: false,
+            secure_token: Some(true),
+        });
+    }
+
+    #[test]
+    fn test_parse_hidden_and_disabled_accounts() {
+        let accounts = parse_user_accounts(DSCL);
+
+        let admin = &accounts[1];
+        assert!(admin.hidden);
+        assert!(admin.is_admin);
+        assert_eq!(admin.secure_token, Some(false));
+        assert_eq!(admin.last_login.as_deref(), Some("Sun May 12 18:40"));
+
+        let intern = &accounts[2];
+        assert!(intern.disabled);
+        assert!(!intern.is_admin);
+        assert_eq!(intern.last_login, None);
+
+        let root = &accounts[3];
+        assert_eq!(root.real_name, "System Administrator");
+        assert!(root.hidden);
+        assert_eq!(root.last_login, None);
+    }
+
+    #[test]
+    fn test_parse_without_admin_group() {
+        let accounts = parse_user_accounts("=== User: fluke ===\nUniqueID: 501\n");
+        assert_eq!(accounts.len(), 1);
+        assert!(!accounts[0].is_admin);
+        assert_eq!(accounts[0].secure_token, None);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::adobe::{ parse_adobe_info, CometPlugin, CreativeCloudUser };
+    use crate::server::inventory::export::column_values;
+    use crate::server::inventory::InventoryRecord;
+
+    const WORKSTATION: &str = include_str!("../fixtures/adobe/indesign_workstation.txt");
+
+    #[test]
+    fn test_parse_adobe_apps_and_installs() {
+        let adobe = parse_adobe_info(WORKSTATION);
+
+        let apps: Vec<(&str, &str)> = adobe.apps.iter().map(|a| (a.name.as_str(), a.version.as_str())).collect();
+        assert_eq!(apps, vec![
+            ("Acrobat Pro", "24.002.20759"),
+            // No bundle name, named after the bundle
+            ("Adobe Creative Cloud", "6.1.0.587"),
+            ("Adobe InDesign 2023", "18.5.2"),
+            ("Adobe InDesign 2024", "19.5")
+        ]);
+        assert_eq!(adobe.apps[3].product(), "Adobe InDesign");
+        assert_eq!(adobe.installs.len(), 2);
+        assert_eq!(adobe.installs[0].sap_code, "IDSN");
+        assert_eq!(adobe.cc_users, vec![CreativeCloudUser {
+            account: "beam".to_string(),
+            email: "beam@example.co.th".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn test_parse_comet_plugins_per_indesign() {
+        let adobe = parse_adobe_info(WORKSTATION);
+
+        assert_eq!(adobe.comet_plugins, vec![
+            // Version taken from the folder name when the plugin has no Info.plist
+            CometPlugin {
+                version: "4.2.1".to_string(),
+                indesign: "Adobe InDesign 2023".to_string(),
+                path: "/Applications/Adobe InDesign 2023/Plug-Ins/priint.comet 4.2.1 R29800".to_string(),
+            },
+            CometPlugin {
+                version: "4.3.1".to_string(),
+                indesign: "Adobe InDesign 2024".to_string(),
+                path: "/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet".to_string(),
+            }
+        ]);
+        assert_eq!(adobe.installed_products(), vec![
+            "Acrobat Pro",
+            "Adobe Creative Cloud",
+            "Adobe InDesign",
+            "priint:comet"
+        ]);
+    }
+
+    #[test]
+    fn test_adobe_export_columns() {
+        let mut record = InventoryRecord { host: "vg-ph-beam.local".to_string(), ..Default::default() };
+        record.info.adobe = parse_adobe_info(WORKSTATION);
+
+        assert_eq!(column_values("comet_plugins", &record), vec![
+            "4.2.1 (Adobe InDesign 2023)",
+            "4.3.1 (Adobe InDesign 2024)"
+        ]);
+        assert_eq!(column_values("adobe_apps", &record)[3], "Adobe InDesign 2024 19.5");
+        assert_eq!(column_values("cc_users", &record), vec!["beam@example.co.th"]);
+        assert!(parse_adobe_info("").apps.is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::linux;
+    use crate::server::collectors::os::{ parse_os_detection, OsFamily };
+    use crate::server::collectors::DiskType;
+    use crate::server::resolve_computer::ComputerInfo;
+
+    #[test]
+    fn test_detect_os_family() {
+        let output = "CONN_TEST_OK\nLinux\nPRETTY_NAME=\"Ubuntu 22.04.4 LTS\"\nNAME=\"Ubuntu\"\n";
+        assert_eq!(
+            parse_os_detection(output),
+            (OsFamily::Linux, Some("Ubuntu 22.04.4 LTS".to_string()))
+        );
+        assert_eq!(parse_os_detection("CONN_TEST_OK\nDarwin\n"), (OsFamily::MacOs, None));
+        assert_eq!(parse_os_detection("CONN_TEST_OK\n"), (OsFamily::Unknown, None));
+    }
+
+    #[test]
+    fn test_parse_lsblk_partitions() {
+        let output = r#"{"blockdevices": [
+            {"name": "nvme0n1", "size": 1000204886016, "type": "disk", "mountpoint": null,
+             "rota": "0", "model": "Samsung SSD 980 PRO 1TB", "fsavail": null, "fsused": null,
+             "children": [
+                {"name": "nvme0n1p1", "size": 536870912, "type": "part", "mountpoint": "/boot/efi",
+                 "rota": "0", "model": null, "fsavail": 530000000, "fsused": 6870912},
+                {"name": "nvme0n1p2", "size": 999666999296, "type": "part", "mountpoint": "/",
+                 "rota": "0", "model": null, "fsavail": 800000000000, "fsused": 150000000000}
+             ]}
+        ]}"#;
+        let mut info = ComputerInfo::default();
+        linux::parse_storage_info(&mut info, output);
+
+        let details = &info.storage_details;
+        assert_eq!(details.volumes.len(), 2);
+        assert_eq!(details.disk_type, DiskType::Ssd);
+        assert_eq!(details.media_name, "Samsung SSD 980 PRO 1TB");
+        let root = details.root_volume().unwrap();
+        assert_eq!(root.device, "/dev/nvme0n1p2");
+        assert_eq!(root.free_bytes, 800000000000);
+        assert_eq!(info.storage, "999.7 GB");
+    }
+
+    #[test]
+    fn test_parse_getent_accounts() {
+        let output = "root:x:0:0:root:/root:/bin/bash\n\
+            daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin\n\
+            render:x:1000:1000:Render Node,,,:/home/render:/bin/bash\n\
+            svc:x:1001:1001::/home/svc:/usr/sbin/nologin\n\
+            nobody:x:65534:65534:nobody:/nonexistent:/usr/sbin/nologin\n\
+            === Admins ===\n\
+            sudo:x:27:render\n";
+        let mut info = ComputerInfo::default();
+        linux::parse_users_info(&mut info, output);
+
+        let names: Vec<&str> = info.user_accounts.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["root", "render", "svc"]);
+        assert_eq!(info.home_users, vec!["render", "svc"]);
+        assert_eq!(info.user_accounts[1].real_name, "Render Node");
+        assert!(info.user_accounts[1].is_admin);
+        assert!(info.user_accounts[2].disabled);
+    }
+
+    // End-to-end: run the Linux collector commands on this machine
+    #[cfg(target_os = "linux")]
+    fn run_local(command: &str) -> String {
+        let output = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .output()
+            .expect("failed to run collector command");
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_local_linux_collectors() {
+        let detection = run_local(crate::server::collectors::os::get_os_detection_command());
+        assert_eq!(parse_os_detection(&detection).0, OsFamily::Linux);
+
+        let mut info = ComputerInfo::default();
+        linux::parse_hardware_info(&mut info, &run_local(linux::get_hardware_command()));
+        linux::parse_system_info(&mut info, &run_local(linux::get_system_command()));
+        linux::parse_network_info(&mut info, &run_local(linux::get_network_command()));
+        linux::parse_storage_info(&mut info, &run_local(linux::get_storage_command()));
+        linux::parse_users_info(&mut info, &run_local(linux::get_users_command()));
+        linux::parse_graphics_info(&mut info, &run_local(linux::get_graphics_command()));
+
+        assert!(!info.architecture.is_empty());
+        assert!(!info.memory.is_empty());
+        assert!(!info.title.is_empty());
+        assert!(!info.version.is_empty());
+        assert!(info.user_accounts.iter().any(|a| a.name == "root"));
+        assert!(info.network_interfaces.iter().all(|i| i.device != "lo"));
+    }
+}
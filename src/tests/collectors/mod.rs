@@ -0,0 +1,8 @@
+pub mod software_updates;
+pub mod storage;
+pub mod power;
+pub mod network;
+pub mod accounts;
+pub mod linux;
+pub mod registry;
+pub mod adobe;
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::network::{ parse_network_interfaces, primary_addresses };
+
+    const MACBOOK_PRO: &str = include_str!("../fixtures/network/macbook_pro.txt");
+
+    #[test]
+    fn test_parse_hardware_ports() {
+        let interfaces = parse_network_interfaces(MACBOOK_PRO);
+        let devices: Vec<&str> = interfaces.iter().map(|i| i.device.as_str()).collect();
+        assert_eq!(devices, vec!["bridge0", "en7", "en0", "en1", "utun3"]);
+
+        let wifi = &interfaces[2];
+        assert_eq!(wifi.hardware_port, "Wi-Fi");
+        assert_eq!(wifi.mac_address, "a4:83:e7:12:34:56");
+        assert_eq!(wifi.ipv4, vec!["192.168.20.45"]);
+        assert_eq!(wifi.ipv6, vec!["fe80::1c2b:9a1f:4e3d:abcd"]);
+        assert!(wifi.active);
+        assert_eq!(wifi.ssid.as_deref(), Some("Office-5G"));
+
+        assert_eq!(interfaces[0].mac_address, "");
+        assert!(!interfaces[3].active);
+    }
+
+    #[test]
+    fn test_primary_addresses_follow_port_type() {
+        // Wi-Fi is listed by ifconfig before the USB LAN adapter
+        let interfaces = parse_network_interfaces(MACBOOK_PRO);
+        let (lan_ip, wifi_ip, wifi_name) = primary_addresses(&interfaces);
+        assert_eq!(lan_ip, "192.168.10.23");
+        assert_eq!(wifi_ip, "192.168.20.45");
+        assert_eq!(wifi_name, "Office-5G");
+    }
+
+    #[test]
+    fn test_parse_empty_output() {
+        let interfaces = parse_network_interfaces("");
+        assert!(interfaces.is_empty());
+        assert_eq!(primary_addresses(&interfaces), (String::new(), String::new(), String::new()));
+    }
+}
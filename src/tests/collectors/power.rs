@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::power::parse_power_info;
+
+    const MACBOOK: &str = include_str!("../fixtures/power/macbook.txt");
+    const IMAC: &str = include_str!("../fixtures/power/imac.txt");
+
+    #[test]
+    fn test_parse_battery_health() {
+        let power = parse_power_info(MACBOOK);
+        assert!(power.has_battery);
+        assert_eq!(power.cycle_count, Some(1043));
+        assert_eq!(power.condition, "Service Recommended");
+        assert_eq!(power.maximum_capacity, Some(71));
+        assert!(power.battery_service_recommended);
+    }
+
+    #[test]
+    fn test_parse_desktop_without_battery() {
+        let power = parse_power_info(IMAC);
+        assert!(!power.has_battery);
+        assert_eq!(power.cycle_count, None);
+        assert!(!power.battery_service_recommended);
+        assert_eq!(power.uptime_days(), Some(2));
+    }
+
+    #[test]
+    fn test_uptime_threshold() {
+        let mut power = parse_power_info(MACBOOK);
+        assert_eq!(power.boot_time.unwrap().timestamp(), 1712000000);
+        assert_eq!(power.uptime_seconds, Some(4_000_000));
+
+        power.apply_uptime_threshold(30);
+        assert!(power.uptime_exceeded);
+        power.apply_uptime_threshold(60);
+        assert!(!power.uptime_exceeded);
+    }
+}
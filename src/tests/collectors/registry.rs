@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::custom::{ extract_value, CustomCollector, Extraction };
+    use crate::server::collectors::output::extract_json;
+    use crate::server::collectors::registry::{
+        builtin_collectors,
+        Collector,
+        CollectorResult,
+        CollectorStatus,
+        Parser,
+    };
+    use crate::server::collectors::OsFamily;
+    use crate::server::resolve_computer::ComputerInfo;
+
+    fn builtin_names(os_family: OsFamily) -> Vec<String> {
+        builtin_collectors()
+            .into_iter()
+            .filter(|c| c.applies_to(os_family))
+            .map(|c| c.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_builtin_collectors_per_os() {
+        let macos = builtin_names(OsFamily::MacOs);
+        assert!(macos.contains(&"software_updates".to_string()));
+        assert!(macos.contains(&"power".to_string()));
+        assert!(macos.contains(&"adobe".to_string()));
+        assert_eq!(builtin_names(OsFamily::Unknown), macos);
+
+        let linux = builtin_names(OsFamily::Linux);
+        assert_eq!(linux, vec!["hardware", "system", "network", "storage", "users", "graphics"]);
+        assert!(builtin_collectors().iter().all(|c| c.timeout_secs > 0 && !c.command.is_empty()));
+    }
+
+    #[test]
+    fn test_parse_custom_collector_config() {
+        let config = r#"[
+            { "field": "filevault", "label": "FileVault", "command": "fdesetup status",
+              "os": ["MacOs"], "extract": { "regex": "FileVault is (On|Off)" } },
+            { "field": "boot_rom", "command": "system_profiler -json SPHardwareDataType",
+              "timeout_secs": 90, "extract": { "json_path": "$.SPHardwareDataType[0].boot_rom_version" } }
+        ]"#;
+        let collectors: Vec<CustomCollector> = serde_json::from_str(config).unwrap();
+        assert_eq!(collectors[0].os, vec![OsFamily::MacOs]);
+        assert_eq!(collectors[0].timeout_secs, 30);
+        assert_eq!(collectors[0].column().label, "FileVault");
+        assert_eq!(collectors[1].column().label, "boot_rom");
+        assert_eq!(collectors[1].timeout_secs, 90);
+        assert!(collectors[1].os.is_empty());
+    }
+
+    #[test]
+    fn test_regex_extraction() {
+        let extract = Extraction::Regex("FileVault is (On|Off)".to_string());
+        assert_eq!(extract_value(&extract, "FileVault is On.\n"), Some("On".to_string()));
+        assert_eq!(extract_value(&extract, "command not found"), None);
+
+        let whole_match = Extraction::Regex(r"^\d+\.\d+".to_string());
+        assert_eq!(extract_value(&whole_match, "Password:\n14.4.1\n"), Some("14.4".to_string()));
+        assert_eq!(extract_value(&Extraction::Regex("(".to_string()), "anything"), None);
+    }
+
+    #[test]
+    fn test_json_path_extraction() {
+        // Prompt and status lines from the expect wrapper surround the JSON
+        let output = "(ph-admin@vg-ph-fon.local) Password:\n\
+            {\"SPHardwareDataType\": [{\"boot_rom_version\": \"10151.101.3\", \"number_processors\": 8}]}\n\
+            SSH connection terminated unexpectedly or completed";
+        assert!(extract_json(output).is_some());
+
+        let path = |p: &str| extract_value(&Extraction::JsonPath(p.to_string()), output);
+        assert_eq!(path("$.SPHardwareDataType[0].boot_rom_version"), Some("10151.101.3".to_string()));
+        assert_eq!(path("SPHardwareDataType.0.number_processors"), Some("8".to_string()));
+        assert_eq!(path("$.SPHardwareDataType[1].boot_rom_version"), None);
+    }
+
+    #[test]
+    fn test_custom_collector_writes_custom_field() {
+        let collector = Collector {
+            name: "custom:filevault".to_string(),
+            command: "fdesetup status".to_string(),
+            os: vec![OsFamily::MacOs],
+            timeout_secs: 30,
+            parser: Parser::Custom {
+                field: "filevault".to_string(),
+                extract: Extraction::Regex("FileVault is (On|Off)".to_string()),
+            },
+        };
+        assert!(!collector.applies_to(OsFamily::Linux));
+
+        let mut info = ComputerInfo::default();
+        collector.apply(&mut info, "FileVault is Off.");
+        assert_eq!(info.custom_fields.get("filevault"), Some(&"Off".to_string()));
+    }
+
+    #[test]
+    fn test_failed_sections() {
+        let result = |name: &str, status: CollectorStatus| CollectorResult {
+            name: name.to_string(),
+            status,
+            duration_ms: 120,
+        };
+        let info = ComputerInfo {
+            collector_results: vec![
+                result("hardware", CollectorStatus::Ok),
+                result("storage", CollectorStatus::Failed("Permission denied".to_string())),
+                result("software_updates", CollectorStatus::TimedOut),
+                result("power", CollectorStatus::Skipped("not applicable to Linux".to_string()))
+            ],
+            ..Default::default()
+        };
+
+        let failed: Vec<&str> = info
+            .failed_sections()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(failed, vec!["storage", "software_updates"]);
+        assert_eq!(info.collector_results[1].status.to_string(), "failed: Permission denied");
+        assert!(info.collector_results[0].status.is_ok());
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::software_updates::{ parse_software_updates, SoftwareUpdate };
+
+    const MOJAVE: &str = include_str!("../fixtures/software_updates/mojave.txt");
+    const CATALINA: &str = include_str!("../fixtures/software_updates/catalina.txt");
+    const SONOMA: &str = include_str!("../fixtures/software_updates/sonoma.txt");
+    const NONE: &str = include_str!("../fixtures/software_updates/none.txt");
+
+    #[test]
+    fn test_parse_no_updates() {
+        assert!(parse_software_updates(NONE).is_empty());
+        assert!(parse_software_updates("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_legacy_format() {
+        let updates = parse_software_updates(MOJAVE);
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[0], SoftwareUpdate {
+            label: "Security Update 2019-001 (Mojave)-10.14.3".to_string(),
+            title: "Security Update 2019-001 (Mojave)".to_string(),
+            version: "10.14.3".to_string(),
+            size: "1024000K".to_string(),
+            recommended: true,
+            restart_required: true,
+        });
+        assert_eq!(updates[1].title, "Safari");
+        assert!(updates[1].recommended);
+        assert!(!updates[1].restart_required);
+        assert!(!updates[2].recommended);
+        assert_eq!(updates[2].size, "270322K");
+    }
+
+    #[test]
+    fn test_parse_catalina_format() {
+        let updates = parse_software_updates(CATALINA);
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0], SoftwareUpdate {
+            label: "Security Update 2021-004-10.15.7".to_string(),
+            title: "Security Update 2021-004".to_string(),
+            version: "10.15.7".to_string(),
+            size: "1952358K".to_string(),
+            recommended: true,
+            restart_required: true,
+        });
+        assert_eq!(updates[1].label, "Safari15.0CatalinaAuto-15.0");
+        assert!(!updates[1].restart_required);
+    }
+
+    #[test]
+    fn test_parse_modern_format() {
+        let updates = parse_software_updates(SONOMA);
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[0].label, "macOS Sonoma 14.4.1-23E224");
+        assert_eq!(updates[0].size, "1024000KiB");
+        assert!(updates[0].restart_required);
+        assert_eq!(updates[1].title, "Command Line Tools for Xcode");
+        assert!(!updates[1].restart_required);
+        assert!(!updates[2].recommended);
+        assert!(updates[2].restart_required);
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::storage::{ parse_storage_details, format_bytes, DiskType };
+
+    const APFS_SSD: &str = include_str!("../fixtures/storage/apfs_ssd.txt");
+    const FUSION_FAILING: &str = include_str!("../fixtures/storage/fusion_failing.txt");
+
+    #[test]
+    fn test_parse_apfs_volumes() {
+        let details = parse_storage_details(APFS_SSD);
+        assert_eq!(details.volumes.len(), 5);
+
+        let root = details.root_volume().unwrap();
+        assert_eq!(root.device, "/dev/disk3s1s1");
+        assert_eq!(root.size_bytes, 482797652 * 1024);
+        assert_eq!(root.used_bytes, 10138172 * 1024);
+        assert_eq!(root.free_bytes, 3012348 * 1024);
+        assert_eq!(details.volumes[4].mount_point, "/Volumes/Backup Disk");
+    }
+
+    #[test]
+    fn test_parse_disk_health() {
+        let details = parse_storage_details(APFS_SSD);
+        assert_eq!(details.smart_status, "Verified");
+        assert_eq!(details.media_name, "APPLE SSD AP0512Q");
+        assert_eq!(details.disk_type, DiskType::Ssd);
+
+        let details = parse_storage_details(FUSION_FAILING);
+        assert_eq!(details.smart_status, "Failing");
+        assert_eq!(details.disk_type, DiskType::Fusion);
+    }
+
+    #[test]
+    fn test_low_free_space_threshold() {
+        let details = parse_storage_details(APFS_SSD);
+        // ~3 GB free on the boot volume
+        assert!(details.is_low_on_space(10_000_000_000));
+        assert!(!details.is_low_on_space(1_000_000_000));
+        assert!(!parse_storage_details("").is_low_on_space(10_000_000_000));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(3_084_644_352), "3.1 GB");
+        assert_eq!(format_bytes(494_384_795_648), "494.4 GB");
+    }
+}
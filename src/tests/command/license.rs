@@ -1,27 +1,138 @@
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+    use std::thread;
+    use std::time::Duration;
+    use crate::components::license_status::describe_status;
+    use crate::configs::{ get_ssh_user, DEFAULT_SSH_USER };
+    use crate::server::license::rules::{ default_license_rules, parse_license_files, ExpiryThresholds };
+    use crate::server::license::{
+        analyze_expiration_date,
+        check_hosts_with,
+        summarize,
+        unreachable_result,
+        LicenseCheckResult,
+        LicenseChecker,
+        LicenseStatus,
+        ProductLicense,
+    };
+    use crate::tests::common::fixtures::date;
+
     #[test]
     fn test_license_checker_creation() {
-        todo!("Test creating new LicenseChecker instance")
+        let checker = LicenseChecker::new("vg-ph-beam.local".to_string());
+        assert!(format!("{:?}", checker).contains("vg-ph-beam.local"));
+    }
+
+    #[test]
+    fn test_license_checker_logs_in_as_ssh_user() {
+        let checker = LicenseChecker::new("vg-ph-beam.local".to_string());
+        assert_eq!(checker.username(), get_ssh_user());
+        assert_ne!(checker.username(), "vg-ph-beam.local");
+        if std::env::var("SSH_USER").is_err() {
+            assert_eq!(checker.username(), DEFAULT_SSH_USER);
+        }
+    }
+
+    #[test]
+    fn test_check_hosts_overlaps_slow_checks() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let hosts = vec!["vg-ph-beam.local".to_string(), "vg-ph-alpha.local".to_string()];
+
+        let check = {
+            let running = running.clone();
+            let peak = peak.clone();
+            move |host: &str| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(100));
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(LicenseCheckResult { host: host.to_string(), ..Default::default() })
+            }
+        };
+        let results = futures::executor::block_on(check_hosts_with(hosts, 2, check)).unwrap();
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        let hosts: Vec<&str> = results.iter().map(|r| r.host.as_str()).collect();
+        assert_eq!(hosts, vec!["vg-ph-alpha.local", "vg-ph-beam.local"]);
     }
 
     #[test]
-    fn test_check_comet_license() {
-        todo!("Test checking Comet license functionality")
+    fn test_unreachable_host_result() {
+        let result = unreachable_result("vg-ph-beam.local", "Connection refused");
+
+        assert_eq!(result.host, "vg-ph-beam.local");
+        assert_eq!(result.status, LicenseStatus::HostUnreachable);
+        assert!(result.status.is_failure());
+        assert_eq!(result.error.as_deref(), Some("SSH connection error: Connection refused"));
+        assert!(result.debug_log.contains("vg-ph-beam.local"));
+        assert!(result.licenses.is_empty());
+        assert_eq!(result.days_remaining, None);
+        assert!(result.checked_at.is_some());
+        assert_eq!(
+            describe_status(result.status, result.expiry, result.days_remaining),
+            "[UNREACHABLE] Host unreachable over SSH"
+        );
     }
 
     #[test]
     fn test_parse_license_output() {
-        todo!("Test parsing various license output formats")
+        let output = "==> /Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic\n// Expires: 2024/07/02\n";
+        let licenses = parse_license_files(&default_license_rules()[0], output, &date(2024, 6, 1));
+        let result = summarize("vg-ph-beam.local", licenses);
+
+        assert_eq!(result.status, LicenseStatus::Notice);
+        assert_eq!(result.expiry, Some(date(2024, 7, 2)));
+        assert_eq!(result.days_remaining, Some(31));
+        assert_eq!(result.licenses.len(), 1);
+        assert_eq!(
+            describe_status(result.status, result.expiry, result.days_remaining),
+            "[NOTICE] Expires 2024-07-02 (31 days remaining)"
+        );
     }
 
     #[test]
     fn test_license_expiration_analysis() {
-        todo!("Test license expiration date analysis")
+        let today = date(2024, 6, 1);
+        let thresholds = ExpiryThresholds::default();
+        let cases = [
+            (date(2024, 5, 31), LicenseStatus::Expired, -1),
+            (date(2024, 6, 1), LicenseStatus::Warning, 0),
+            (date(2024, 7, 1), LicenseStatus::Warning, 30),
+            (date(2024, 7, 2), LicenseStatus::Notice, 31),
+            (date(2024, 8, 30), LicenseStatus::Notice, 90),
+            (date(2024, 8, 31), LicenseStatus::Valid, 91),
+        ];
+        for (expiry, status, days) in cases {
+            assert_eq!(analyze_expiration_date(&expiry, &today, &thresholds), (status, days), "{}", expiry);
+        }
+        assert_eq!(
+            describe_status(LicenseStatus::Expired, Some(date(2024, 5, 31)), Some(-1)),
+            "[EXPIRED] 2024-05-31 (1 days overdue)"
+        );
     }
 
     #[test]
     fn test_license_file_not_found() {
-        todo!("Test handling of missing license files")
+        let licenses = parse_license_files(&default_license_rules()[0], "", &date(2024, 6, 1));
+        let result = summarize("vg-ph-beam.local", licenses);
+        assert_eq!(result.status, LicenseStatus::NotFound);
+        assert_eq!(result.days_remaining, None);
+        assert!(result.status.is_failure());
+
+        // A readable license wins over a missing one when summarizing
+        let licenses = vec![
+            ProductLicense { product: "Other".to_string(), ..Default::default() },
+            ProductLicense {
+                product: "priint:comet".to_string(),
+                status: LicenseStatus::Expired,
+                days_remaining: Some(-3),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(summarize("vg-ph-beam.local", licenses).status, LicenseStatus::Expired);
+        assert_eq!(summarize("vg-ph-beam.local", Vec::new()).status, LicenseStatus::NotFound);
     }
 }
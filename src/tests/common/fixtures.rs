@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+use crate::server::inventory::InventoryRecord;
+use crate::server::resolve_computer::ComputerInfo;
+
+#[allow(dead_code)]
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+// The day license fixtures are checked on
+#[allow(dead_code)]
+pub fn today() -> NaiveDate {
+    date(2025, 2, 1)
+}
+
+// An inventory record with nothing but its host
+#[allow(dead_code)]
+pub fn record(host: &str) -> InventoryRecord {
+    InventoryRecord { host: host.to_string(), ..Default::default() }
+}
+
+// A Mac as resolved over SSH, with its owner and the admin account as home folders
+#[allow(dead_code)]
+pub fn mac(host: &str, architecture: &str, version: &str) -> InventoryRecord {
+    InventoryRecord {
+        info: ComputerInfo {
+            architecture: architecture.to_string(),
+            version: version.to_string(),
+            home_users: vec!["beam".to_string(), "ph-admin".to_string()],
+            ..Default::default()
+        },
+        ..record(host)
+    }
+}
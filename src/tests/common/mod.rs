@@ -1,4 +1,5 @@
 pub mod mocks;
+pub mod fixtures;
 
 #[allow(dead_code)]
 pub fn setup() {
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use crate::server::collectors::adobe::{ AdobeApp, CometPlugin };
+    use crate::server::entitlements::{ reconcile, render_reconciliation, Entitlement, SeatStatus };
+    use crate::server::inventory::{ ExportFormat, InventoryRecord };
+    use crate::server::license::{ LicenseCheckResult, LicenseStatus, ProductLicense };
+    use crate::tests::common::fixtures::{ self, date };
+
+    fn comet_check(host: &str, status: LicenseStatus) -> LicenseCheckResult {
+        LicenseCheckResult {
+            host: host.to_string(),
+            status,
+            licenses: vec![ProductLicense {
+                product: "priint:comet".to_string(),
+                path: (status != LicenseStatus::NotFound).then(|| "/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet/w2_license.lic".to_string()),
+                status,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn record(host: &str, indesign: &str) -> InventoryRecord {
+        let mut record = fixtures::record(host);
+        record.info.custom_fields.insert("indesign".to_string(), indesign.to_string());
+        record
+    }
+
+    fn entitlements() -> Vec<Entitlement> {
+        vec![
+            Entitlement {
+                id: "priint-comet".to_string(),
+                product: "priint:comet".to_string(),
+                vendor: Some("WERK II".to_string()),
+                seats: 2,
+                contract_end: Some(date(2025, 12, 31)),
+                ..Default::default()
+            },
+            // Lapsed bundle, its seats no longer count
+            Entitlement {
+                id: "priint-comet-2".to_string(),
+                product: "Priint:Comet".to_string(),
+                seats: 5,
+                contract_end: Some(date(2024, 6, 30)),
+                ..Default::default()
+            },
+            Entitlement {
+                id: "indesign".to_string(),
+                product: "InDesign".to_string(),
+                vendor: Some("Adobe".to_string()),
+                seats: 10,
+                installed_rule: Some("custom.indesign ~ \"2024\"".to_string()),
+                ..Default::default()
+            }
+        ]
+    }
+
+    #[test]
+    fn test_reconcile_flags_over_deployment_and_unused_seats() {
+        let results = vec![
+            comet_check("vg-ph-beam.local", LicenseStatus::Valid),
+            comet_check("vg-ph-fon.local", LicenseStatus::Expired),
+            comet_check("vg-ph-noi.local", LicenseStatus::Warning),
+            comet_check("vg-ph-ton.local", LicenseStatus::NotFound)
+        ];
+        let records = vec![
+            record("vg-ph-beam.local", "19.5 (2024)"),
+            record("vg-ph-fon.local", "19.5 (2024)"),
+            record("vg-ph-ton.local", "18.0 (2023)")
+        ];
+        let rows = reconcile(&entitlements(), &results, &records, &BTreeMap::new(), &date(2025, 1, 1)).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        let indesign = &rows[0];
+        assert_eq!(indesign.product, "InDesign");
+        assert_eq!(indesign.hosts, vec!["vg-ph-beam.local", "vg-ph-fon.local"]);
+        assert_eq!(indesign.status, SeatStatus::Unused);
+        assert_eq!(indesign.unused_seats(), 8);
+
+        let comet = &rows[1];
+        assert_eq!(comet.product, "priint:comet");
+        assert_eq!(comet.seats, 2);
+        assert_eq!(comet.installed, 3);
+        assert_eq!(comet.status, SeatStatus::OverDeployed);
+        assert_eq!(comet.over_deployed(), 1);
+        assert_eq!(comet.contract_end, Some(date(2025, 12, 31)));
+        assert_eq!(comet.entitlement_ids, vec!["priint-comet", "priint-comet-2"]);
+    }
+
+    #[test]
+    fn test_reconcile_reports_products_without_entitlement() {
+        let mut check = comet_check("vg-ph-beam.local", LicenseStatus::Valid);
+        check.licenses[0].product = "Font Manager".to_string();
+        let rows = reconcile(&[], &[check], &[], &BTreeMap::new(), &date(2025, 1, 1)).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].seats, 0);
+        assert_eq!(rows[0].status, SeatStatus::OverDeployed);
+    }
+
+    #[test]
+    fn test_reconcile_counts_collector_installs() {
+        let mut record = record("vg-ph-noi.local", "");
+        record.info.adobe.comet_plugins.push(CometPlugin {
+            version: "4.3.1".to_string(),
+            indesign: "Adobe InDesign 2024".to_string(),
+            path: "/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet".to_string(),
+        });
+        record.info.adobe.apps.push(AdobeApp { name: "Adobe Photoshop 2024".to_string(), ..Default::default() });
+        let results = vec![comet_check("vg-ph-beam.local", LicenseStatus::Valid)];
+        let rows = reconcile(&entitlements()[..1], &results, &[record], &BTreeMap::new(), &date(2025, 1, 1)).unwrap();
+
+        // Photoshop has no entitlement, so it isn't reported
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].hosts, vec!["vg-ph-beam.local", "vg-ph-noi.local"]);
+        assert_eq!(rows[0].status, SeatStatus::Balanced);
+    }
+
+    #[test]
+    fn test_render_reconciliation_csv() {
+        let results = vec![comet_check("vg-ph-beam.local", LicenseStatus::Valid)];
+        let rows = reconcile(&entitlements()[..1], &results, &[], &BTreeMap::new(), &date(2025, 1, 1)).unwrap();
+        let file = render_reconciliation(&rows, ExportFormat::Csv).unwrap();
+        let csv = String::from_utf8(file.data).unwrap();
+
+        assert!(file.filename.starts_with("seat-reconciliation-"));
+        assert!(file.filename.ends_with(".csv"));
+        assert_eq!(
+            csv,
+            "product,vendors,seats,installed,unused_seats,over_deployed,status,contract_end,hosts\n\
+             priint:comet,WERK II,2,1,1,0,Unused,2025-12-31,vg-ph-beam.local\n"
+        );
+        assert!(!render_reconciliation(&rows, ExportFormat::Xlsx).unwrap().data.is_empty());
+    }
+}
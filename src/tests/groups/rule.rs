@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{ BTreeMap, BTreeSet };
+    use crate::server::groups::{ group_members, Group, GroupKind, Operator, Rule };
+    use crate::server::groups::rule::compare;
+    use crate::server::groups::store::{ normalize_tags, validate_rule };
+    use crate::server::inventory::InventoryRecord;
+    use crate::tests::common::fixtures::mac;
+
+    fn records() -> Vec<InventoryRecord> {
+        vec![
+            mac("vg-ph-beam.local", "x86_64", "macOS 13.6.1 (22G313)"),
+            mac("vg-ph-fon.local", "arm64", "macOS 14.4.1 (23E224)"),
+            mac("vg-ph-kai.local", "x86_64", "macOS 14.0 (23A344)"),
+            mac("render-01.local", "x86_64", "")
+        ]
+    }
+
+    fn members(rule: &str, tags: &BTreeMap<String, BTreeSet<String>>) -> Vec<String> {
+        let group = Group {
+            kind: GroupKind::Dynamic(rule.to_string()),
+            ..Default::default()
+        };
+        group_members(&group, &records(), tags).unwrap()
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let rule = Rule::parse("a == 1 OR b == 2 AND NOT c ~ x").unwrap();
+        let compare = |field: &str, op, value: &str| Rule::Compare {
+            field: field.to_string(),
+            op,
+            value: value.to_string(),
+        };
+        assert_eq!(
+            rule,
+            Rule::Or(
+                Box::new(compare("a", Operator::Eq, "1")),
+                Box::new(
+                    Rule::And(
+                        Box::new(compare("b", Operator::Eq, "2")),
+                        Box::new(Rule::Not(Box::new(compare("c", Operator::Contains, "x"))))
+                    )
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Rule::parse("").is_err());
+        assert!(Rule::parse("version <").is_err());
+        assert!(Rule::parse("(version < 14").is_err());
+        assert!(Rule::parse("version << 14").is_err());
+        assert!(Rule::parse("title == \"unterminated").is_err());
+        assert!(Rule::parse("version < 14 version").is_err());
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert!(compare("macOS 13.6.1 (22G313)", Operator::Lt, "14"));
+        assert!(!compare("macOS 14.0 (23A344)", Operator::Lt, "14"));
+        assert!(compare("macOS 14.0 (23A344)", Operator::GtEq, "14"));
+        assert!(compare("10.15.7", Operator::Lt, "10.16"));
+        assert!(!compare("", Operator::Lt, "14"));
+        assert!(compare("X86_64", Operator::Eq, "x86_64"));
+        assert!(compare("Apple M1 Pro", Operator::Contains, "m1"));
+    }
+
+    #[test]
+    fn test_dynamic_group_members() {
+        let tags = BTreeMap::from([
+            ("vg-ph-fon.local".to_string(), BTreeSet::from(["design".to_string()])),
+            ("render-01.local".to_string(), BTreeSet::from(["render".to_string(), "linux".to_string()])),
+        ]);
+
+        assert_eq!(members("architecture == x86_64 AND version < 14", &tags), vec!["vg-ph-beam.local"]);
+        assert_eq!(members("tag == render OR tag == design", &tags), vec!["vg-ph-fon.local", "render-01.local"]);
+        assert_eq!(members("NOT (architecture == x86_64)", &tags), vec!["vg-ph-fon.local"]);
+        assert_eq!(members("home_users == beam AND host ~ 'kai'", &tags), vec!["vg-ph-kai.local"]);
+        assert_eq!(members("tag != render AND version >= 14", &tags), vec!["vg-ph-fon.local", "vg-ph-kai.local"]);
+    }
+
+    #[test]
+    fn test_static_group_members() {
+        let group = Group {
+            kind: GroupKind::Static(vec!["loaner-01.local".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(group_members(&group, &records(), &BTreeMap::new()).unwrap(), vec!["loaner-01.local"]);
+    }
+
+    #[test]
+    fn test_validate_rule_fields() {
+        assert!(validate_rule("tag == design AND custom.filevault == On").is_ok());
+        assert_eq!(validate_rule("colour == red").unwrap_err(), "Unknown field: colour");
+    }
+
+    #[test]
+    fn test_normalize_tags() {
+        let tags = normalize_tags(&[" Design".to_string(), "design".to_string(), "".to_string(), "Print Room".to_string()]);
+        assert_eq!(tags.into_iter().collect::<Vec<_>>(), vec!["design", "print room"]);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::inventory::AssetMetadata;
+    use crate::server::license::LicenseStatus;
+    use crate::server::inventory::assets::{ coverage_warnings, parse_asset_date, parse_assets_csv };
+    use crate::tests::common::fixtures::date;
+
+    #[test]
+    fn test_parse_assets_csv_with_spreadsheet_headers() {
+        let csv = "Serial Number,Purchase Date,Supplier,Invoice No,Price,AppleCare,Warranty\n\
+                   C02XL0GHJGH5,15/03/2023,Copper Wired,INV-2023-0412,\"฿45,900.00\",2026-03-15,2024-03-15\n\
+                   FVFGK1Q2Q6L4,2024-01-08,iStudio,,,,\n";
+        let (assets, errors) = parse_assets_csv(csv).unwrap();
+
+        assert!(errors.is_empty());
+        assert_eq!(assets.len(), 2);
+        assert_eq!(assets[0], AssetMetadata {
+            serial: "C02XL0GHJGH5".to_string(),
+            purchase_date: Some(date(2023, 3, 15)),
+            supplier: Some("Copper Wired".to_string()),
+            invoice_number: Some("INV-2023-0412".to_string()),
+            cost: Some(45900.0),
+            warranty_expiry: Some(date(2024, 3, 15)),
+            applecare_expiry: Some(date(2026, 3, 15)),
+            notes: None,
+        });
+        assert_eq!(assets[1].invoice_number, None);
+        assert_eq!(assets[1].cost, None);
+    }
+
+    #[test]
+    fn test_parse_assets_csv_reports_bad_rows() {
+        let csv = "serial,purchase_date,cost\n\
+                   ,2023-01-01,100\n\
+                   C02XL0GHJGH5,31/02/2023,100\n\
+                   FVFGK1Q2Q6L4,2023-01-01,n/a\n\
+                   H4TFK2ABQ05D,2023-01-01,100\n";
+        let (assets, errors) = parse_assets_csv(csv).unwrap();
+
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].serial, "H4TFK2ABQ05D");
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 3, 4]);
+        assert_eq!(errors[0].message, "Missing serial number");
+    }
+
+    #[test]
+    fn test_parse_assets_csv_requires_serial_column() {
+        assert!(parse_assets_csv("supplier,cost\niStudio,100\n").is_err());
+    }
+
+    #[test]
+    fn test_merge_keeps_existing_values() {
+        let mut existing = AssetMetadata {
+            serial: "C02XL0GHJGH5".to_string(),
+            supplier: Some("Copper Wired".to_string()),
+            cost: Some(45900.0),
+            ..Default::default()
+        };
+        existing.merge(AssetMetadata {
+            serial: "C02XL0GHJGH5".to_string(),
+            applecare_expiry: Some(date(2026, 3, 15)),
+            cost: Some(42000.0),
+            ..Default::default()
+        });
+
+        assert_eq!(existing.supplier.as_deref(), Some("Copper Wired"));
+        assert_eq!(existing.cost, Some(42000.0));
+        assert_eq!(existing.applecare_expiry, Some(date(2026, 3, 15)));
+    }
+
+    #[test]
+    fn test_parse_asset_date_formats() {
+        assert_eq!(parse_asset_date("2024-01-08"), Some(date(2024, 1, 8)));
+        assert_eq!(parse_asset_date("08/01/2024"), Some(date(2024, 1, 8)));
+        assert_eq!(parse_asset_date("08.01.2024"), Some(date(2024, 1, 8)));
+        assert_eq!(parse_asset_date("January 8"), None);
+    }
+
+    #[test]
+    fn test_coverage_warnings_use_license_thresholds() {
+        let today = date(2024, 6, 1);
+        let asset = AssetMetadata {
+            serial: "C02XL0GHJGH5".to_string(),
+            warranty_expiry: Some(date(2024, 5, 1)),
+            applecare_expiry: Some(date(2024, 8, 1)),
+            ..Default::default()
+        };
+        let warnings = coverage_warnings(&asset, &today);
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].coverage, "Warranty");
+        assert_eq!(warnings[0].status, LicenseStatus::Expired);
+        assert_eq!(warnings[0].days_remaining, -31);
+        assert_eq!(warnings[1].coverage, "AppleCare");
+        assert_eq!(warnings[1].status, LicenseStatus::Notice);
+
+        let covered = AssetMetadata {
+            applecare_expiry: Some(date(2026, 3, 15)),
+            ..asset
+        };
+        let warnings = coverage_warnings(&covered, &today);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].coverage, "Warranty");
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use crate::cli::parse_export_args;
+    use crate::server::collectors::{ OsFamily, SoftwareUpdate };
+    use crate::server::inventory::export::build_export_table;
+    use crate::server::inventory::{
+        render_export,
+        ExportFilter,
+        ExportFormat,
+        ExportRequest,
+        InventoryRecord,
+    };
+    use crate::server::resolve_computer::ComputerInfo;
+
+    fn records() -> Vec<InventoryRecord> {
+        vec![
+            InventoryRecord {
+                host: "vg-ph-beam.local".to_string(),
+                info: ComputerInfo {
+                    title: "MacBook Pro".to_string(),
+                    serial: "C02XL0GHJGH5".to_string(),
+                    os_family: OsFamily::MacOs,
+                    home_users: vec!["beam".to_string(), "ph-admin".to_string()],
+                    software_updates: vec![SoftwareUpdate {
+                        label: "Safari-17.1".to_string(),
+                        ..Default::default()
+                    }],
+                    custom_fields: BTreeMap::from([("filevault".to_string(), "On".to_string())]),
+                    ..Default::default()
+                },
+                last_resolved: None,
+                ..Default::default()
+            },
+            InventoryRecord {
+                host: "render-01.local".to_string(),
+                info: ComputerInfo {
+                    title: "Render node".to_string(),
+                    serial: "R01".to_string(),
+                    os_family: OsFamily::Linux,
+                    ..Default::default()
+                },
+                last_resolved: None,
+                ..Default::default()
+            }
+        ]
+    }
+
+    fn request(columns: &[&str], filter: ExportFilter) -> ExportRequest {
+        ExportRequest {
+            format: ExportFormat::Csv,
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            filter,
+            group: None,
+        }
+    }
+
+    #[test]
+    fn test_selected_columns_and_flattened_lists() {
+        let request = request(&["host", "home_users", "custom.filevault"], ExportFilter::default());
+        let (columns, rows) = build_export_table(&request, &records()).unwrap();
+
+        let keys: Vec<&str> = columns.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["host", "home_users", "custom.filevault"]);
+        assert_eq!(rows[0], vec!["vg-ph-beam.local", "beam; ph-admin", "On"]);
+        assert_eq!(rows[1], vec!["render-01.local", "", ""]);
+    }
+
+    #[test]
+    fn test_all_columns_include_custom_fields() {
+        let (columns, _) = build_export_table(&request(&[], ExportFilter::default()), &records()).unwrap();
+        assert_eq!(columns.first().unwrap().key, "host");
+        assert_eq!(columns.last().unwrap().key, "custom.filevault");
+    }
+
+    #[test]
+    fn test_filters() {
+        let by_os = ExportFilter { os_family: Some(OsFamily::Linux), ..Default::default() };
+        let (_, rows) = build_export_table(&request(&["host"], by_os), &records()).unwrap();
+        assert_eq!(rows, vec![vec!["render-01.local".to_string()]]);
+
+        let by_text = ExportFilter { text: Some("c02xl".to_string()), ..Default::default() };
+        let (_, rows) = build_export_table(&request(&["host"], by_text), &records()).unwrap();
+        assert_eq!(rows, vec![vec!["vg-ph-beam.local".to_string()]]);
+
+        let updates = ExportFilter { pending_updates_only: true, ..Default::default() };
+        let (_, rows) = build_export_table(&request(&["pending_updates"], updates), &records()).unwrap();
+        assert_eq!(rows, vec![vec!["Safari-17.1".to_string()]]);
+    }
+
+    #[test]
+    fn test_unknown_column_is_rejected() {
+        let result = build_export_table(&request(&["hostname"], ExportFilter::default()), &records());
+        assert_eq!(result.unwrap_err(), "Unknown export column: hostname");
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let mut request = request(&["host", "home_users"], ExportFilter::default());
+        let csv = render_export(&request, &records()).unwrap();
+        assert!(csv.filename.ends_with(".csv"));
+        assert_eq!(
+            String::from_utf8(csv.data).unwrap(),
+            "host,home_users\nvg-ph-beam.local,beam; ph-admin\nrender-01.local,\n"
+        );
+
+        request.format = ExportFormat::Json;
+        let json = render_export(&request, &records()).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json.data).unwrap();
+        assert_eq!(value[0]["home_users"], "beam; ph-admin");
+        assert_eq!(value[1]["host"], "render-01.local");
+
+        request.format = ExportFormat::Xlsx;
+        let xlsx = render_export(&request, &records()).unwrap();
+        assert!(xlsx.data.starts_with(b"PK"));
+    }
+
+    #[test]
+    fn test_cli_export_args() {
+        let args: Vec<String> = ["--columns", "host, serial", "--os", "linux", "-o", "fleet.xlsx"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        let (request, output) = parse_export_args(&args).unwrap();
+
+        assert_eq!(request.format, ExportFormat::Xlsx);
+        assert_eq!(request.columns, vec!["host", "serial"]);
+        assert_eq!(request.filter.os_family, Some(OsFamily::Linux));
+        assert_eq!(output.unwrap().to_str(), Some("fleet.xlsx"));
+
+        let explicit: Vec<String> = ["--format", "json", "-o", "fleet.xlsx"].iter().map(|a| a.to_string()).collect();
+        assert_eq!(parse_export_args(&explicit).unwrap().0.format, ExportFormat::Json);
+        assert!(parse_export_args(&["--bogus".to_string()]).is_err());
+    }
+}
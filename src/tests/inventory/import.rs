@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::OsFamily;
+    use crate::server::inventory::import::parse_inventory_import;
+    use std::collections::HashMap;
+    use crate::server::inventory::store::{ merge_info, merge_records };
+    use crate::server::inventory::{
+        render_export,
+        ExportFormat,
+        ExportRequest,
+        InventoryRecord,
+        RecordSource,
+    };
+    use crate::server::resolve_computer::ComputerInfo;
+
+    #[test]
+    fn test_csv_import_by_key_or_label() {
+        let csv = "Serial,title,Home Users,os_family,admins,custom.filevault\n\
+                   C02XL0GHJGH5,Loaner MacBook,beam; ph-admin,macOS,ph-admin,On\n";
+        let (records, errors) = parse_inventory_import(csv, ExportFormat::Csv).unwrap();
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        let record = &records[0];
+        assert_eq!(record.source, RecordSource::Manual);
+        assert_eq!(record.info.serial, "C02XL0GHJGH5");
+        assert_eq!(record.info.title, "Loaner MacBook");
+        assert_eq!(record.info.home_users, vec!["beam", "ph-admin"]);
+        assert_eq!(record.info.os_family, OsFamily::MacOs);
+        assert!(record.info.user_accounts.is_empty());
+        assert_eq!(record.info.custom_fields.get("filevault").map(String::as_str), Some("On"));
+    }
+
+    #[test]
+    fn test_csv_import_reports_row_errors() {
+        let csv = "serial,lan_ip,os_family,colour\n\
+                   ,192.168.10.20,macOS,red\n\
+                   C02XL0GHJGH5,192.168.10.300,Windows,red\n\
+                   FVFGK1Q2Q6L4,192.168.10.21,linux,blue\n\
+                   FVFGK1Q2Q6L4,192.168.10.22,linux,blue\n";
+        let (records, errors) = parse_inventory_import(csv, ExportFormat::Csv).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].info.lan_ip, "192.168.10.21");
+        let reported: Vec<(usize, &str)> = errors
+            .iter()
+            .map(|e| (e.line, e.message.as_str()))
+            .collect();
+        assert_eq!(reported, vec![
+            (1, "Unknown column ignored: colour"),
+            (2, "Missing serial number"),
+            (3, "Invalid IP address: 192.168.10.300"),
+            (3, "Unknown OS family: Windows"),
+            (5, "Duplicate serial number: FVFGK1Q2Q6L4"),
+        ]);
+    }
+
+    #[test]
+    fn test_json_import() {
+        let json = r#"[
+            { "serial": "C02XL0GHJGH5", "home_users": ["beam", "ph-admin"], "memory": 16 },
+            { "title": "No serial" }
+        ]"#;
+        let (records, errors) = parse_inventory_import(json, ExportFormat::Json).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].info.home_users, vec!["beam", "ph-admin"]);
+        assert_eq!(records[0].info.memory, "16");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 2);
+        assert!(parse_inventory_import("{}", ExportFormat::Json).is_err());
+        assert!(parse_inventory_import("", ExportFormat::Xlsx).is_err());
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let record = InventoryRecord {
+            host: "vg-ph-beam.local".to_string(),
+            info: ComputerInfo {
+                title: "MacBook Pro".to_string(),
+                serial: "C02XL0GHJGH5".to_string(),
+                os_family: OsFamily::MacOs,
+                lan_ip: "192.168.10.20".to_string(),
+                home_users: vec!["beam".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for format in [ExportFormat::Csv, ExportFormat::Json] {
+            let request = ExportRequest { format, ..Default::default() };
+            let file = render_export(&request, std::slice::from_ref(&record)).unwrap();
+            let data = String::from_utf8(file.data).unwrap();
+            let (records, errors) = parse_inventory_import(&data, format).unwrap();
+
+            assert!(errors.is_empty(), "{:?}", errors);
+            assert_eq!(records[0].host, record.host);
+            assert_eq!(records[0].info.title, record.info.title);
+            assert_eq!(records[0].info.lan_ip, record.info.lan_ip);
+            assert_eq!(records[0].info.home_users, record.info.home_users);
+            assert_eq!(records[0].info.os_family, OsFamily::MacOs);
+        }
+    }
+
+    #[test]
+    fn test_merge_only_fills_blanks_on_resolved_records() {
+        let imported = ComputerInfo {
+            title: "Loaner".to_string(),
+            user: "beam".to_string(),
+            os_family: OsFamily::Linux,
+            ..Default::default()
+        };
+        let mut resolved = ComputerInfo {
+            title: "MacBook Pro".to_string(),
+            os_family: OsFamily::MacOs,
+            ..Default::default()
+        };
+        merge_info(&mut resolved, imported.clone(), false);
+        assert_eq!(resolved.title, "MacBook Pro");
+        assert_eq!(resolved.user, "beam");
+        assert_eq!(resolved.os_family, OsFamily::MacOs);
+
+        let mut manual = ComputerInfo { title: "Old".to_string(), ..Default::default() };
+        merge_info(&mut manual, imported, true);
+        assert_eq!(manual.title, "Loaner");
+        assert_eq!(manual.os_family, OsFamily::Linux);
+    }
+
+    #[test]
+    fn test_merge_never_replaces_another_machine_on_the_same_host() {
+        let record = |host: &str, serial: &str, title: &str, source: RecordSource| InventoryRecord {
+            host: host.to_string(),
+            info: ComputerInfo { serial: serial.to_string(), title: title.to_string(), ..Default::default() },
+            last_resolved: None,
+            source,
+        };
+        let mut inventory: HashMap<String, InventoryRecord> = [
+            ("vg-ph-beam.local", "C02XL0GHJGH5", "MacBook Pro"),
+            ("vg-ph-toom.local", "", "iMac"),
+        ]
+            .into_iter()
+            .map(|(host, serial, title)| (host.to_string(), record(host, serial, title, RecordSource::Ssh)))
+            .collect();
+
+        let (created, updated, errors) = merge_records(&mut inventory, vec![
+            (2, record("vg-ph-beam.local", "C02OTHER0001", "Loaner", RecordSource::Manual)),
+            (3, record("vg-ph-toom.local", "C02TOOM00001", "Loaner", RecordSource::Manual)),
+            (4, record("", "C02SPARE0001", "Spare", RecordSource::Manual)),
+        ]);
+        assert_eq!((created, updated), (1, 1));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 2);
+        assert_eq!(errors[0].message, "Host vg-ph-beam.local already belongs to serial number C02XL0GHJGH5");
+
+        // The collected record keeps its data and source
+        let beam = &inventory["vg-ph-beam.local"];
+        assert_eq!((beam.info.title.as_str(), beam.source), ("MacBook Pro", RecordSource::Ssh));
+        // A collected record without a serial only has its blanks filled
+        let toom = &inventory["vg-ph-toom.local"];
+        assert_eq!((toom.info.serial.as_str(), toom.info.title.as_str()), ("C02TOOM00001", "iMac"));
+        assert_eq!(toom.source, RecordSource::Ssh);
+        assert_eq!(inventory["C02SPARE0001"].source, RecordSource::Manual);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod report;
+pub mod scheduler;
+pub mod assets;
+pub mod export;
+pub mod import;
+pub mod search;
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::collectors::SoftwareUpdate;
+    use crate::server::inventory::{ build_pending_updates_report, InventoryRecord };
+    use crate::server::resolve_computer::ComputerInfo;
+    use crate::tests::common::fixtures;
+
+    fn record(host: &str, labels: &[&str]) -> InventoryRecord {
+        InventoryRecord {
+            info: ComputerInfo {
+                software_updates: labels
+                    .iter()
+                    .map(|label| SoftwareUpdate {
+                        label: label.to_string(),
+                        title: label.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+            ..fixtures::record(host)
+        }
+    }
+
+    #[test]
+    fn test_pending_updates_grouped_by_label() {
+        let records = vec![
+            record("vg-ph-fon.local", &["Safari-17.1", "macOS Sonoma 14.4.1-23E224"]),
+            record("vg-ph-beam.local", &["macOS Sonoma 14.4.1-23E224"]),
+            record("vg-ph-kai.local", &[])
+        ];
+
+        let report = build_pending_updates_report(&records);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].label, "Safari-17.1");
+        assert_eq!(report[0].hosts, vec!["vg-ph-fon.local"]);
+        assert_eq!(report[1].hosts, vec!["vg-ph-beam.local", "vg-ph-fon.local"]);
+    }
+}
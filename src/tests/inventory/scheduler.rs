@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{ AtomicUsize, Ordering };
+    use std::thread;
+    use std::time::Duration;
+    use crate::server::inventory::RefreshProgress;
+    use crate::server::inventory::scheduler::{ is_quiet_hour, run_batch };
+
+    #[test]
+    fn test_quiet_hours_within_day() {
+        let quiet = Some((12, 14));
+        assert!(!is_quiet_hour(11, quiet));
+        assert!(is_quiet_hour(12, quiet));
+        assert!(is_quiet_hour(13, quiet));
+        assert!(!is_quiet_hour(14, quiet));
+    }
+
+    #[test]
+    fn test_quiet_hours_wrap_midnight() {
+        let quiet = Some((22, 6));
+        assert!(is_quiet_hour(23, quiet));
+        assert!(is_quiet_hour(0, quiet));
+        assert!(is_quiet_hour(5, quiet));
+        assert!(!is_quiet_hour(6, quiet));
+        assert!(!is_quiet_hour(21, quiet));
+        assert!(!is_quiet_hour(3, None));
+    }
+
+    #[test]
+    fn test_run_batch_respects_max_concurrency() {
+        let hosts: Vec<String> = (0..10).map(|i| format!("host-{}.local", i)).collect();
+        let progress = Mutex::new(RefreshProgress {
+            in_progress: true,
+            total: hosts.len(),
+            ..Default::default()
+        });
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        run_batch(hosts, 3, &progress, |host| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            running.fetch_sub(1, Ordering::SeqCst);
+            if host == "host-4.local" {
+                Err("Connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        let progress = progress.lock().unwrap();
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert!(peak.load(Ordering::SeqCst) > 1);
+        assert_eq!(progress.completed, 10);
+        assert_eq!(progress.failed, vec!["host-4.local".to_string()]);
+        assert!(progress.current_hosts.is_empty());
+        assert!(!progress.in_progress);
+        assert!(progress.finished_at.is_some());
+    }
+
+    #[test]
+    fn test_run_batch_with_no_hosts_finishes() {
+        let progress = Mutex::new(RefreshProgress { in_progress: true, ..Default::default() });
+        run_batch(Vec::new(), 4, &progress, |_| Ok(()));
+        assert!(!progress.lock().unwrap().in_progress);
+    }
+}
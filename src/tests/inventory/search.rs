@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{ BTreeMap, BTreeSet };
+    use crate::server::groups::{ group_members, Group, GroupKind, Rule };
+    use crate::server::inventory::{ compose_query, InventoryRecord, SearchIndex };
+    use crate::server::resolve_computer::ComputerInfo;
+    use crate::tests::common::fixtures::mac;
+
+    fn record(host: &str, user: &str, architecture: &str, version: &str) -> InventoryRecord {
+        let mac = mac(host, architecture, version);
+        InventoryRecord {
+            info: ComputerInfo {
+                title: format!("{}'s Mac", user),
+                user: user.to_string(),
+                home_users: vec![user.to_lowercase(), "ph-admin".to_string()],
+                custom_fields: BTreeMap::from([
+                    ("filevault".to_string(), if architecture == "arm64" { "On" } else { "Off" }.to_string()),
+                ]),
+                ..mac.info
+            },
+            ..mac
+        }
+    }
+
+    fn records() -> Vec<InventoryRecord> {
+        vec![
+            record("vg-ph-beam.local", "Beam", "x86_64", "macOS 13.6.1 (22G313)"),
+            record("vg-ph-fon.local", "Fon", "arm64", "macOS 14.4.1 (23E224)"),
+            record("vg-ph-kai.local", "Kai", "x86_64", "macOS 14.0 (23A344)"),
+            record("render-01.local", "", "x86_64", "")
+        ]
+    }
+
+    fn tags() -> BTreeMap<String, BTreeSet<String>> {
+        BTreeMap::from([
+            ("vg-ph-fon.local".to_string(), BTreeSet::from(["design".to_string()])),
+            ("render-01.local".to_string(), BTreeSet::from(["render".to_string()])),
+        ])
+    }
+
+    fn search(query: &str) -> Vec<String> {
+        let index = SearchIndex::build(&records(), &tags());
+        index.search(&Rule::parse(query).unwrap())
+    }
+
+    #[test]
+    fn test_search_queries() {
+        assert_eq!(search("architecture == X86_64 AND version < 14"), vec!["vg-ph-beam.local"]);
+        assert_eq!(search("tag == design OR tag == render"), vec!["vg-ph-fon.local", "render-01.local"]);
+        assert_eq!(search("home_users == kai"), vec!["vg-ph-kai.local"]);
+        assert_eq!(search("custom.filevault == on"), vec!["vg-ph-fon.local"]);
+        assert_eq!(search("user != beam AND NOT tag == render"), vec!["vg-ph-fon.local", "vg-ph-kai.local"]);
+        assert!(search("serial == C02ABC").is_empty());
+    }
+
+    #[test]
+    fn test_free_text_terms() {
+        assert_eq!(Rule::parse("\"kai's\"").unwrap(), Rule::Text("kai's".to_string()));
+        assert_eq!(search("fon"), vec!["vg-ph-fon.local"]);
+        assert_eq!(search("render"), vec!["render-01.local"]);
+        assert_eq!(search("\"Beam's Mac\" OR design"), vec!["vg-ph-beam.local", "vg-ph-fon.local"]);
+        assert_eq!(search("ph-admin AND NOT version >= 14"), vec!["vg-ph-beam.local", "render-01.local"]);
+    }
+
+    #[test]
+    fn test_index_agrees_with_rule_matching() {
+        let queries = [
+            "version >= 14",
+            "version < 14.4 OR tag == design",
+            "NOT (architecture ~ 86)",
+            "home_users != ph-admin",
+            "title ~ mac AND tag != design",
+            "kai OR 23E224",
+        ];
+        for query in queries {
+            let group = Group { kind: GroupKind::Dynamic(query.to_string()), ..Default::default() };
+            let expected = group_members(&group, &records(), &tags()).unwrap();
+            assert_eq!(search(query), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_compose_query() {
+        let mut filters = BTreeMap::new();
+        assert_eq!(compose_query("  beam ", &filters), "beam");
+
+        filters.insert("serial".to_string(), "C02".to_string());
+        filters.insert("title".to_string(), " ".to_string());
+        assert_eq!(compose_query("a OR b", &filters), "(a OR b) AND serial ~ \"C02\"");
+
+        filters.insert("user".to_string(), "say \"hi\"".to_string());
+        assert_eq!(compose_query("", &filters), "serial ~ \"C02\" AND user ~ 'say \"hi\"'");
+
+        let query = compose_query("version < 14", &filters);
+        assert!(Rule::parse(&query).is_ok());
+    }
+}
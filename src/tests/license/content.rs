@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::server::license::rules::{
+        default_license_rules,
+        is_perpetual,
+        parse_date,
+        read_license,
+        DateExtraction,
+        ExpiryThresholds,
+        LicenseRule,
+    };
+    use crate::server::license::{ LicenseStatus, ProductLicense };
+    use crate::tests::common::fixtures::{ date, today };
+
+    fn rule(extract: DateExtraction) -> LicenseRule {
+        LicenseRule {
+            product: "Example".to_string(),
+            paths: vec!["/Library/Application Support/Example/*.lic".to_string()],
+            extract,
+            date_formats: vec!["%Y/%m/%d".to_string()],
+            thresholds: ExpiryThresholds::default(),
+        }
+    }
+
+    fn read(rule: &LicenseRule, content: &str) -> Vec<ProductLicense> {
+        let license = ProductLicense { product: rule.product.clone(), ..Default::default() };
+        read_license(rule, license, content, &today())
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        let formats = vec!["%Y/%m/%d".to_string()];
+        let cases = [
+            ("2025/06/30", Some(date(2025, 6, 30))),
+            ("2025-06-30", Some(date(2025, 6, 30))),
+            ("2025.06.30", Some(date(2025, 6, 30))),
+            ("30.06.2025", Some(date(2025, 6, 30))),
+            ("20250630", Some(date(2025, 6, 30))),
+            ("30-Jun-2025", Some(date(2025, 6, 30))),
+            ("30 June 2025", Some(date(2025, 6, 30))),
+            ("June 30, 2025", Some(date(2025, 6, 30))),
+            ("Jun 30 2025", Some(date(2025, 6, 30))),
+            ("2025-06-30T23:59:59", Some(date(2025, 6, 30))),
+            ("2025-06-30T23:59:59Z", Some(date(2025, 6, 30))),
+            ("2025-06-30T23:59:59+02:00", Some(date(2025, 6, 30))),
+            ("\"2025-06-30\"", Some(date(2025, 6, 30))),
+            ("2025-06-30 (renewal pending)", Some(date(2025, 6, 30))),
+            ("2025-06-30; seats=5", Some(date(2025, 6, 30))),
+            ("June 30, 2025.", Some(date(2025, 6, 30))),
+            // Day/month order can't be told apart without a rule format
+            ("06/30/2025", None),
+            ("2025-02-30", None),
+            ("soon", None),
+            ("", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_date(text, &formats), expected, "parsing {:?}", text);
+        }
+
+        let us = vec!["%m/%d/%Y".to_string()];
+        assert_eq!(parse_date("06/30/2025", &us), Some(date(2025, 6, 30)));
+    }
+
+    #[test]
+    fn test_perpetual_values() {
+        let cases = [
+            ("perpetual", true),
+            ("Never", true),
+            ("UNLIMITED", true),
+            ("\"permanent\"", true),
+            ("never (site license)", true),
+            ("2025-06-30", false),
+            ("nevermore", false),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(is_perpetual(text), expected, "checking {:?}", text);
+        }
+    }
+
+    // Line, status and expiry of each license read from a sample
+    type Expected = Vec<(Option<usize>, LicenseStatus, Option<NaiveDate>)>;
+
+    #[test]
+    fn test_real_world_samples() {
+        let key_value = |key: &str| DateExtraction::KeyValue(key.to_string());
+        let cases: Vec<(&str, DateExtraction, Expected)> = vec![
+            (
+                // priint:comet w2_license.lic
+                "// priint:comet license\n// Licensee: Example Print GmbH\n// Expires: 2025/03/01\nKEY=0f3a9c\n",
+                key_value("Expires"),
+                vec![(Some(3), LicenseStatus::Warning, Some(date(2025, 3, 1)))],
+            ),
+            (
+                // Comet license written with ISO dates
+                "// Licensee: Example Print GmbH\n// Expires: 2025-06-30\n",
+                key_value("Expires"),
+                vec![(Some(2), LicenseStatus::Valid, Some(date(2025, 6, 30)))],
+            ),
+            (
+                // Several licenses concatenated into one file
+                "# Seat 1\nExpires = 2025-01-15\n# Seat 2\nExpires = 2025-04-01\n# Seat 3\nExpires = never\n",
+                key_value("expires"),
+                vec![
+                    (Some(2), LicenseStatus::Expired, Some(date(2025, 1, 15))),
+                    (Some(4), LicenseStatus::Notice, Some(date(2025, 4, 1))),
+                    (Some(6), LicenseStatus::Valid, None),
+                ],
+            ),
+            (
+                // FlexLM-style feature lines
+                "SERVER lic01 0050569a1b2c 27000\nFEATURE indesign adobe 1.0 30-jun-2025 5 SIGN=1234\nFEATURE comet priint 4.3 permanent uncounted SIGN=5678\n",
+                DateExtraction::Regex(r"^FEATURE \S+ \S+ \S+ (\S+)".to_string()),
+                vec![
+                    (Some(2), LicenseStatus::Valid, Some(date(2025, 6, 30))),
+                    (Some(3), LicenseStatus::Valid, None),
+                ],
+            ),
+            (
+                // Property list converted by plutil
+                "<?xml version=\"1.0\"?>\n<plist version=\"1.0\">\n<dict>\n\t<key>ExpirationDate</key>\n\t<date>2025-02-20T00:00:00Z</date>\n</dict>\n</plist>\n",
+                DateExtraction::PlistKey("ExpirationDate".to_string()),
+                vec![(Some(5), LicenseStatus::Warning, Some(date(2025, 2, 20)))],
+            ),
+            (
+                "{\"license\": {\"holder\": \"Example\", \"expires\": \"December 31, 2024\"}}\n",
+                DateExtraction::JsonPath("$.license.expires".to_string()),
+                vec![(None, LicenseStatus::Expired, Some(date(2024, 12, 31)))],
+            ),
+            (
+                "Valid until: 31.12.2025\n",
+                key_value("Valid until"),
+                vec![(Some(1), LicenseStatus::Valid, Some(date(2025, 12, 31)))],
+            ),
+            (
+                "// Expires: whenever\n",
+                key_value("Expires"),
+                vec![(Some(1), LicenseStatus::ParseError, None)],
+            ),
+            (
+                "KEY=0f3a9c\n",
+                key_value("Expires"),
+                vec![(None, LicenseStatus::ParseError, None)],
+            ),
+        ];
+
+        for (sample, extraction, expected) in cases {
+            let licenses = read(&rule(extraction), sample);
+            let actual: Vec<_> = licenses.iter().map(|l| (l.line, l.status, l.expiry)).collect();
+            assert_eq!(actual, expected, "reading {:?}", sample);
+        }
+    }
+
+    #[test]
+    fn test_matched_text_and_perpetual_message() {
+        let licenses = read(&default_license_rules()[0], "// Expires: 2025-06-30 (renewed)\n// Expires: Never\n");
+        assert_eq!(licenses[0].matched.as_deref(), Some("2025-06-30 (renewed)"));
+        assert_eq!(licenses[0].days_remaining, Some(149));
+        assert_eq!(licenses[1].matched.as_deref(), Some("Never"));
+        assert_eq!(licenses[1].days_remaining, None);
+        assert_eq!(licenses[1].message.as_deref(), Some("Perpetual license"));
+    }
+}
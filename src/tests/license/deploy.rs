@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use crate::server::license::deploy::{
+        deploy_command,
+        deploy_targets,
+        parse_deploy_output,
+        preview_license,
+        rollback_command,
+        validate_filename,
+    };
+    use crate::server::license::rules::{ default_license_rules, DateExtraction, ExpiryThresholds, LicenseRule };
+    use crate::server::license::{ DeployedFile, LicenseStatus };
+    use crate::tests::common::fixtures::{ date, today };
+
+    const RENEWED_LICENSE: &str = "// Licensee: Example Print\n// Expires: 2025/03/01\nKEY=abcdef\n";
+
+    #[test]
+    fn test_preview_license() {
+        let rule = &default_license_rules()[0];
+        let license = preview_license(rule, RENEWED_LICENSE, &today());
+        assert_eq!(license.status, LicenseStatus::Warning);
+        assert_eq!(license.expiry, Some(date(2025, 3, 1)));
+        assert_eq!(license.days_remaining, Some(28));
+        assert_eq!(license.matched.as_deref(), Some("2025/03/01"));
+        assert_eq!(license.path, None);
+
+        let license = preview_license(rule, "KEY=abcdef\n", &today());
+        assert_eq!(license.status, LicenseStatus::ParseError);
+    }
+
+    #[test]
+    fn test_deploy_targets() {
+        let rule = &default_license_rules()[0];
+        assert_eq!(
+            deploy_targets(rule, "renewed.lic"),
+            vec![(
+                "/Applications/Adobe InDesign */Plug-Ins/priint.comet*".to_string(),
+                "w2_license.lic".to_string(),
+            )]
+        );
+
+        let rule = LicenseRule {
+            product: "Example".to_string(),
+            paths: vec!["/Library/Application Support/Example/*.lic".to_string()],
+            extract: DateExtraction::KeyValue("Expires".to_string()),
+            date_formats: vec!["%Y/%m/%d".to_string()],
+            thresholds: ExpiryThresholds::default(),
+        };
+        assert_eq!(
+            deploy_targets(&rule, "site.lic"),
+            vec![("/Library/Application Support/Example".to_string(), "site.lic".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_deploy_command() {
+        let rule = &default_license_rules()[0];
+        let cmd = deploy_command(rule, "w2_license.lic", "A'b\n", "20260201120000").unwrap();
+        // Content travels hex encoded, so quotes in it can't break the command
+        assert!(cmd.contains("printf '%s' '4127620a' | xxd -r -p"));
+        assert!(cmd.contains("for d in '/Applications/Adobe InDesign '*'/Plug-Ins/priint.comet'*; do"));
+        assert!(cmd.contains(r#"f="$d/"'w2_license.lic'"#));
+        assert!(cmd.contains(r#"b="$f.bak-20260201120000""#));
+        assert!(cmd.contains(r#"sudo chown "$owner" "$f" && sudo chmod "$mode" "$f""#));
+        assert!(cmd.ends_with(r#"rm -f "$tmp""#));
+    }
+
+    #[test]
+    fn test_parse_deploy_output() {
+        let output = "Password:\r\n\
+            ==> /Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3/w2_license.lic\t/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3/w2_license.lic.bak-20260201120000\r\n\
+            ==> /Applications/Adobe InDesign 2025/Plug-Ins/priint.comet 5.0/w2_license.lic\t\r\n\
+            ==! /Applications/Adobe InDesign 2023/Plug-Ins/priint.comet 4.2/w2_license.lic\r\n";
+        let (files, failed) = parse_deploy_output(output);
+        assert_eq!(files.len(), 2);
+        assert_eq!(
+            files[0].backup.as_deref(),
+            Some("/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3/w2_license.lic.bak-20260201120000")
+        );
+        assert_eq!(files[1].path, "/Applications/Adobe InDesign 2025/Plug-Ins/priint.comet 5.0/w2_license.lic");
+        assert_eq!(files[1].backup, None);
+        assert_eq!(failed, vec!["/Applications/Adobe InDesign 2023/Plug-Ins/priint.comet 4.2/w2_license.lic"]);
+    }
+
+    #[test]
+    fn test_rollback_command() {
+        let files = vec![
+            DeployedFile { path: "/opt/a/it's.lic".to_string(), backup: Some("/opt/a/it's.lic.bak-1".to_string()) },
+            DeployedFile { path: "/opt/b/new.lic".to_string(), backup: None },
+        ];
+        let cmd = rollback_command(&files);
+        assert!(cmd.contains(r#"if sudo mv '/opt/a/it'\''s.lic.bak-1' '/opt/a/it'\''s.lic'; then echo "==> "'/opt/a/it'\''s.lic'"#));
+        assert!(cmd.contains(r#"if sudo rm -f '/opt/b/new.lic'; then"#));
+
+        // The markers of a successful rollback parse back to the restored paths
+        let (restored, failed) = parse_deploy_output("==> /opt/a/it's.lic\n==> /opt/b/new.lic\n");
+        assert_eq!(restored.len(), 2);
+        assert!(failed.is_empty());
+    }
+
+    #[test]
+    fn test_filename_must_stay_in_license_directory() {
+        let rule = LicenseRule {
+            product: "Example".to_string(),
+            paths: vec!["/Library/Application Support/Example/*.lic".to_string()],
+            extract: DateExtraction::KeyValue("Expires".to_string()),
+            date_formats: vec!["%Y/%m/%d".to_string()],
+            thresholds: ExpiryThresholds::default(),
+        };
+        assert!(validate_filename(&rule, "site.lic").is_ok());
+        for name in ["", ".", "..", "../../../../etc/sudoers.d/x.lic", "sub/site.lic", "site.txt", "x"] {
+            assert!(validate_filename(&rule, name).is_err(), "accepted {:?}", name);
+            assert!(deploy_command(&rule, name, "Expires: 2026/03/01\n", "1").is_err());
+        }
+
+        // With a fixed file name only the basics are checked, the rule's name is used
+        let comet = &default_license_rules()[0];
+        assert!(validate_filename(comet, "renewed.lic").is_ok());
+        assert!(validate_filename(comet, "../x").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{ NaiveDate, TimeZone, Utc };
+    use crate::server::license::{ build_timeline, LicenseRow, LicenseStatus, TimelineKind };
+
+    const HOST: &str = "vg-ph-beam.local";
+    const PATH: &str = "/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet/w2_license.lic";
+
+    // Like real checks: the expiry's line for licenses that were read, none
+    // when the file couldn't be read or parsed
+    fn row(day: u32, status: LicenseStatus, expiry: Option<(i32, u32, u32)>) -> LicenseRow {
+        LicenseRow {
+            host: HOST.to_string(),
+            product: "priint:comet".to_string(),
+            path: Some(PATH.to_string()),
+            line: if status.is_failure() { None } else { Some(2) },
+            status,
+            expiry: expiry.and_then(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d)),
+            checked_at: Utc.with_ymd_and_hms(2025, 1, day, 6, 0, 0).single(),
+            ..Default::default()
+        }
+    }
+
+    fn unreachable(day: u32) -> LicenseRow {
+        LicenseRow {
+            host: HOST.to_string(),
+            status: LicenseStatus::HostUnreachable,
+            message: Some("SSH connection error".to_string()),
+            checked_at: Utc.with_ymd_and_hms(2025, 1, day, 6, 0, 0).single(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_renewal_and_reporting_gaps() {
+        let rows = vec![
+            row(1, LicenseStatus::Warning, Some((2025, 1, 20))),
+            row(2, LicenseStatus::Warning, Some((2025, 1, 20))),
+            unreachable(3),
+            unreachable(4),
+            row(5, LicenseStatus::Valid, Some((2026, 1, 20))),
+            // Another host's history is ignored
+            LicenseRow { host: "other.local".to_string(), ..unreachable(6) },
+        ];
+        let timeline = build_timeline(HOST, &rows);
+        assert_eq!(timeline.checks, 5);
+        assert_eq!(timeline.last_checked, Utc.with_ymd_and_hms(2025, 1, 5, 6, 0, 0).single());
+        assert_eq!(timeline.last_reported, timeline.last_checked);
+
+        let kinds: Vec<TimelineKind> = timeline.events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineKind::Renewed,
+                TimelineKind::ResumedReporting,
+                TimelineKind::StoppedReporting,
+                TimelineKind::FirstSeen,
+            ]
+        );
+        assert_eq!(timeline.events[0].previous_expiry, NaiveDate::from_ymd_opt(2025, 1, 20));
+        assert_eq!(timeline.events[0].expiry, NaiveDate::from_ymd_opt(2026, 1, 20));
+        assert_eq!(timeline.events[2].message.as_deref(), Some("SSH connection error"));
+    }
+
+    #[test]
+    fn test_last_reported_and_disappeared() {
+        let other = LicenseRow { product: "InDesign".to_string(), path: None, ..row(1, LicenseStatus::Valid, None) };
+        let rows = vec![
+            row(1, LicenseStatus::Valid, Some((2026, 1, 1))),
+            other.clone(),
+            LicenseRow { checked_at: Utc.with_ymd_and_hms(2025, 1, 2, 6, 0, 0).single(), ..other },
+            unreachable(3),
+        ];
+        let timeline = build_timeline(HOST, &rows);
+        assert_eq!(timeline.last_reported, Utc.with_ymd_and_hms(2025, 1, 2, 6, 0, 0).single());
+        assert_ne!(timeline.last_reported, timeline.last_checked);
+        let disappeared: Vec<_> = timeline.events
+            .iter()
+            .filter(|e| e.kind == TimelineKind::Disappeared)
+            .collect();
+        assert_eq!(disappeared.len(), 1);
+        assert_eq!(disappeared[0].product, "priint:comet");
+        assert_eq!(disappeared[0].previous_status, Some(LicenseStatus::Valid));
+    }
+
+    #[test]
+    fn test_flapping_unreadable() {
+        let statuses = [
+            LicenseStatus::Valid,
+            LicenseStatus::Unreadable,
+            LicenseStatus::Valid,
+            LicenseStatus::Unreadable,
+            LicenseStatus::Valid,
+        ];
+        let rows: Vec<LicenseRow> = statuses
+            .iter()
+            .enumerate()
+            .map(|(idx, status)| row(idx as u32 + 1, *status, None))
+            .collect();
+        let timeline = build_timeline(HOST, &rows);
+        assert_eq!(timeline.unstable.len(), 1);
+        assert_eq!(timeline.unstable[0].changes, 4);
+        assert_eq!(timeline.events.iter().filter(|e| e.kind == TimelineKind::StatusChanged).count(), 4);
+
+        // Two changes aren't flapping yet
+        let timeline = build_timeline(HOST, &rows[..3]);
+        assert!(timeline.unstable.is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_is_a_status_change() {
+        let rows = vec![
+            row(1, LicenseStatus::Valid, Some((2026, 1, 1))),
+            row(2, LicenseStatus::Unreadable, None),
+            row(3, LicenseStatus::Valid, Some((2026, 1, 1))),
+            row(4, LicenseStatus::Unreadable, None),
+        ];
+        assert_eq!(rows[0].line, Some(2));
+        assert_eq!(rows[1].line, None);
+        let timeline = build_timeline(HOST, &rows);
+        let kinds: Vec<TimelineKind> = timeline.events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineKind::StatusChanged,
+                TimelineKind::StatusChanged,
+                TimelineKind::StatusChanged,
+                TimelineKind::FirstSeen,
+            ]
+        );
+        assert_eq!(timeline.unstable.len(), 1);
+        assert_eq!(timeline.unstable[0].changes, 3);
+    }
+
+    #[test]
+    fn test_failed_read_without_path_is_a_status_change() {
+        // What the checker records when the SSH read of a rule fails
+        let failed = |day: u32, status: LicenseStatus| LicenseRow {
+            path: None,
+            message: Some("Connection closed".to_string()),
+            ..row(day, status, None)
+        };
+        let rows = vec![
+            row(1, LicenseStatus::Valid, Some((2026, 1, 1))),
+            failed(2, LicenseStatus::Unreadable),
+            row(3, LicenseStatus::Valid, Some((2026, 1, 1))),
+            failed(4, LicenseStatus::NotFound),
+        ];
+        let timeline = build_timeline(HOST, &rows);
+        let kinds: Vec<TimelineKind> = timeline.events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineKind::StatusChanged,
+                TimelineKind::StatusChanged,
+                TimelineKind::StatusChanged,
+                TimelineKind::FirstSeen,
+            ]
+        );
+        assert_eq!(timeline.events[2].status, LicenseStatus::Unreadable);
+        assert_eq!(timeline.events[2].path.as_deref(), Some(PATH));
+        assert_eq!(timeline.events[0].previous_status, Some(LicenseStatus::Valid));
+        assert_eq!(timeline.events[0].status, LicenseStatus::NotFound);
+
+        // The unreadable round trip counts towards flapping
+        let rows = [rows[..3].to_vec(), vec![failed(4, LicenseStatus::Unreadable)]].concat();
+        let timeline = build_timeline(HOST, &rows);
+        assert_eq!(timeline.unstable.len(), 1);
+        assert_eq!(timeline.unstable[0].path.as_deref(), Some(PATH));
+        assert_eq!(timeline.unstable[0].changes, 3);
+    }
+
+    #[test]
+    fn test_renewal_on_another_line() {
+        let renewed = LicenseRow { line: Some(5), ..row(2, LicenseStatus::Valid, Some((2026, 1, 20))) };
+        let timeline = build_timeline(HOST, &[row(1, LicenseStatus::Warning, Some((2025, 1, 20))), renewed]);
+        assert_eq!(timeline.events.len(), 2);
+        assert_eq!(timeline.events[0].kind, TimelineKind::Renewed);
+    }
+
+    #[test]
+    fn test_several_licenses_in_one_file() {
+        let check = |day: u32, second: LicenseStatus| {
+            vec![
+                row(day, LicenseStatus::Valid, Some((2026, 1, 1))),
+                LicenseRow { line: Some(4), ..row(day, second, Some((2025, 3, 1))) },
+            ]
+        };
+        let rows: Vec<LicenseRow> = [check(1, LicenseStatus::Notice), check(2, LicenseStatus::Warning)].concat();
+        let timeline = build_timeline(HOST, &rows);
+        let kinds: Vec<TimelineKind> = timeline.events.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![TimelineKind::StatusChanged, TimelineKind::FirstSeen, TimelineKind::FirstSeen]);
+        assert_eq!(timeline.events[0].status, LicenseStatus::Warning);
+    }
+}
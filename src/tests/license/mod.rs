@@ -0,0 +1,6 @@
+pub mod rules;
+pub mod store;
+pub mod notify;
+pub mod deploy;
+pub mod content;
+pub mod history;
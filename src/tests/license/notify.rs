@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::io::{ BufRead, BufReader, Read, Write };
+    use std::net::TcpListener;
+    use std::thread;
+    use chrono::{ NaiveDate, TimeZone, Utc };
+    use crate::server::license::{
+        notify::{ detect_transitions, send_all, SmtpSink, WebhookSink },
+        LicenseCheckResult,
+        LicenseNotification,
+        LicenseStatus,
+        NotificationSink,
+        ProductLicense,
+    };
+
+    fn check(status: LicenseStatus, days: i64) -> LicenseCheckResult {
+        LicenseCheckResult {
+            host: "vg-ph-beam.local".to_string(),
+            status,
+            licenses: vec![ProductLicense {
+                product: "priint:comet 4.3".to_string(),
+                path: Some("/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet/w2_license.lic".to_string()),
+                expiry: NaiveDate::from_ymd_opt(2025, 1, 1).map(|d| d + chrono::Duration::days(days)),
+                days_remaining: Some(days),
+                status,
+                ..Default::default()
+            }],
+            checked_at: Utc.with_ymd_and_hms(2025, 1, 1, 6, 0, 0).single(),
+            ..Default::default()
+        }
+    }
+
+    fn notification() -> LicenseNotification {
+        detect_transitions(&mut BTreeMap::new(), &check(LicenseStatus::Warning, 12)).remove(0)
+    }
+
+    struct FixedSink(Result<(), String>);
+
+    impl NotificationSink for FixedSink {
+        fn name(&self) -> String {
+            "fixed".to_string()
+        }
+
+        fn send(&self, _notification: &LicenseNotification) -> Result<(), String> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_send_all_keeps_undelivered_notifications() {
+        let down: Box<dyn NotificationSink> = Box::new(FixedSink(Err("Connection refused".to_string())));
+        let up: Box<dyn NotificationSink> = Box::new(FixedSink(Ok(())));
+        let notifications = vec![notification()];
+
+        // Nothing accepted it, so it stays queued for the next check
+        assert!(send_all(&[down], &notifications).is_empty());
+
+        let down: Box<dyn NotificationSink> = Box::new(FixedSink(Err("Connection refused".to_string())));
+        assert_eq!(send_all(&[down, up], &notifications), notifications);
+
+        // Only logged when no sink is configured
+        assert_eq!(send_all(&[], &notifications), notifications);
+    }
+
+    #[test]
+    fn test_detect_transitions_notifies_once_per_threshold() {
+        let mut notified = BTreeMap::new();
+
+        let first = detect_transitions(&mut notified, &check(LicenseStatus::Notice, 60));
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].previous, None);
+        assert_eq!(first[0].status, LicenseStatus::Notice);
+
+        // Same status on the next run stays quiet
+        assert!(detect_transitions(&mut notified, &check(LicenseStatus::Notice, 59)).is_empty());
+
+        let warning = detect_transitions(&mut notified, &check(LicenseStatus::Warning, 20));
+        assert_eq!(warning.len(), 1);
+        assert_eq!(warning[0].previous, Some(LicenseStatus::Notice));
+
+        // A failed check doesn't reset anything
+        let unreachable = LicenseCheckResult {
+            host: "vg-ph-beam.local".to_string(),
+            status: LicenseStatus::HostUnreachable,
+            ..Default::default()
+        };
+        assert!(detect_transitions(&mut notified, &unreachable).is_empty());
+        assert!(detect_transitions(&mut notified, &check(LicenseStatus::Warning, 19)).is_empty());
+
+        // Renewal clears the state, so the next expiry notifies again
+        assert!(detect_transitions(&mut notified, &check(LicenseStatus::Valid, 365)).is_empty());
+        assert!(notified.is_empty());
+        assert_eq!(detect_transitions(&mut notified, &check(LicenseStatus::Warning, 10)).len(), 1);
+    }
+
+    #[test]
+    fn test_detect_transitions_per_license_in_file() {
+        let mut result = check(LicenseStatus::Warning, 12);
+        let mut second = result.licenses[0].clone();
+        result.licenses[0].line = Some(2);
+        second.line = Some(4);
+        result.licenses.push(second);
+
+        let mut notified = BTreeMap::new();
+        assert_eq!(detect_transitions(&mut notified, &result).len(), 2);
+        assert_eq!(notified.len(), 2);
+        // The first license of a file keeps the key it had before lines were tracked
+        assert!(notified.contains_key(
+            "vg-ph-beam.local|priint:comet 4.3|/Applications/Adobe InDesign 2024/Plug-Ins/priint.comet/w2_license.lic"
+        ));
+    }
+
+    #[test]
+    fn test_webhook_sink_posts_json() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let mut writer = stream;
+            writer.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+            String::from_utf8(body).unwrap()
+        });
+
+        let sink = WebhookSink { url: format!("http://127.0.0.1:{}/hooks/licenses", port) };
+        sink.send(&notification()).unwrap();
+
+        let payload: LicenseNotification = serde_json::from_str(&server.join().unwrap()).unwrap();
+        assert_eq!(payload, notification());
+    }
+
+    #[test]
+    fn test_smtp_sink_sends_message() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut writer = stream;
+            let mut commands = Vec::new();
+            let mut data = String::new();
+            writer.write_all(b"220 mail.local ESMTP\r\n").unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    break;
+                }
+                let line = line.trim_end().to_string();
+                let reply: &[u8] = match line.as_str() {
+                    l if l.starts_with("EHLO") => b"250-mail.local\r\n250 8BITMIME\r\n",
+                    "DATA" => {
+                        writer.write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n").unwrap();
+                        loop {
+                            let mut data_line = String::new();
+                            reader.read_line(&mut data_line).unwrap();
+                            if data_line == ".\r\n" {
+                                break;
+                            }
+                            data.push_str(&data_line);
+                        }
+                        b"250 Queued\r\n"
+                    }
+                    "QUIT" => {
+                        writer.write_all(b"221 Bye\r\n").unwrap();
+                        commands.push(line);
+                        break;
+                    }
+                    _ => b"250 OK\r\n",
+                };
+                commands.push(line);
+                writer.write_all(reply).unwrap();
+            }
+            (commands, data)
+        });
+
+        let sink = SmtpSink {
+            server: "127.0.0.1".to_string(),
+            port,
+            from: "it-management@example.com".to_string(),
+            to: vec!["it@example.com".to_string(), "ops@example.com".to_string()],
+        };
+        sink.send(&notification()).unwrap();
+
+        let (commands, data) = server.join().unwrap();
+        assert_eq!(commands, vec![
+            "EHLO it-management",
+            "MAIL FROM:<it-management@example.com>",
+            "RCPT TO:<it@example.com>",
+            "RCPT TO:<ops@example.com>",
+            "DATA",
+            "QUIT"
+        ]);
+        assert!(data.contains("To: it@example.com, ops@example.com\r\n"));
+        assert!(data.contains("Subject: License Warning: priint:comet 4.3 on vg-ph-beam.local\r\n"));
+        assert!(data.contains("Days remaining: 12\r\n"));
+    }
+}
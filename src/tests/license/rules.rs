@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::server::license::rules::{
+        default_license_rules,
+        parse_license_files,
+        quote_glob,
+        remote_command,
+        DateExtraction,
+        ExpiryThresholds,
+        LicenseRule,
+    };
+    use crate::server::license::{ most_urgent, LicenseStatus, ProductLicense };
+    use crate::tests::common::fixtures::today;
+
+    const COMET_OUTPUT: &str = "==> /Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic
+// Licensee: Example Print
+// Expires: 2025/03/01
+KEY=abcdef
+
+==> /Applications/Adobe InDesign 2024/Plug-Ins/priint.comet 4.3 R 34010/w2_license.lic
+// Expires: 2026/01/15
+
+";
+
+    fn rule(extract: DateExtraction, formats: &[&str]) -> LicenseRule {
+        LicenseRule {
+            product: "Example".to_string(),
+            paths: vec!["/Library/Application Support/Example/*.lic".to_string()],
+            extract,
+            date_formats: formats.iter().map(|f| f.to_string()).collect(),
+            thresholds: ExpiryThresholds::default(),
+        }
+    }
+
+    #[test]
+    fn test_quote_glob() {
+        assert_eq!(
+            quote_glob("/Applications/Adobe InDesign */Plug-Ins/priint.comet*/w2_license.lic"),
+            "'/Applications/Adobe InDesign '*'/Plug-Ins/priint.comet'*'/w2_license.lic'"
+        );
+        assert_eq!(quote_glob("/opt/app[0-9]/it's.lic"), "'/opt/app'[0-9]'/it'\\''s.lic'");
+    }
+
+    #[test]
+    fn test_remote_command() {
+        let command = remote_command(&default_license_rules()[0]);
+        assert!(command.starts_with("for f in '/Applications/Adobe InDesign '*"));
+        assert!(command.contains(r#"cat "$f""#));
+
+        let plist = rule(DateExtraction::PlistKey("ExpirationDate".to_string()), &[]);
+        assert!(remote_command(&plist).contains(r#"plutil -convert xml1 -o - "$f""#));
+    }
+
+    #[test]
+    fn test_parse_comet_license_files() {
+        let licenses = parse_license_files(&default_license_rules()[0], COMET_OUTPUT, &today());
+        assert_eq!(licenses.len(), 2);
+        assert_eq!(
+            licenses[0].path.as_deref(),
+            Some("/Applications/Adobe InDesign CC 2017/Plug-Ins/priint.comet 4.1.6 R R25255/w2_license.lic")
+        );
+        assert_eq!(licenses[0].matched.as_deref(), Some("2025/03/01"));
+        assert_eq!(licenses[0].expiry, NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(licenses[0].status, LicenseStatus::Warning);
+        assert_eq!(licenses[0].days_remaining, Some(28));
+        assert_eq!(licenses[1].status, LicenseStatus::Valid);
+        assert_eq!(most_urgent(&licenses), Some(&licenses[0]));
+    }
+
+    #[test]
+    fn test_extraction_methods() {
+        let regex = rule(DateExtraction::Regex(r"valid through (\S+)".to_string()), &["%d.%m.%Y"]);
+        let output = "==> /Library/Application Support/Example/a.lic\nLicense valid through 30.04.2025\n";
+        assert_eq!(parse_license_files(&regex, output, &today())[0].expiry, NaiveDate::from_ymd_opt(2025, 4, 30));
+
+        let plist = rule(DateExtraction::PlistKey("ExpirationDate".to_string()), &["%Y-%m-%dT%H:%M:%SZ"]);
+        let output = "==> /Library/Preferences/com.example.plist
+<dict>
+\t<key>ExpirationDate</key>
+\t<date>2025-02-20T00:00:00Z</date>
+</dict>
+";
+        let license = &parse_license_files(&plist, output, &today())[0];
+        assert_eq!(license.expiry, NaiveDate::from_ymd_opt(2025, 2, 20));
+
+        let json = rule(DateExtraction::JsonPath("$.license.expires".to_string()), &["%Y-%m-%d"]);
+        let output = "==> /etc/example.json\n{\"license\": {\"expires\": \"2024-12-31\"}}\n";
+        let license = &parse_license_files(&json, output, &today())[0];
+        assert_eq!(license.status, LicenseStatus::Expired);
+        assert_eq!(license.days_remaining, Some(-32));
+    }
+
+    #[test]
+    fn test_thresholds_and_failures() {
+        let mut key_value = rule(DateExtraction::KeyValue("expiry".to_string()), &["%Y-%m-%d"]);
+        key_value.thresholds = ExpiryThresholds { warning_days: 7, notice_days: 60 };
+        let output = "==> /a.lic\nExpiry = 2025-02-20\n==> /b.lic\nExpiry = someday\n==> /c.lic\nnothing here\n==! /d.lic\n";
+        let licenses = parse_license_files(&key_value, output, &today());
+        assert_eq!(licenses[0].status, LicenseStatus::Notice);
+        assert_eq!(licenses[1].status, LicenseStatus::ParseError);
+        assert_eq!(licenses[1].matched.as_deref(), Some("someday"));
+        assert_eq!(licenses[2].status, LicenseStatus::ParseError);
+        assert_eq!(licenses[3].status, LicenseStatus::Unreadable);
+        assert_eq!(licenses[3].path.as_deref(), Some("/d.lic"));
+
+        let missing = parse_license_files(&key_value, "", &today());
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].path, None);
+        assert_eq!(missing[0].status, LicenseStatus::NotFound);
+    }
+
+    #[test]
+    fn test_most_urgent_ranks_failures_above_healthy_licenses() {
+        let output = format!("{}==! /Applications/Adobe InDesign 2025/Plug-Ins/priint.comet 5.0/w2_license.lic\n", COMET_OUTPUT);
+        let mut licenses = parse_license_files(&default_license_rules()[0], &output, &today());
+        licenses.remove(0);
+        assert_eq!(licenses[0].status, LicenseStatus::Valid);
+        assert_eq!(most_urgent(&licenses).map(|l| l.status), Some(LicenseStatus::Unreadable));
+
+        // Expiry states still come first, the nearest expiry before later ones
+        let licenses = parse_license_files(&default_license_rules()[0], &output, &today());
+        assert_eq!(most_urgent(&licenses), Some(&licenses[0]));
+
+        let perpetual = ProductLicense { status: LicenseStatus::Valid, ..licenses[1].clone() };
+        let dated = ProductLicense { days_remaining: Some(400), ..perpetual.clone() };
+        let undated = ProductLicense { days_remaining: None, expiry: None, ..perpetual };
+        let both = [undated, dated.clone()];
+        assert_eq!(most_urgent(&both), Some(&dated));
+    }
+
+    #[test]
+    fn test_config_format() {
+        let config = r#"[{
+            "product": "InDesign 2024",
+            "paths": ["/Library/Application Support/Adobe/*.lic"],
+            "extract": { "key_value": "Expires" },
+            "thresholds": { "warning_days": 14, "notice_days": 45 }
+        }]"#;
+        let rules: Vec<LicenseRule> = serde_json::from_str(config).unwrap();
+        assert_eq!(rules[0].extract, DateExtraction::KeyValue("Expires".to_string()));
+        assert_eq!(rules[0].date_formats, vec!["%Y/%m/%d", "%Y-%m-%d"]);
+        assert_eq!(rules[0].thresholds.warning_days, 14);
+    }
+}
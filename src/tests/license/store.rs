@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::server::inventory::{ InventoryRecord, RecordSource };
+    use crate::server::license::store::{ cap_history, fleet_hosts };
+    use crate::server::resolve_computer::ComputerInfo;
+    use crate::server::license::{ dashboard_rows, LicenseCheckResult, LicenseRow, LicenseStatus, ProductLicense };
+
+    fn license(product: &str, status: LicenseStatus, days: Option<i64>) -> ProductLicense {
+        ProductLicense {
+            product: product.to_string(),
+            path: Some(format!("/Applications/{}/license.lic", product)),
+            expiry: days.map(|d| NaiveDate::from_ymd_opt(2025, 1, 1).unwrap() + chrono::Duration::days(d)),
+            days_remaining: days,
+            status,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dashboard_rows_per_license_file() {
+        let results = vec![
+            LicenseCheckResult {
+                host: "vg-ph-beam.local".to_string(),
+                status: LicenseStatus::Warning,
+                licenses: vec![
+                    license("priint:comet 4.1.6", LicenseStatus::Warning, Some(12)),
+                    license("priint:comet 4.3", LicenseStatus::Valid, Some(300))
+                ],
+                ..Default::default()
+            },
+            LicenseCheckResult {
+                host: "vg-ph-fon.local".to_string(),
+                status: LicenseStatus::HostUnreachable,
+                error: Some("SSH connection error: timed out".to_string()),
+                ..Default::default()
+            }
+        ];
+        let rows = dashboard_rows(&results);
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].host, "vg-ph-beam.local");
+        assert_eq!(rows[0].product, "priint:comet 4.1.6");
+        assert_eq!(rows[0].days_remaining, Some(12));
+        assert_eq!(rows[1].status, LicenseStatus::Valid);
+        assert_eq!(rows[2].host, "vg-ph-fon.local");
+        assert_eq!(rows[2].product, "");
+        assert_eq!(rows[2].status, LicenseStatus::HostUnreachable);
+        assert_eq!(rows[2].message.as_deref(), Some("SSH connection error: timed out"));
+    }
+
+    #[test]
+    fn test_status_severity_order() {
+        let mut statuses = vec![
+            LicenseStatus::Valid,
+            LicenseStatus::NotFound,
+            LicenseStatus::Expired,
+            LicenseStatus::Notice,
+            LicenseStatus::HostUnreachable,
+            LicenseStatus::Warning
+        ];
+        statuses.sort_by_key(|s| std::cmp::Reverse(s.severity()));
+        assert_eq!(
+            statuses,
+            vec![
+                LicenseStatus::Expired,
+                LicenseStatus::Warning,
+                LicenseStatus::Notice,
+                LicenseStatus::HostUnreachable,
+                LicenseStatus::NotFound,
+                LicenseStatus::Valid
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cap_history_per_product() {
+        let row = |product: &str, days: i64| LicenseRow {
+            host: "vg-ph-beam.local".to_string(),
+            product: product.to_string(),
+            days_remaining: Some(days),
+            ..Default::default()
+        };
+        let mut rows = vec![row("comet", 3), row("indesign", 3), row("comet", 2), row("comet", 1), row("", 0)];
+        cap_history(&mut rows, 2);
+        let kept: Vec<(&str, Option<i64>)> = rows.iter().map(|r| (r.product.as_str(), r.days_remaining)).collect();
+        // A busy product doesn't push out another product's older rows
+        assert_eq!(kept, vec![("indesign", Some(3)), ("comet", Some(2)), ("comet", Some(1)), ("", Some(0))]);
+    }
+
+    #[test]
+    fn test_fleet_hosts_skip_serial_keyed_records() {
+        let record = |host: &str, serial: &str, source: RecordSource| InventoryRecord {
+            host: host.to_string(),
+            info: ComputerInfo { serial: serial.to_string(), ..Default::default() },
+            last_resolved: None,
+            source,
+        };
+        let records = vec![
+            record("vg-ph-beam.local", "C02XL0GHJGH5", RecordSource::Ssh),
+            record("C02SPARE0001", "C02SPARE0001", RecordSource::Manual),
+            record("vg-ph-loaner.local", "C02LOAN00001", RecordSource::Manual),
+        ];
+        assert_eq!(
+            fleet_hosts(&records, vec!["vg-ph-beam.local".to_string(), "vg-ph-fon.local".to_string()]),
+            vec!["vg-ph-beam.local", "vg-ph-fon.local", "vg-ph-loaner.local"]
+        );
+    }
+}
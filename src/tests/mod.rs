@@ -1,3 +1,11 @@
 pub mod common;
 pub mod command;
 pub mod resolve_computer;
+pub mod collectors;
+pub mod inventory;
+pub mod people;
+pub mod groups;
+pub mod license;
+pub mod calendar;
+pub mod entitlements;
+pub mod store;
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{ TimeZone, Utc };
+    use crate::server::people::{ Assignment, Location, Person };
+    use crate::server::people::store::{ apply_check_in, apply_check_out, make_id, owner_from_hostname, PeopleData };
+
+    const HOST: &str = "vg-ph-beam.local";
+
+    fn data() -> PeopleData {
+        PeopleData {
+            people: vec![
+                Person { id: "beam".to_string(), name: "Beam".to_string(), ..Default::default() },
+                Person { id: "suriya".to_string(), name: "Suriya".to_string(), ..Default::default() },
+            ],
+            locations: vec![Location { id: "studio".to_string(), name: "Studio".to_string(), ..Default::default() }],
+            assignments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_make_id_from_name() {
+        assert_eq!(make_id("Beam Suriya", &[]), "beam-suriya");
+        assert_eq!(make_id("  Studio 2 / Floor 3 ", &[]), "studio-2-floor-3");
+        assert_eq!(make_id("!!!", &[]), "item");
+    }
+
+    #[test]
+    fn test_make_id_is_unique() {
+        assert_eq!(make_id("Beam", &["beam"]), "beam-2");
+        assert_eq!(make_id("Beam", &["beam", "beam-2"]), "beam-3");
+    }
+
+    #[test]
+    fn test_owner_from_hostname() {
+        assert_eq!(owner_from_hostname("vg-ph-beam.local"), Some("beam".to_string()));
+        assert_eq!(owner_from_hostname("vg-ph-Suriya.local."), Some("suriya".to_string()));
+        assert_eq!(owner_from_hostname("vg-ph-toom102"), Some("toom102".to_string()));
+        assert_eq!(owner_from_hostname("vg-ph-.local"), None);
+        assert_eq!(owner_from_hostname("printer.local"), None);
+    }
+
+    #[test]
+    fn test_assignment_active_until_checked_in() {
+        let mut assignment = Assignment {
+            host: "vg-ph-beam.local".to_string(),
+            serial: Some("C02XL0GHJGH5".to_string()),
+            person_id: Some("beam".to_string()),
+            location_id: None,
+            checked_out_at: Utc::now(),
+            checked_in_at: None,
+            note: None,
+        };
+        assert!(assignment.is_active());
+        assignment.checked_in_at = Some(Utc::now());
+        assert!(!assignment.is_active());
+    }
+
+    #[test]
+    fn test_check_out_closes_previous_assignment() {
+        let mut data = data();
+        let first = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2025, 2, 1, 9, 0, 0).unwrap();
+        apply_check_out(&mut data, HOST, None, Some("beam".to_string()), None, None, first).unwrap();
+        let current = apply_check_out(&mut data, HOST, None, Some("suriya".to_string()), None, None, second).unwrap();
+
+        assert_eq!(data.assignments.len(), 2);
+        assert_eq!(data.assignments[0].checked_in_at, Some(second));
+        assert!(current.is_active());
+        assert_eq!(data.assignments.iter().filter(|a| a.is_active()).count(), 1);
+
+        // Other machines keep their assignments
+        apply_check_out(&mut data, "vg-ph-toom.local", None, None, Some("studio".to_string()), None, second).unwrap();
+        assert!(data.assignments[1].is_active());
+    }
+
+    #[test]
+    fn test_check_out_rejects_unknown_targets() {
+        let mut data = data();
+        let now = Utc::now();
+        assert_eq!(
+            apply_check_out(&mut data, HOST, None, Some("nobody".to_string()), None, None, now),
+            Err("Unknown person: nobody".to_string())
+        );
+        assert_eq!(
+            apply_check_out(&mut data, HOST, None, None, Some("attic".to_string()), None, now),
+            Err("Unknown location: attic".to_string())
+        );
+        assert!(apply_check_out(&mut data, HOST, None, None, None, None, now).is_err());
+        assert!(data.assignments.is_empty());
+    }
+
+    #[test]
+    fn test_check_in() {
+        let mut data = data();
+        let now = Utc::now();
+        assert_eq!(
+            apply_check_in(&mut data, HOST, None, now),
+            Err(format!("{} is not checked out", HOST))
+        );
+
+        apply_check_out(&mut data, HOST, None, Some("beam".to_string()), None, Some("laptop".to_string()), now).unwrap();
+        let returned = apply_check_in(&mut data, HOST, Some("returned".to_string()), now).unwrap();
+        assert_eq!(returned.checked_in_at, Some(now));
+        assert_eq!(returned.note.as_deref(), Some("returned"));
+        assert!(apply_check_in(&mut data, HOST, None, now).is_err());
+    }
+}
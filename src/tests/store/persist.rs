@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::server::store::{ append_lines_path, load_lines_path, load_path, save_lines_path, save_path };
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("it-management-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = temp_dir("store-round-trip").join("groups.json");
+        let value: BTreeMap<String, u32> = [("a".to_string(), 1)].into_iter().collect();
+        save_path(&path, &value).unwrap();
+        assert_eq!(load_path::<BTreeMap<String, u32>>(&path), value);
+        assert!(load_path::<BTreeMap<String, u32>>(&path.with_file_name("missing.json")).is_empty());
+    }
+
+    #[test]
+    fn test_unparseable_file_is_kept() {
+        let dir = temp_dir("store-corrupt");
+        let path = dir.join("people.json");
+        fs::write(&path, "{\"people\": [oops").unwrap();
+
+        let loaded: BTreeMap<String, u32> = load_path(&path);
+        assert!(loaded.is_empty());
+        assert!(!path.exists());
+        let moved: Vec<PathBuf> = fs::read_dir(&dir).unwrap().map(|e| e.unwrap().path()).collect();
+        assert_eq!(moved.len(), 1);
+        assert!(moved[0].file_name().unwrap().to_string_lossy().starts_with("people.json.corrupt-"));
+        assert_eq!(fs::read_to_string(&moved[0]).unwrap(), "{\"people\": [oops");
+
+        // Saving afterwards writes a fresh document without touching the old one
+        save_path(&path, &loaded).unwrap();
+        assert_eq!(fs::read_to_string(&moved[0]).unwrap(), "{\"people\": [oops");
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let path = temp_dir("store-lines").join("history.jsonl");
+        append_lines_path(&path, &[1u32, 2]).unwrap();
+        append_lines_path(&path, &[3u32]).unwrap();
+        assert_eq!(load_lines_path::<u32>(&path), vec![1, 2, 3]);
+
+        // A line cut short mid-append doesn't take the rest with it
+        let mut content = fs::read_to_string(&path).unwrap();
+        content.push_str("{\"trunc");
+        fs::write(&path, content).unwrap();
+        assert_eq!(load_lines_path::<u32>(&path), vec![1, 2, 3]);
+
+        save_lines_path(&path, &[3u32]).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3\n");
+    }
+}
@@ -0,0 +1,27 @@
+use wasm_bindgen::JsCast;
+
+// Saves bytes in the browser by clicking a temporary blob link
+pub fn download_file(filename: &str, content_type: &str, data: &[u8]) -> Result<(), String> {
+    let window = web_sys::window().ok_or("No window available")?;
+    let document = window.document().ok_or("No document available")?;
+
+    let parts = js_sys::Array::new();
+    parts.push(&js_sys::Uint8Array::from(data));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(content_type);
+    let blob = web_sys::Blob
+        ::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|e| format!("{:?}", e))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(|e| format!("{:?}", e))?;
+
+    let anchor = document
+        .create_element("a")
+        .map_err(|e| format!("{:?}", e))?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| "Failed to create download link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url).map_err(|e| format!("{:?}", e))
+}
@@ -1,3 +1,7 @@
 mod theme_state;
+mod timer;
+mod download;
 
 pub use theme_state::*;
+pub use timer::*;
+pub use download::*;
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use chrono::{ Local, NaiveDate };
+use dioxus::prelude::*;
+use crate::server::{
+    get_inventory_records,
+    import_asset_metadata_csv,
+    list_asset_metadata,
+    save_asset_metadata,
+};
+use crate::server::inventory::{ AssetImportSummary, AssetMetadata };
+use crate::server::license::{ analyze_expiration_date, ExpiryThresholds };
+use crate::components::license_status::{ describe_status, status_class, status_label };
+
+#[component]
+pub fn CoverageCell(expiry: Option<NaiveDate>) -> Element {
+    let Some(expiry) = expiry else {
+        return rsx!(span { class: "text-gray-400", "—" });
+    };
+    let (status, days) = analyze_expiration_date(&expiry, &Local::now().date_naive(), &ExpiryThresholds::default());
+    let description = describe_status(status, Some(expiry), Some(days));
+
+    rsx!(
+        div { {expiry.to_string()} }
+        div { class: "text-sm font-medium {status_class(status)}", title: "{description}", {status_label(status)} }
+    )
+}
+
+fn parse_date_input(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim().to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+#[component]
+fn AssetForm(asset: AssetMetadata, on_saved: EventHandler<()>, on_cancel: EventHandler<()>) -> Element {
+    let mut draft = use_signal(|| asset.clone());
+    let mut error = use_signal(|| None::<String>);
+    let input_class = "mt-1 block w-full rounded border border-gray-300 px-2 py-1";
+
+    let save = move |_| {
+        spawn(async move {
+            match save_asset_metadata(draft.read().clone()).await {
+                Ok(_) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let date_value = |date: Option<NaiveDate>| date.map(|d| d.to_string()).unwrap_or_default();
+
+    rsx!(
+        div {
+            class: "mb-6 p-4 bg-white shadow rounded-lg",
+            h2 { class: "text-lg font-semibold mb-4", {format!("Asset {}", asset.serial)} }
+            div {
+                class: "grid grid-cols-2 gap-4 text-sm",
+                label { "Purchase date"
+                    input { class: input_class, r#type: "date", value: date_value(draft.read().purchase_date),
+                        oninput: move |evt| draft.with_mut(|d| d.purchase_date = parse_date_input(&evt.value())) }
+                }
+                label { "Supplier"
+                    input { class: input_class, value: draft.read().supplier.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|d| d.supplier = non_empty(evt.value())) }
+                }
+                label { "Invoice number"
+                    input { class: input_class, value: draft.read().invoice_number.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|d| d.invoice_number = non_empty(evt.value())) }
+                }
+                label { "Cost"
+                    input { class: input_class, r#type: "number", step: "0.01", value: draft.read().cost.map(|c| c.to_string()).unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|d| d.cost = evt.value().parse::<f64>().ok()) }
+                }
+                label { "Warranty expiry"
+                    input { class: input_class, r#type: "date", value: date_value(draft.read().warranty_expiry),
+                        oninput: move |evt| draft.with_mut(|d| d.warranty_expiry = parse_date_input(&evt.value())) }
+                }
+                label { "AppleCare expiry"
+                    input { class: input_class, r#type: "date", value: date_value(draft.read().applecare_expiry),
+                        oninput: move |evt| draft.with_mut(|d| d.applecare_expiry = parse_date_input(&evt.value())) }
+                }
+                label { class: "col-span-2", "Notes"
+                    input { class: input_class, value: draft.read().notes.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|d| d.notes = non_empty(evt.value())) }
+                }
+            }
+            if let Some(e) = error.read().as_ref() {
+                div { class: "mt-2 text-red-500 text-sm", {e.clone()} }
+            }
+            div {
+                class: "mt-4 flex gap-2",
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600",
+                    onclick: save,
+                    "Save"
+                }
+                button {
+                    class: "px-3 py-1 bg-gray-200 rounded text-sm hover:bg-gray-300",
+                    onclick: move |_| on_cancel.call(()),
+                    "Cancel"
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ImportResult(summary: AssetImportSummary) -> Element {
+    rsx!(
+        div {
+            class: "mb-4 text-sm",
+            div { {format!("Imported: {} new, {} updated", summary.created, summary.updated)} }
+            for error in summary.errors.iter() {
+                div { class: "text-red-500", {format!("Line {}: {}", error.line, error.message)} }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn Assets() -> Element {
+    let mut assets = use_resource(|| async move { list_asset_metadata().await });
+    let records = use_resource(|| async move { get_inventory_records().await.unwrap_or_default() });
+    let mut editing = use_signal(|| None::<AssetMetadata>);
+    let mut import_result = use_signal(|| None::<Result<AssetImportSummary, String>>);
+
+    let import_file = move |evt: Event<FormData>| async move {
+        let Some(files) = evt.files() else {
+            return;
+        };
+        for name in files.files() {
+            let Some(data) = files.read_file_to_string(&name).await else {
+                import_result.set(Some(Err(format!("Could not read {}", name))));
+                continue;
+            };
+            let result = import_asset_metadata_csv(data).await.map_err(|e| e.to_string());
+            import_result.set(Some(result));
+            assets.restart();
+        }
+    };
+
+    // Machines by serial so metadata rows show which host they belong to
+    let machines: BTreeMap<String, String> = records
+        .read()
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|record| !record.info.serial.is_empty())
+        .map(|record| (record.info.serial.clone(), format!("{} ({})", record.info.title, record.host)))
+        .collect();
+
+    let mut rows: BTreeMap<String, AssetMetadata> = machines
+        .keys()
+        .map(|serial| (serial.clone(), AssetMetadata { serial: serial.clone(), ..Default::default() }))
+        .collect();
+    if let Some(Ok(list)) = &*assets.read() {
+        for asset in list {
+            rows.insert(asset.serial.clone(), asset.clone());
+        }
+    }
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Assets" }
+            div {
+                class: "mb-4 flex items-center gap-4 text-sm",
+                span { class: "font-semibold", "Import CSV:" }
+                input { r#type: "file", accept: ".csv", onchange: import_file }
+            }
+            match &*import_result.read() {
+                Some(Ok(summary)) => rsx!(ImportResult { summary: summary.clone() }),
+                Some(Err(e)) => rsx!(div { class: "mb-4 text-red-500 text-sm", {e.clone()} }),
+                None => rsx!(),
+            }
+            if let Some(asset) = editing.read().clone() {
+                AssetForm {
+                    key: "{asset.serial}",
+                    asset,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        assets.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+            if let Some(Err(e)) = &*assets.read() {
+                div { class: "text-center text-red-500", {format!("Error loading assets: {}", e)} }
+            }
+            div {
+                class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                table {
+                    class: "min-w-full divide-y divide-gray-300",
+                    thead {
+                        class: "bg-gray-50",
+                        tr {
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Serial" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Machine" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Purchased" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Supplier" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Invoice" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Cost" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Warranty" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "AppleCare" }
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
+                        }
+                    }
+                    tbody {
+                        class: "divide-y divide-gray-200 bg-white",
+                        for (serial, asset) in rows.into_iter() {
+                            tr {
+                                key: "{serial}",
+                                td { class: "px-6 py-4", {serial.clone()} }
+                                td { class: "px-6 py-4", {machines.get(&serial).cloned().unwrap_or_default()} }
+                                td { class: "px-6 py-4", {asset.purchase_date.map(|d| d.to_string()).unwrap_or_default()} }
+                                td { class: "px-6 py-4", {asset.supplier.clone().unwrap_or_default()} }
+                                td { class: "px-6 py-4", {asset.invoice_number.clone().unwrap_or_default()} }
+                                td { class: "px-6 py-4", {asset.cost.map(|c| format!("{:.2}", c)).unwrap_or_default()} }
+                                td { class: "px-6 py-4", CoverageCell { expiry: asset.warranty_expiry } }
+                                td { class: "px-6 py-4", CoverageCell { expiry: asset.applecare_expiry } }
+                                td { class: "px-6 py-4",
+                                    button {
+                                        class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600 transition duration-300 ease-in-out",
+                                        onclick: move |_| editing.set(Some(asset.clone())),
+                                        "Edit"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,303 @@
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use crate::server::{
+    delete_entitlement,
+    export_seat_reconciliation,
+    get_seat_reconciliation,
+    list_entitlements,
+    save_entitlement,
+};
+use crate::server::entitlements::{ Entitlement, ReconciliationRow, SeatStatus };
+use crate::server::inventory::ExportFormat;
+use crate::utils::download_file;
+
+fn parse_date_input(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim().to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+fn seat_status_class(status: SeatStatus) -> &'static str {
+    match status {
+        SeatStatus::OverDeployed => "text-red-600",
+        SeatStatus::Unused => "text-yellow-600",
+        SeatStatus::Balanced => "text-green-600",
+    }
+}
+
+fn seat_status_label(row: &ReconciliationRow) -> String {
+    match row.status {
+        SeatStatus::OverDeployed => format!("Over-deployed by {}", row.over_deployed()),
+        SeatStatus::Unused => format!("{} unused", row.unused_seats()),
+        SeatStatus::Balanced => "Balanced".to_string(),
+    }
+}
+
+#[component]
+fn EntitlementForm(entitlement: Entitlement, on_saved: EventHandler<()>) -> Element {
+    let mut draft = use_signal(|| entitlement.clone());
+    let mut error = use_signal(|| None::<String>);
+    let input_class = "mt-1 block w-full rounded border border-gray-300 px-2 py-1";
+    let date_value = |date: Option<NaiveDate>| date.map(|d| d.to_string()).unwrap_or_default();
+
+    let save = move |_| {
+        let entitlement = draft.read().clone();
+        spawn(async move {
+            match save_entitlement(entitlement).await {
+                Ok(_) => {
+                    error.set(None);
+                    draft.set(Entitlement::default());
+                    on_saved.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx!(
+        div {
+            class: "mb-6 p-4 bg-white shadow rounded-lg",
+            div {
+                class: "grid grid-cols-3 gap-4 text-sm",
+                label { "Product"
+                    input { class: input_class, placeholder: "priint:comet", value: draft.read().product.clone(),
+                        oninput: move |evt| draft.with_mut(|e| e.product = evt.value()) }
+                }
+                label { "Vendor"
+                    input { class: input_class, value: draft.read().vendor.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|e| e.vendor = non_empty(evt.value())) }
+                }
+                label { "Seats"
+                    input { class: input_class, r#type: "number", min: "0", value: draft.read().seats.to_string(),
+                        oninput: move |evt| draft.with_mut(|e| e.seats = evt.value().parse().unwrap_or_default()) }
+                }
+                label { "Contract start"
+                    input { class: input_class, r#type: "date", value: date_value(draft.read().contract_start),
+                        oninput: move |evt| draft.with_mut(|e| e.contract_start = parse_date_input(&evt.value())) }
+                }
+                label { "Contract end"
+                    input { class: input_class, r#type: "date", value: date_value(draft.read().contract_end),
+                        oninput: move |evt| draft.with_mut(|e| e.contract_end = parse_date_input(&evt.value())) }
+                }
+                label { "Notes"
+                    input { class: input_class, value: draft.read().notes.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|e| e.notes = non_empty(evt.value())) }
+                }
+                label { class: "col-span-3", "Installed on hosts matching (optional group rule)"
+                    input { class: "{input_class} font-mono", placeholder: "custom.indesign ~ \"2024\"",
+                        value: draft.read().installed_rule.clone().unwrap_or_default(),
+                        oninput: move |evt| draft.with_mut(|e| e.installed_rule = non_empty(evt.value())) }
+                }
+            }
+            if let Some(e) = error.read().as_ref() {
+                div { class: "mt-2 text-red-500 text-sm", {e.clone()} }
+            }
+            button {
+                class: "mt-4 px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600",
+                onclick: save,
+                if entitlement.id.is_empty() { "Add entitlement" } else { "Save" }
+            }
+        }
+    )
+}
+
+#[component]
+fn ReconciliationTable(rows: Vec<ReconciliationRow>) -> Element {
+    let mut format = use_signal(ExportFormat::default);
+    let mut error = use_signal(|| None::<String>);
+
+    let export = move |_| {
+        spawn(async move {
+            let result = match export_seat_reconciliation(format()).await {
+                Ok(file) => download_file(&file.filename, &file.content_type, &file.data),
+                Err(e) => Err(e.to_string()),
+            };
+            error.set(result.err());
+        });
+    };
+
+    rsx!(
+        div {
+            class: "mb-2 flex items-center gap-2 text-sm",
+            h2 { class: "text-lg font-semibold mr-auto", "Seat reconciliation" }
+            select {
+                class: "rounded border border-gray-300 px-2 py-1",
+                onchange: move |evt: Event<FormData>| {
+                    if let Ok(value) = evt.value().parse() {
+                        format.set(value);
+                    }
+                },
+                option { value: "csv", "CSV" }
+                option { value: "json", "JSON" }
+                option { value: "xlsx", "Excel (XLSX)" }
+            }
+            button {
+                class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                onclick: export,
+                "Download"
+            }
+            if let Some(e) = error.read().as_ref() {
+                span { class: "text-red-500", {e.clone()} }
+            }
+        }
+        div {
+            class: "mb-8 overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+            table {
+                class: "min-w-full divide-y divide-gray-300",
+                thead {
+                    class: "bg-gray-50",
+                    tr {
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Product" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Vendor" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Seats" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Installed" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Status" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Contract end" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Hosts" }
+                    }
+                }
+                tbody {
+                    class: "divide-y divide-gray-200 bg-white",
+                    for row in rows.iter() {
+                        tr {
+                            key: "{row.product}",
+                            td { class: "px-6 py-4 font-medium", {row.product.clone()} }
+                            td { class: "px-6 py-4", {row.vendors.join(", ")} }
+                            td { class: "px-6 py-4", {row.seats.to_string()} }
+                            td { class: "px-6 py-4", {row.installed.to_string()} }
+                            td { class: "px-6 py-4 font-medium {seat_status_class(row.status)}", {seat_status_label(row)} }
+                            td { class: "px-6 py-4", {row.contract_end.map(|d| d.to_string()).unwrap_or_default()} }
+                            td { class: "px-6 py-4 text-sm",
+                                if !row.hosts.is_empty() {
+                                    details {
+                                        summary { class: "cursor-pointer", {format!("{} hosts", row.hosts.len())} }
+                                        for host in row.hosts.iter() {
+                                            div { key: "{host}", {host.clone()} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn Entitlements() -> Element {
+    let mut entitlements = use_resource(|| async move { list_entitlements().await });
+    let mut reconciliation = use_resource(|| async move { get_seat_reconciliation().await });
+    let mut editing = use_signal(|| None::<Entitlement>);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut reload = move || {
+        editing.set(None);
+        entitlements.restart();
+        reconciliation.restart();
+    };
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Entitlements" }
+            match editing() {
+                Some(entitlement) => rsx!(
+                    EntitlementForm { key: "{entitlement.id}", entitlement, on_saved: move |_| reload() }
+                ),
+                None => rsx!(
+                    EntitlementForm { entitlement: Entitlement::default(), on_saved: move |_| reload() }
+                ),
+            }
+            if let Some(e) = error.read().as_ref() {
+                div { class: "mb-4 text-red-500 text-sm", {e.clone()} }
+            }
+            match &*reconciliation.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(rows)) => rsx!(ReconciliationTable { rows: rows.clone() }),
+            }
+            h2 { class: "text-lg font-semibold mb-2", "Contracts" }
+            match &*entitlements.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(list)) => rsx!(
+                    div {
+                        class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Product" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Vendor" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Seats" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Contract" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Installed rule" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for entitlement in list.iter() {
+                                    tr {
+                                        key: "{entitlement.id}",
+                                        td { class: "px-6 py-4",
+                                            div { class: "font-medium", {entitlement.product.clone()} }
+                                            if let Some(notes) = entitlement.notes.as_ref() {
+                                                div { class: "text-xs text-gray-500", {notes.clone()} }
+                                            }
+                                        }
+                                        td { class: "px-6 py-4", {entitlement.vendor.clone().unwrap_or_default()} }
+                                        td { class: "px-6 py-4", {entitlement.seats.to_string()} }
+                                        td { class: "px-6 py-4 text-sm",
+                                            {format!(
+                                                "{} – {}",
+                                                entitlement.contract_start.map(|d| d.to_string()).unwrap_or_default(),
+                                                entitlement.contract_end.map(|d| d.to_string()).unwrap_or_default()
+                                            )}
+                                        }
+                                        td { class: "px-6 py-4 font-mono text-sm", {entitlement.installed_rule.clone().unwrap_or_default()} }
+                                        td { class: "px-6 py-4 flex gap-2",
+                                            button {
+                                                class: "px-3 py-1 bg-gray-200 rounded text-sm hover:bg-gray-300",
+                                                onclick: {
+                                                    let entitlement = entitlement.clone();
+                                                    move |_| editing.set(Some(entitlement.clone()))
+                                                },
+                                                "Edit"
+                                            }
+                                            button {
+                                                class: "text-red-500 hover:underline text-sm",
+                                                onclick: {
+                                                    let id = entitlement.id.clone();
+                                                    move |_| {
+                                                        let id = id.clone();
+                                                        spawn(async move {
+                                                            match delete_entitlement(id).await {
+                                                                Ok(_) => {
+                                                                    error.set(None);
+                                                                    reload();
+                                                                }
+                                                                Err(e) => error.set(Some(e.to_string())),
+                                                            }
+                                                        });
+                                                    }
+                                                },
+                                                "Delete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ),
+            }
+        }
+    )
+}
@@ -0,0 +1,288 @@
+use dioxus::prelude::*;
+use crate::server::{
+    check_group_licenses,
+    delete_group,
+    execute_group_command,
+    list_groups,
+    preview_group_rule,
+    save_group,
+};
+use crate::server::groups::{ Group, GroupKind, GroupSummary };
+use crate::server::license::LicenseCheckResult;
+use crate::components::license_status::LicenseStatusText;
+
+#[derive(Clone, PartialEq)]
+enum GroupOutput {
+    Commands(Vec<(String, String, String)>),
+    Licenses(Vec<LicenseCheckResult>),
+    Error(String),
+}
+
+#[component]
+fn NewGroupForm(on_saved: EventHandler<()>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut dynamic = use_signal(|| false);
+    let mut members = use_signal(String::new);
+    let mut rule = use_signal(String::new);
+    let mut preview = use_signal(|| None::<Result<Vec<String>, String>>);
+    let mut error = use_signal(|| None::<String>);
+    let input_class = "rounded border border-gray-300 px-2 py-1";
+
+    let run_preview = move |_| {
+        spawn(async move {
+            let result = preview_group_rule(rule()).await.map_err(|e| e.to_string());
+            preview.set(Some(result));
+        });
+    };
+
+    let save = move |_| {
+        let kind = if dynamic() {
+            GroupKind::Dynamic(rule())
+        } else {
+            GroupKind::Static(
+                members()
+                    .split([',', '\n'])
+                    .map(|m| m.trim().to_string())
+                    .filter(|m| !m.is_empty())
+                    .collect()
+            )
+        };
+        let group = Group { id: String::new(), name: name(), kind };
+        spawn(async move {
+            match save_group(group).await {
+                Ok(_) => {
+                    name.set(String::new());
+                    members.set(String::new());
+                    rule.set(String::new());
+                    preview.set(None);
+                    error.set(None);
+                    on_saved.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx!(
+        div {
+            class: "mb-6 p-4 bg-white shadow rounded-lg text-sm",
+            h2 { class: "text-lg font-semibold mb-2", "New group" }
+            div {
+                class: "flex flex-wrap items-center gap-2",
+                input { class: input_class, placeholder: "Name", value: name(), oninput: move |evt| name.set(evt.value()) }
+                select {
+                    class: input_class,
+                    onchange: move |evt: Event<FormData>| dynamic.set(evt.value() == "dynamic"),
+                    option { value: "static", "Static members" }
+                    option { value: "dynamic", "Dynamic rule" }
+                }
+            }
+            if dynamic() {
+                div {
+                    class: "mt-2 flex items-center gap-2",
+                    input {
+                        class: "{input_class} flex-1 font-mono",
+                        placeholder: "architecture == x86_64 AND version < 14",
+                        value: rule(),
+                        oninput: move |evt| rule.set(evt.value()),
+                    }
+                    button { class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300", onclick: run_preview, "Preview" }
+                }
+                div {
+                    class: "mt-1 text-gray-500",
+                    "Fields are export columns (host, serial, version, architecture, home_users, ...), custom.<field> or tag. Operators: == != < <= > >= ~ (contains), combined with AND, OR, NOT and parentheses."
+                }
+                match &*preview.read() {
+                    Some(Ok(hosts)) => rsx!(div { class: "mt-1", {format!("Matches {} host(s): {}", hosts.len(), hosts.join(", "))} }),
+                    Some(Err(e)) => rsx!(div { class: "mt-1 text-red-500", {e.clone()} }),
+                    None => rsx!(),
+                }
+            } else {
+                textarea {
+                    class: "{input_class} mt-2 w-full",
+                    rows: "3",
+                    placeholder: "vg-ph-beam.local, vg-ph-fon.local",
+                    value: members(),
+                    oninput: move |evt| members.set(evt.value()),
+                }
+            }
+            if let Some(e) = error.read().as_ref() {
+                div { class: "mt-2 text-red-500", {e.clone()} }
+            }
+            button {
+                class: "mt-2 px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                onclick: save,
+                "Add group"
+            }
+        }
+    )
+}
+
+#[component]
+fn GroupRow(summary: GroupSummary, on_deleted: EventHandler<()>) -> Element {
+    let mut output = use_signal(|| None::<GroupOutput>);
+    let mut running = use_signal(|| false);
+    let mut command = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let group_id = summary.group.id.clone();
+
+    let check_licenses = {
+        let group_id = group_id.clone();
+        move |_| {
+            let group_id = group_id.clone();
+            spawn(async move {
+                running.set(true);
+                let result = match check_group_licenses(group_id).await {
+                    Ok(results) => GroupOutput::Licenses(results),
+                    Err(e) => GroupOutput::Error(e.to_string()),
+                };
+                output.set(Some(result));
+                running.set(false);
+            });
+        }
+    };
+
+    let run_command = {
+        let group_id = group_id.clone();
+        move |_| {
+            let group_id = group_id.clone();
+            spawn(async move {
+                running.set(true);
+                let result = match execute_group_command(group_id, command(), password()).await {
+                    Ok(results) => GroupOutput::Commands(results),
+                    Err(e) => GroupOutput::Error(e.to_string()),
+                };
+                output.set(Some(result));
+                running.set(false);
+            });
+        }
+    };
+
+    let delete = move |_| {
+        let group_id = group_id.clone();
+        spawn(async move {
+            match delete_group(group_id).await {
+                Ok(_) => on_deleted.call(()),
+                Err(e) => output.set(Some(GroupOutput::Error(e.to_string()))),
+            }
+        });
+    };
+
+    rsx!(
+        div {
+            class: "mb-4 p-4 bg-white shadow rounded-lg text-sm",
+            div {
+                class: "flex items-center justify-between",
+                div {
+                    span { class: "text-lg font-semibold", {summary.group.name.clone()} }
+                    span { class: "ml-2 text-gray-500",
+                        match &summary.group.kind {
+                            GroupKind::Static(_) => rsx!("Static"),
+                            GroupKind::Dynamic(rule) => rsx!(span { class: "font-mono", {format!("Dynamic: {}", rule)} }),
+                        }
+                    }
+                }
+                button { class: "text-red-500 hover:underline", onclick: delete, "Delete" }
+            }
+            div {
+                class: "mt-1 text-gray-500",
+                {format!("{} host(s): {}", summary.members.len(), summary.members.join(", "))}
+            }
+            div {
+                class: "mt-2 flex flex-wrap items-center gap-2",
+                button {
+                    class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300",
+                    disabled: running(),
+                    onclick: check_licenses,
+                    "Check licenses"
+                }
+                input {
+                    class: "rounded border border-gray-300 px-2 py-1 font-mono",
+                    placeholder: "Command",
+                    value: command(),
+                    oninput: move |evt| command.set(evt.value()),
+                }
+                input {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    r#type: "password",
+                    placeholder: "Password",
+                    value: password(),
+                    oninput: move |evt| password.set(evt.value()),
+                }
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                    disabled: running() || command().is_empty(),
+                    onclick: run_command,
+                    if running() { "Running..." } else { "Run on group" }
+                }
+            }
+            match &*output.read() {
+                Some(GroupOutput::Licenses(results)) => rsx!(
+                    ul {
+                        class: "mt-2",
+                        for result in results.iter() {
+                            li {
+                                key: "{result.host}",
+                                class: "flex gap-2",
+                                span { class: "font-medium", {result.host.clone()} }
+                                LicenseStatusText {
+                                    status: result.status,
+                                    expiry: result.expiry,
+                                    days_remaining: result.days_remaining,
+                                    detail: result.error.clone(),
+                                }
+                            }
+                        }
+                    }
+                ),
+                Some(GroupOutput::Commands(results)) => rsx!(
+                    div {
+                        class: "mt-2",
+                        for (host, stdout, stderr) in results.iter() {
+                            div {
+                                key: "{host}",
+                                class: "mb-2",
+                                div { class: "font-medium", {host.clone()} }
+                                if !stdout.is_empty() {
+                                    pre { class: "bg-gray-50 p-2 rounded whitespace-pre-wrap", {stdout.clone()} }
+                                }
+                                if !stderr.is_empty() {
+                                    pre { class: "bg-red-50 text-red-600 p-2 rounded whitespace-pre-wrap", {stderr.clone()} }
+                                }
+                            }
+                        }
+                    }
+                ),
+                Some(GroupOutput::Error(e)) => rsx!(div { class: "mt-2 text-red-500", {e.clone()} }),
+                None => rsx!(),
+            }
+        }
+    )
+}
+
+#[component]
+pub fn Groups() -> Element {
+    let mut groups = use_resource(|| async move { list_groups().await });
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Groups" }
+            NewGroupForm { on_saved: move |_| groups.restart() }
+            match &*groups.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(list)) if list.is_empty() => rsx!(div { class: "text-center text-gray-500", "No groups yet." }),
+                Some(Ok(list)) => rsx!(
+                    for summary in list.iter() {
+                        GroupRow {
+                            key: "{summary.group.id}",
+                            summary: summary.clone(),
+                            on_deleted: move |_| groups.restart(),
+                        }
+                    }
+                ),
+            }
+        }
+    )
+}
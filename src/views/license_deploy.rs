@@ -0,0 +1,291 @@
+use chrono::Local;
+use dioxus::prelude::*;
+use crate::server::{
+    deploy_license_file,
+    get_license_products,
+    list_license_deployments,
+    preview_license_file,
+    rollback_license_deployment,
+};
+use crate::server::license::{
+    DeploymentStatus,
+    HostDeployment,
+    LicenseDeployRequest,
+    LicenseDeployment,
+    LicenseTarget,
+    ProductLicense,
+};
+use crate::components::group_select::GroupSelect;
+use crate::components::license_status::{ describe_status, status_class };
+use crate::Route;
+
+fn deployment_label(status: DeploymentStatus) -> &'static str {
+    match status {
+        DeploymentStatus::Pending => "Pending",
+        DeploymentStatus::Deployed => "Deployed",
+        DeploymentStatus::NoTarget => "No license directory",
+        DeploymentStatus::Failed => "Failed",
+        DeploymentStatus::RolledBack => "Rolled back",
+        DeploymentStatus::RollbackFailed => "Rollback failed",
+    }
+}
+
+fn deployment_class(status: DeploymentStatus) -> &'static str {
+    match status {
+        DeploymentStatus::Deployed | DeploymentStatus::RolledBack => "text-green-600",
+        DeploymentStatus::Pending | DeploymentStatus::NoTarget => "text-gray-500",
+        _ => "text-red-500",
+    }
+}
+
+#[component]
+fn HostResults(hosts: Vec<HostDeployment>) -> Element {
+    rsx!(
+        table {
+            class: "min-w-full divide-y divide-gray-300",
+            thead {
+                class: "bg-gray-50",
+                tr {
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Host" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Result" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Files" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "License check" }
+                }
+            }
+            tbody {
+                class: "divide-y divide-gray-200 bg-white",
+                for host in hosts {
+                    tr {
+                        key: "{host.host}",
+                        td { class: "px-6 py-4", {host.host.clone()} }
+                        td { class: "px-6 py-4 font-medium {deployment_class(host.status)}",
+                            title: "{host.message.clone().unwrap_or_default()}",
+                            {deployment_label(host.status)}
+                            if let Some(message) = host.message.as_ref() {
+                                div { class: "text-xs font-normal text-gray-500", {message.clone()} }
+                            }
+                        }
+                        td { class: "px-6 py-4 text-xs text-gray-500",
+                            for file in host.files.iter() {
+                                div { title: "{file.backup.clone().unwrap_or_default()}", {file.path.clone()} }
+                            }
+                        }
+                        td { class: "px-6 py-4 text-sm",
+                            if let Some(check) = host.check.as_ref() {
+                                span { class: "{status_class(check.status)}",
+                                    {describe_status(check.status, check.expiry, check.days_remaining)}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn Preview(license: ProductLicense) -> Element {
+    rsx!(
+        div { class: "text-sm {status_class(license.status)}",
+            {describe_status(license.status, license.expiry, license.days_remaining)}
+            if let Some(matched) = license.matched.as_ref() {
+                span { class: "ml-2 text-gray-500",
+                    match license.line {
+                        Some(line) => format!("(line {}: \"{}\")", line, matched),
+                        None => format!("(from \"{}\")", matched),
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn LicenseDeploy() -> Element {
+    let products = use_resource(|| async move { get_license_products().await.unwrap_or_default() });
+    let mut deployments = use_resource(|| async move { list_license_deployments().await });
+    let mut product = use_signal(String::new);
+    let mut file = use_signal(|| None::<(String, String)>);
+    let mut preview = use_signal(|| None::<Result<ProductLicense, String>>);
+    let mut host = use_signal(String::new);
+    let mut group = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<LicenseDeployment, String>>);
+
+    // Defaults to the first product once the rules have loaded
+    let selected = if product().is_empty() {
+        products.read().as_ref().and_then(|p| p.first().cloned()).unwrap_or_default()
+    } else {
+        product()
+    };
+
+    let mut refresh_preview = move |product: String| {
+        let Some((_, content)) = file() else {
+            preview.set(None);
+            return;
+        };
+        spawn(async move {
+            preview.set(Some(preview_license_file(product, content).await.map_err(|e| e.to_string())));
+        });
+    };
+
+    let choose_file = {
+        let selected = selected.clone();
+        move |evt: Event<FormData>| {
+            let selected = selected.clone();
+            async move {
+                let Some(files) = evt.files() else {
+                    return;
+                };
+                let Some(name) = files.files().into_iter().next() else {
+                    return;
+                };
+                match files.read_file_to_string(&name).await {
+                    Some(content) => {
+                        file.set(Some((name, content)));
+                        refresh_preview(selected);
+                    }
+                    None => preview.set(Some(Err(format!("Could not read {}", name)))),
+                }
+            }
+        }
+    };
+
+    let deploy = {
+        let selected = selected.clone();
+        move |_| {
+            let Some((filename, content)) = file() else {
+                return;
+            };
+            let target = match (host().trim(), group()) {
+                (host, _) if !host.is_empty() => LicenseTarget::Host(host.to_string()),
+                (_, Some(id)) => LicenseTarget::Group(id),
+                _ => LicenseTarget::All,
+            };
+            let request = LicenseDeployRequest { product: selected.clone(), filename, content, target };
+            busy.set(true);
+            spawn(async move {
+                result.set(Some(deploy_license_file(request).await.map_err(|e| e.to_string())));
+                deployments.restart();
+                busy.set(false);
+            });
+        }
+    };
+
+    let mut rollback = move |id: String| {
+        busy.set(true);
+        spawn(async move {
+            result.set(Some(rollback_license_deployment(id).await.map_err(|e| e.to_string())));
+            deployments.restart();
+            busy.set(false);
+        });
+    };
+
+    let previewed = matches!(&*preview.read(), Some(Ok(license)) if !license.status.is_failure());
+    let target_label = match (host().trim(), group()) {
+        (host, _) if !host.is_empty() => format!("Deploy to {}", host),
+        (_, Some(_)) => "Deploy to group".to_string(),
+        _ => "Deploy to all hosts".to_string(),
+    };
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            Link { class: "text-sm text-blue-500", to: Route::Licenses, "← Licenses" }
+            h1 { class: "text-2xl font-bold mb-4", "Deploy license file" }
+            div {
+                class: "mb-4 flex flex-col gap-3 text-sm",
+                div {
+                    class: "flex flex-wrap items-center gap-2",
+                    select {
+                        class: "rounded border border-gray-300 px-2 py-1",
+                        onchange: move |evt: Event<FormData>| {
+                            product.set(evt.value());
+                            refresh_preview(evt.value());
+                        },
+                        for name in products.read().iter().flatten() {
+                            option { key: "{name}", value: "{name}", selected: *name == selected, {name.clone()} }
+                        }
+                    }
+                    input { r#type: "file", onchange: choose_file }
+                }
+                match &*preview.read() {
+                    Some(Ok(license)) => rsx!(Preview { license: license.clone() }),
+                    Some(Err(e)) => rsx!(div { class: "text-red-500", {e.clone()} }),
+                    None => rsx!(div { class: "text-gray-500", "Choose the renewed license file to preview its expiry." }),
+                }
+                div {
+                    class: "flex flex-wrap items-center gap-2",
+                    input {
+                        class: "rounded border border-gray-300 px-2 py-1",
+                        placeholder: "Single host (optional)",
+                        value: host(),
+                        oninput: move |evt| host.set(evt.value()),
+                    }
+                    GroupSelect { on_change: move |value| group.set(value) }
+                    button {
+                        class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600 disabled:opacity-50",
+                        disabled: busy() || !previewed,
+                        onclick: deploy,
+                        if busy() { "Working..." } else { {target_label} }
+                    }
+                }
+                div {
+                    class: "text-gray-500",
+                    "Existing files are backed up next to the new one and keep their owner and mode. Each host's license is re-checked afterwards."
+                }
+            }
+            match &*result.read() {
+                Some(Ok(deployment)) => rsx!(
+                    div {
+                        class: "mb-6 overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        HostResults { hosts: deployment.hosts.clone() }
+                    }
+                ),
+                Some(Err(e)) => rsx!(div { class: "mb-6 text-red-500", {e.clone()} }),
+                None => rsx!(),
+            }
+            h2 { class: "text-xl font-semibold mb-2", "Past deployments" }
+            match &*deployments.read() {
+                None => rsx!(div { class: "text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-red-500", {e.to_string()} }),
+                Some(Ok(list)) if list.is_empty() => rsx!(div { class: "text-gray-500", "No deployments yet." }),
+                Some(Ok(list)) => rsx!(
+                    for deployment in list.iter() {
+                        div {
+                            key: "{deployment.id}",
+                            class: "mb-4 rounded border border-gray-200 p-3",
+                            div {
+                                class: "mb-2 flex flex-wrap items-center gap-3 text-sm",
+                                span { class: "font-medium", {format!("{} — {}", deployment.product, deployment.filename)} }
+                                span { class: "text-gray-500",
+                                    {deployment.created_at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()}
+                                }
+                                if let Some(expiry) = deployment.expiry {
+                                    span { {format!("Expires {}", expiry)} }
+                                }
+                                if let Some(at) = deployment.rolled_back_at {
+                                    span { class: "text-gray-500",
+                                        {format!("Rolled back {}", at.with_timezone(&Local).format("%Y-%m-%d %H:%M"))}
+                                    }
+                                } else {
+                                    button {
+                                        class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300",
+                                        disabled: busy(),
+                                        onclick: {
+                                            let id = deployment.id.clone();
+                                            move |_| rollback(id.clone())
+                                        },
+                                        "Rollback"
+                                    }
+                                }
+                            }
+                            HostResults { hosts: deployment.hosts.clone() }
+                        }
+                    }
+                ),
+            }
+        }
+    )
+}
@@ -0,0 +1,115 @@
+use chrono::{ DateTime, Local, Utc };
+use dioxus::prelude::*;
+use crate::server::get_license_timeline;
+use crate::server::license::{ TimelineEvent, TimelineKind };
+use crate::components::license_status::{ status_class, status_label };
+use crate::Route;
+
+fn local_time(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+fn describe_event(event: &TimelineEvent) -> String {
+    let expiry = |date: Option<chrono::NaiveDate>| date.map(|d| d.to_string()).unwrap_or_else(|| "no expiry".to_string());
+    match event.kind {
+        TimelineKind::FirstSeen => format!("Found, {}", expiry(event.expiry)),
+        TimelineKind::Renewed => format!("Renewed: {} → {}", expiry(event.previous_expiry), expiry(event.expiry)),
+        TimelineKind::StatusChanged =>
+            format!(
+                "{} → {}",
+                event.previous_status.map(status_label).unwrap_or_default(),
+                status_label(event.status)
+            ),
+        TimelineKind::Disappeared => "License file no longer found".to_string(),
+        TimelineKind::StoppedReporting => "Host stopped reporting".to_string(),
+        TimelineKind::ResumedReporting => "Host reporting again".to_string(),
+    }
+}
+
+fn event_class(event: &TimelineEvent) -> &'static str {
+    match event.kind {
+        TimelineKind::Renewed | TimelineKind::ResumedReporting => "text-green-600",
+        TimelineKind::StoppedReporting | TimelineKind::Disappeared => "text-red-500",
+        _ => status_class(event.status),
+    }
+}
+
+#[component]
+pub fn LicenseHost(host: String) -> Element {
+    let timeline = use_resource(use_reactive!(|host| async move { get_license_timeline(host).await }));
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            Link { class: "text-sm text-blue-500", to: Route::Licenses, "← Licenses" }
+            h1 { class: "text-2xl font-bold mb-4", {format!("License history: {}", host)} }
+            match &*timeline.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(timeline)) if timeline.checks == 0 => rsx!(
+                    div { class: "text-center text-gray-500", "No license checks recorded for this host." }
+                ),
+                Some(Ok(timeline)) => rsx!(
+                    div {
+                        class: "mb-4 flex flex-wrap gap-6 text-sm",
+                        div { {format!("{} checks", timeline.checks)} }
+                        if let Some(at) = timeline.last_checked {
+                            div { {format!("Last checked {}", local_time(at))} }
+                        }
+                        div {
+                            class: if timeline.last_reported == timeline.last_checked { "" } else { "text-red-500" },
+                            match timeline.last_reported {
+                                Some(at) => format!("Last reported {}", local_time(at)),
+                                None => "Never reported".to_string(),
+                            }
+                        }
+                    }
+                    for unstable in timeline.unstable.iter() {
+                        div {
+                            class: "mb-2 rounded bg-yellow-50 px-3 py-2 text-sm text-yellow-800",
+                            {format!(
+                                "{} switched between readable and unreadable {} times{}",
+                                unstable.product,
+                                unstable.changes,
+                                unstable.path.as_ref().map(|p| format!(" ({})", p)).unwrap_or_default()
+                            )}
+                        }
+                    }
+                    div {
+                        class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "When" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Product" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Change" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for (idx, event) in timeline.events.iter().enumerate() {
+                                    tr {
+                                        key: "{idx}",
+                                        td { class: "px-6 py-4 text-sm text-gray-500", {local_time(event.at)} }
+                                        td { class: "px-6 py-4",
+                                            div { {event.product.clone()} }
+                                            if let Some(path) = event.path.as_ref() {
+                                                div { class: "text-xs text-gray-500", {path.clone()} }
+                                            }
+                                        }
+                                        td { class: "px-6 py-4 {event_class(event)}",
+                                            title: "{event.message.clone().unwrap_or_default()}",
+                                            {describe_event(event)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ),
+            }
+        }
+    )
+}
@@ -0,0 +1,235 @@
+use std::cmp::Reverse;
+use chrono::Local;
+use dioxus::prelude::*;
+use crate::server::{ get_license_dashboard, recheck_licenses };
+use crate::server::license::{ LicenseRow, LicenseStatus, LicenseTarget };
+use crate::components::calendar_panel::CalendarPanel;
+use crate::components::group_select::GroupSelect;
+use crate::components::license_status::{ status_class, status_label };
+use crate::Route;
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortKey {
+    Host,
+    Product,
+    Status,
+    Expiry,
+}
+
+const STATUSES: [LicenseStatus; 8] = [
+    LicenseStatus::Expired,
+    LicenseStatus::Warning,
+    LicenseStatus::Notice,
+    LicenseStatus::Valid,
+    LicenseStatus::HostUnreachable,
+    LicenseStatus::Unreadable,
+    LicenseStatus::ParseError,
+    LicenseStatus::NotFound,
+];
+
+fn sort_rows(rows: &mut [LicenseRow], key: SortKey, ascending: bool) {
+    match key {
+        SortKey::Host => rows.sort_by(|a, b| a.host.cmp(&b.host).then(a.product.cmp(&b.product))),
+        SortKey::Product => rows.sort_by(|a, b| a.product.cmp(&b.product).then(a.host.cmp(&b.host))),
+        SortKey::Status => rows.sort_by_key(|r| (Reverse(r.status.severity()), r.days_remaining)),
+        // Rows without an expiry date go last
+        SortKey::Expiry => rows.sort_by_key(|r| (r.days_remaining.is_none(), r.days_remaining)),
+    }
+    if !ascending {
+        rows.reverse();
+    }
+}
+
+fn countdown(row: &LicenseRow) -> String {
+    match row.days_remaining {
+        Some(days) if days < 0 => format!("{} days overdue", -days),
+        Some(days) => format!("{} days", days),
+        None => "—".to_string(),
+    }
+}
+
+#[component]
+fn SortHeader(label: &'static str, column: SortKey, sort: Signal<(SortKey, bool)>) -> Element {
+    let (current, ascending) = sort();
+    let arrow = match (current == column, ascending) {
+        (true, true) => " ▲",
+        (true, false) => " ▼",
+        _ => "",
+    };
+
+    rsx!(
+        th {
+            class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900 cursor-pointer select-none",
+            onclick: move |_| sort.set((column, if current == column { !ascending } else { true })),
+            {format!("{}{}", label, arrow)}
+        }
+    )
+}
+
+#[component]
+pub fn Licenses() -> Element {
+    let mut group = use_signal(|| None::<String>);
+    let mut rows = use_resource(move || async move { get_license_dashboard(group()).await });
+    let mut filter = use_signal(String::new);
+    let mut status_filter = use_signal(String::new);
+    let sort = use_signal(|| (SortKey::Status, true));
+    let mut checking = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut recheck = move |target: LicenseTarget, label: String| {
+        checking.set(Some(label));
+        spawn(async move {
+            match recheck_licenses(target).await {
+                Ok(_) => {
+                    error.set(None);
+                    rows.restart();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            checking.set(None);
+        });
+    };
+
+    let visible: Vec<LicenseRow> = match &*rows.read() {
+        Some(Ok(all)) => {
+            let text = filter().to_lowercase();
+            let status = status_filter();
+            let mut visible: Vec<LicenseRow> = all
+                .iter()
+                .filter(|r| text.is_empty() || r.host.to_lowercase().contains(&text) || r.product.to_lowercase().contains(&text))
+                .filter(|r| match status.as_str() {
+                    "" => true,
+                    "attention" => matches!(r.status, LicenseStatus::Expired | LicenseStatus::Warning | LicenseStatus::Notice),
+                    other => format!("{:?}", r.status) == other,
+                })
+                .cloned()
+                .collect();
+            let (key, ascending) = sort();
+            sort_rows(&mut visible, key, ascending);
+            visible
+        }
+        _ => Vec::new(),
+    };
+    let busy = checking.read().is_some();
+    let recheck_label = if group().is_some() { "Re-check group" } else { "Re-check all" };
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Licenses" }
+            div {
+                class: "mb-4 flex flex-wrap items-center gap-2 text-sm",
+                GroupSelect { on_change: move |value| group.set(value) }
+                input {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    placeholder: "Filter by host or product",
+                    value: filter(),
+                    oninput: move |evt| filter.set(evt.value()),
+                }
+                select {
+                    class: "rounded border border-gray-300 px-2 py-1",
+                    onchange: move |evt: Event<FormData>| status_filter.set(evt.value()),
+                    option { value: "", "All statuses" }
+                    option { value: "attention", "Needs attention" }
+                    for status in STATUSES {
+                        option { value: "{status:?}", {status_label(status)} }
+                    }
+                }
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                    disabled: busy,
+                    onclick: move |_| {
+                        let target = match group() {
+                            Some(id) => LicenseTarget::Group(id),
+                            None => LicenseTarget::All,
+                        };
+                        recheck(target, "all".to_string());
+                    },
+                    if busy { "Checking..." } else { {recheck_label} }
+                }
+                Link {
+                    class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300",
+                    to: Route::LicenseDeploy,
+                    "Deploy license file"
+                }
+                if let Some(e) = error.read().as_ref() {
+                    span { class: "text-red-500", {e.clone()} }
+                }
+            }
+            match &*rows.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(all)) if all.is_empty() => rsx!(
+                    div { class: "text-center text-gray-500", "No license checks yet. Use Re-check all to run one." }
+                ),
+                Some(Ok(_)) => rsx!(
+                    div {
+                        class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    SortHeader { label: "Host", column: SortKey::Host, sort }
+                                    SortHeader { label: "Product", column: SortKey::Product, sort }
+                                    SortHeader { label: "Status", column: SortKey::Status, sort }
+                                    SortHeader { label: "Expiry", column: SortKey::Expiry, sort }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Countdown" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Checked" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for (idx, row) in visible.iter().enumerate() {
+                                    tr {
+                                        key: "{idx}-{row.host}",
+                                        td { class: "px-6 py-4",
+                                            Link {
+                                                class: "text-blue-500 hover:underline",
+                                                to: Route::LicenseHost { host: row.host.clone() },
+                                                {row.host.clone()}
+                                            }
+                                        }
+                                        td { class: "px-6 py-4",
+                                            div { {row.product.clone()} }
+                                            if let Some(path) = row.path.as_ref() {
+                                                div { class: "text-xs text-gray-500",
+                                                    match row.line {
+                                                        Some(line) => format!("{}:{}", path, line),
+                                                        None => path.clone(),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        td { class: "px-6 py-4 font-medium {status_class(row.status)}",
+                                            title: "{row.message.clone().unwrap_or_default()}",
+                                            {status_label(row.status)}
+                                        }
+                                        td { class: "px-6 py-4", {row.expiry.map(|d| d.to_string()).unwrap_or_default()} }
+                                        td { class: "px-6 py-4 {status_class(row.status)}", {countdown(row)} }
+                                        td { class: "px-6 py-4 text-sm text-gray-500",
+                                            {row.checked_at.map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()).unwrap_or_default()}
+                                        }
+                                        td { class: "px-6 py-4",
+                                            button {
+                                                class: "px-3 py-1 bg-gray-200 rounded text-sm hover:bg-gray-300",
+                                                disabled: busy,
+                                                onclick: {
+                                                    let host = row.host.clone();
+                                                    move |_| recheck(LicenseTarget::Host(host.clone()), host.clone())
+                                                },
+                                                if checking.read().as_deref() == Some(row.host.as_str()) { "Checking..." } else { "Re-check" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ),
+            }
+            CalendarPanel {}
+        }
+    )
+}
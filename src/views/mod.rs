@@ -1,7 +1,23 @@
 mod home;
 mod user;
 mod user_list;
+mod updates;
+mod assets;
+mod people;
+mod groups;
+mod licenses;
+mod license_deploy;
+mod license_host;
+mod entitlements;
 
 pub use home::Home;
 pub use user::User;
 pub use user_list::UserList;
+pub use updates::Updates;
+pub use assets::Assets;
+pub use people::People;
+pub use groups::Groups;
+pub use licenses::Licenses;
+pub use license_deploy::LicenseDeploy;
+pub use license_host::LicenseHost;
+pub use entitlements::Entitlements;
@@ -0,0 +1,239 @@
+use dioxus::prelude::*;
+use crate::Route;
+use crate::server::{
+    delete_location,
+    delete_person,
+    get_active_assignments,
+    list_locations,
+    list_people,
+    save_location,
+    save_person,
+};
+use crate::server::people::{ Location, Person };
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim().to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+#[component]
+pub fn PersonForm(person: Person, locations: Vec<Location>, on_save: EventHandler<Person>) -> Element {
+    let mut draft = use_signal(|| person.clone());
+    let input_class = "rounded border border-gray-300 px-2 py-1";
+
+    rsx!(
+        div {
+            class: "mb-6 flex flex-wrap items-center gap-2 text-sm",
+            input { class: input_class, placeholder: "Name", value: draft.read().name.clone(),
+                oninput: move |evt| draft.with_mut(|p| p.name = evt.value()) }
+            input { class: input_class, placeholder: "Email", value: draft.read().email.clone().unwrap_or_default(),
+                oninput: move |evt| draft.with_mut(|p| p.email = non_empty(evt.value())) }
+            input { class: input_class, placeholder: "Department", value: draft.read().department.clone().unwrap_or_default(),
+                oninput: move |evt| draft.with_mut(|p| p.department = non_empty(evt.value())) }
+            select {
+                class: input_class,
+                onchange: move |evt: Event<FormData>| draft.with_mut(|p| p.location_id = non_empty(evt.value())),
+                option { value: "", selected: draft.read().location_id.is_none(), "No location" }
+                for location in locations.iter() {
+                    option {
+                        key: "{location.id}",
+                        value: "{location.id}",
+                        selected: draft.read().location_id.as_deref() == Some(location.id.as_str()),
+                        {location.name.clone()}
+                    }
+                }
+            }
+            button {
+                class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600",
+                onclick: move |_| {
+                    on_save.call(draft.read().clone());
+                    if draft.read().id.is_empty() {
+                        draft.set(Person::default());
+                    }
+                },
+                if person.id.is_empty() { "Add person" } else { "Save" }
+            }
+        }
+    )
+}
+
+#[component]
+fn Locations(locations: Vec<Location>, on_change: EventHandler<()>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let add = move |_| {
+        let location = Location {
+            id: String::new(),
+            name: name.read().clone(),
+            description: non_empty(description.read().clone()),
+        };
+        spawn(async move {
+            match save_location(location).await {
+                Ok(_) => {
+                    name.set(String::new());
+                    description.set(String::new());
+                    error.set(None);
+                    on_change.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx!(
+        h2 { class: "text-lg font-semibold mb-2", "Locations" }
+        div {
+            class: "mb-4 flex items-center gap-2 text-sm",
+            input { class: "rounded border border-gray-300 px-2 py-1", placeholder: "Name", value: name.read().clone(),
+                oninput: move |evt| name.set(evt.value()) }
+            input { class: "rounded border border-gray-300 px-2 py-1", placeholder: "Description", value: description.read().clone(),
+                oninput: move |evt| description.set(evt.value()) }
+            button {
+                class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600",
+                onclick: add,
+                "Add location"
+            }
+            if let Some(e) = error.read().as_ref() {
+                span { class: "text-red-500", {e.clone()} }
+            }
+        }
+        ul {
+            class: "mb-8 text-sm",
+            for location in locations.iter() {
+                li {
+                    key: "{location.id}",
+                    class: "py-1 flex items-center gap-2",
+                    span { class: "font-medium", {location.name.clone()} }
+                    span { class: "text-gray-500", {location.description.clone().unwrap_or_default()} }
+                    button {
+                        class: "text-red-500 hover:underline",
+                        onclick: {
+                            let id = location.id.clone();
+                            move |_| {
+                                let id = id.clone();
+                                spawn(async move {
+                                    match delete_location(id).await {
+                                        Ok(_) => on_change.call(()),
+                                        Err(e) => error.set(Some(e.to_string())),
+                                    }
+                                });
+                            }
+                        },
+                        "Delete"
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn People() -> Element {
+    let mut people = use_resource(|| async move { list_people().await });
+    let mut locations = use_resource(|| async move { list_locations().await.unwrap_or_default() });
+    let mut assignments = use_resource(|| async move { get_active_assignments().await.unwrap_or_default() });
+    let mut error = use_signal(|| None::<String>);
+
+    let location_list = locations.read().clone().unwrap_or_default();
+    let location_name = |id: &Option<String>| {
+        id.as_ref()
+            .and_then(|id| location_list.iter().find(|l| &l.id == id))
+            .map(|l| l.name.clone())
+            .unwrap_or_default()
+    };
+    let machine_count = |id: &str| {
+        assignments
+            .read()
+            .iter()
+            .flatten()
+            .filter(|a| a.person_id.as_deref() == Some(id))
+            .count()
+    };
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "People" }
+            PersonForm {
+                person: Person::default(),
+                locations: location_list.clone(),
+                on_save: move |person: Person| {
+                    spawn(async move {
+                        match save_person(person).await {
+                            Ok(_) => {
+                                error.set(None);
+                                people.restart();
+                            }
+                            Err(e) => error.set(Some(e.to_string())),
+                        }
+                    });
+                },
+            }
+            if let Some(e) = error.read().as_ref() {
+                div { class: "mb-4 text-red-500 text-sm", {e.clone()} }
+            }
+            match &*people.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(div { class: "text-center text-red-500", {e.to_string()} }),
+                Some(Ok(list)) => rsx!(
+                    div {
+                        class: "mb-8 overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Name" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Email" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Department" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Location" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Machines" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for person in list.iter() {
+                                    tr {
+                                        key: "{person.id}",
+                                        td { class: "px-6 py-4",
+                                            Link { class: "text-blue-500 hover:underline", to: Route::User { id: person.id.clone() }, {person.name.clone()} }
+                                        }
+                                        td { class: "px-6 py-4", {person.email.clone().unwrap_or_default()} }
+                                        td { class: "px-6 py-4", {person.department.clone().unwrap_or_default()} }
+                                        td { class: "px-6 py-4", {location_name(&person.location_id)} }
+                                        td { class: "px-6 py-4", {machine_count(&person.id).to_string()} }
+                                        td { class: "px-6 py-4",
+                                            button {
+                                                class: "text-red-500 text-sm hover:underline",
+                                                onclick: {
+                                                    let id = person.id.clone();
+                                                    move |_| {
+                                                        let id = id.clone();
+                                                        spawn(async move {
+                                                            match delete_person(id).await {
+                                                                Ok(_) => {
+                                                                    people.restart();
+                                                                    assignments.restart();
+                                                                }
+                                                                Err(e) => error.set(Some(e.to_string())),
+                                                            }
+                                                        });
+                                                    }
+                                                },
+                                                "Delete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ),
+            }
+            Locations { locations: location_list.clone(), on_change: move |_| locations.restart() }
+        }
+    )
+}
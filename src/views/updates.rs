@@ -0,0 +1,75 @@
+use dioxus::prelude::*;
+use crate::server::get_pending_updates_report;
+use crate::components::group_select::GroupSelect;
+
+#[component]
+pub fn Updates() -> Element {
+    let mut group = use_signal(|| None::<String>);
+    let report = use_resource(move || async move { get_pending_updates_report(group()).await });
+
+    rsx!(
+        div {
+            class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Pending Software Updates" }
+            div {
+                class: "mb-4 text-sm",
+                GroupSelect { on_change: move |value| group.set(value) }
+            }
+            match &*report.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(
+                    div {
+                        class: "text-center text-red-500",
+                        div { "Error loading update report" }
+                        div { {e.to_string()} }
+                    }
+                ),
+                Some(Ok(groups)) if groups.is_empty() => rsx!(
+                    div { class: "text-center text-gray-500", "All resolved machines are up to date." }
+                ),
+                Some(Ok(groups)) => rsx!(
+                    div {
+                        class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Update" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Version" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Flags" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Machines" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for group in groups.iter() {
+                                    tr {
+                                        key: "{group.label}",
+                                        td { class: "px-6 py-4",
+                                            div { class: "font-medium", {group.title.clone()} }
+                                            div { class: "text-sm text-gray-500", {group.label.clone()} }
+                                        }
+                                        td { class: "px-6 py-4", {group.version.clone()} }
+                                        td { class: "px-6 py-4",
+                                            if group.recommended {
+                                                span { class: "mr-2 text-yellow-600 font-medium", "Recommended" }
+                                            }
+                                            if group.restart_required {
+                                                span { class: "text-red-500 font-medium", "Restart" }
+                                            }
+                                        }
+                                        td { class: "px-6 py-4",
+                                            div { class: "font-medium", {format!("{} behind", group.hosts.len())} }
+                                            div { class: "text-sm text-gray-500", {group.hosts.join(", ")} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ),
+            }
+        }
+    )
+}
@@ -1,177 +1,295 @@
-use dioxus::events::FormData;
+use std::collections::HashMap;
 use dioxus::prelude::*;
-use serde::{ Deserialize, Serialize };
 use crate::Route;
+use crate::server::{
+    check_expired_adobe_plugin_comet_license,
+    check_in_machine,
+    check_out_machine,
+    get_inventory_records,
+    get_person_overview,
+    list_locations,
+    save_person,
+};
+use crate::server::collectors::AdobeInfo;
+use crate::server::license::LicenseCheckResult;
+use crate::components::license_status::{ describe_status, status_class, LicenseStatusText };
+use crate::server::people::{ Assignment, Person };
+use super::people::PersonForm;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SystemInfo {
-    device_type: String,
-    model: String,
-    processor: String,
-    mac_os: String,
-    graphic: String,
-    user: String,
-    ip_ether: String,
-    ip_wifi: String,
-    wifi_ssid: String,
+// "InDesign 2024 19.5, Comet 4.3.1" for the apps the license checks care about
+fn adobe_summary(adobe: &AdobeInfo) -> String {
+    let indesign = adobe.apps
+        .iter()
+        .filter(|a| a.product() == "Adobe InDesign")
+        .map(|a| format!("{} {}", a.name.trim_start_matches("Adobe "), a.version));
+    let comet = adobe.comet_plugins.iter().map(|p| format!("Comet {}", p.version));
+    indesign.chain(comet).collect::<Vec<_>>().join(", ")
 }
 
-impl SystemInfo {
-    fn new(
-        device_type: String,
-        model: String,
-        processor: String,
-        mac_os: String,
-        graphic: String,
-        user: String,
-        ip_ether: String,
-        ip_wifi: String,
-        wifi_ssid: String
-    ) -> Self {
-        Self {
-            device_type,
-            model,
-            processor,
-            mac_os,
-            graphic,
-            user,
-            ip_ether,
-            ip_wifi,
-            wifi_ssid,
-        }
-    }
+fn format_time(time: &chrono::DateTime<chrono::Utc>) -> String {
+    time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[component]
+fn LicenseStatus(host: String) -> Element {
+    let mut result = use_signal(|| None::<Result<LicenseCheckResult, String>>);
+    let mut checking = use_signal(|| false);
+
+    let check = move |_| {
+        let host = host.clone();
+        spawn(async move {
+            checking.set(true);
+            let checked = check_expired_adobe_plugin_comet_license(host).await.map_err(|e| e.to_string());
+            result.set(Some(checked));
+            checking.set(false);
+        });
+    };
 
-    fn mock_data() -> Vec<Self> {
-        vec![
-            SystemInfo::new(
-                "iMac".to_string(),
-                "iMac 13,2".to_string(),
-                "core i7".to_string(),
-                "15.1".to_string(),
-                "Intel Iris".to_string(),
-                "fluke, admin".to_string(),
-                "192.168.0.2".to_string(),
-                "192.168.1.2".to_string(),
-                "Test".to_string()
+    rsx!(
+        match &*result.read() {
+            Some(Ok(checked)) => rsx!(
+                LicenseStatusText {
+                    status: checked.status,
+                    expiry: checked.expiry,
+                    days_remaining: checked.days_remaining,
+                    detail: checked.error.clone(),
+                }
+                // Every license file found, when there's more than the one summarized above
+                if checked.licenses.len() > 1 {
+                    for (idx, license) in checked.licenses.iter().enumerate() {
+                        div {
+                            key: "{idx}",
+                            class: "text-sm {status_class(license.status)}",
+                            title: "{license.path.clone().unwrap_or_default()}",
+                            {format!("{}: {}", license.product, describe_status(license.status, license.expiry, license.days_remaining))}
+                        }
+                    }
+                }
             ),
-            SystemInfo::new(
-                "Macbook Pro".to_string(),
-                "Macbook Pro 16,1".to_string(),
-                "Apple silicon M1".to_string(),
-                "15".to_string(),
-                "Apple M4 Max".to_string(),
-                "abc, admin".to_string(),
-                "192.168.0.3".to_string(),
-                "192.168.1.3".to_string(),
-                "".to_string()
-            )
-        ]
-    }
+            Some(Err(e)) => rsx!(div { class: "text-sm text-red-500", {format!("[ERROR] {}", e)} }),
+            None => rsx!(),
+        }
+        button {
+            class: "px-3 py-1 bg-gray-200 rounded text-sm hover:bg-gray-300",
+            disabled: *checking.read(),
+            onclick: check,
+            if *checking.read() { "Checking..." } else { "Check license" }
+        }
+    )
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub enum MachineStatus {
-    Online,
-    Offline,
-}
+#[component]
+fn AssignMachine(person_id: String, suggested: Vec<String>, on_assigned: EventHandler<()>) -> Element {
+    let records = use_resource(|| async move { get_inventory_records().await.unwrap_or_default() });
+    let mut host = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
 
-impl ToString for MachineStatus {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Online => "Online".to_string(),
-            Self::Offline => "Offline".to_string(),
+    let assign = move |_| {
+        let person_id = person_id.clone();
+        let host = host.read().clone();
+        if host.is_empty() {
+            return;
         }
-    }
-}
+        spawn(async move {
+            match check_out_machine(host, Some(person_id), None, None).await {
+                Ok(_) => {
+                    error.set(None);
+                    on_assigned.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-pub struct Machine {
-    pub name: String,
-    pub host: String,
-    pub status: MachineStatus,
+    rsx!(
+        div {
+            class: "mb-4 flex items-center gap-2 text-sm",
+            select {
+                class: "rounded border border-gray-300 px-2 py-1",
+                onchange: move |evt: Event<FormData>| host.set(evt.value()),
+                option { value: "", "Select a machine" }
+                for candidate in suggested.iter() {
+                    option { key: "suggested-{candidate}", value: "{candidate}", {format!("{} (suggested)", candidate)} }
+                }
+                for record in records.read().iter().flatten().filter(|r| !suggested.contains(&r.host)) {
+                    option { key: "{record.host}", value: "{record.host}", {format!("{} — {}", record.host, record.info.title)} }
+                }
+            }
+            button {
+                class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600",
+                onclick: assign,
+                "Check out"
+            }
+            if let Some(e) = error.read().as_ref() {
+                span { class: "text-red-500", {e.clone()} }
+            }
+        }
+    )
 }
 
 #[component]
-pub fn User(#[props] id: String) -> Element {
-    let default_machines = vec![
-        Machine {
-            name: "Local Machine".to_string(),
-            host: "localhost".to_string(),
-            status: MachineStatus::Online,
-        },
-        Machine {
-            name: "Mac 1".to_string(),
-            host: "mac1.local".to_string(),
-            status: MachineStatus::Offline,
-        },
-        Machine {
-            name: "Mac 2".to_string(),
-            host: "mac2.local".to_string(),
-            status: MachineStatus::Online,
+fn HistoryTable(history: Vec<Assignment>) -> Element {
+    rsx!(
+        table {
+            class: "min-w-full divide-y divide-gray-300",
+            thead {
+                class: "bg-gray-50",
+                tr {
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Machine" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Serial" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Checked out" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Checked in" }
+                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Note" }
+                }
+            }
+            tbody {
+                class: "divide-y divide-gray-200 bg-white",
+                for (idx, assignment) in history.iter().enumerate() {
+                    tr {
+                        key: "{idx}",
+                        td { class: "px-6 py-4", {assignment.host.clone()} }
+                        td { class: "px-6 py-4", {assignment.serial.clone().unwrap_or_default()} }
+                        td { class: "px-6 py-4", {format_time(&assignment.checked_out_at)} }
+                        td { class: "px-6 py-4", {assignment.checked_in_at.as_ref().map(format_time).unwrap_or_else(|| "—".to_string())} }
+                        td { class: "px-6 py-4", {assignment.note.clone().unwrap_or_default()} }
+                    }
+                }
+            }
         }
-    ];
+    )
+}
 
-    let machines = use_signal(|| default_machines);
-    let mut system_info = use_signal(|| Vec::<SystemInfo>::new());
-    let mut selected = use_signal(|| None::<Machine>);
+#[component]
+pub fn User(id: ReadOnlySignal<String>) -> Element {
+    let mut overview = use_resource(move || async move { get_person_overview(id()).await });
+    let locations = use_resource(|| async move { list_locations().await.unwrap_or_default() });
+    let mut editing = use_signal(|| false);
 
-    let fetch_info = move |_| {
-        *system_info.write() = SystemInfo::mock_data();
+    let check_in = move |host: String| {
+        spawn(async move {
+            match check_in_machine(host, None).await {
+                Ok(_) => overview.restart(),
+                Err(e) => log::error!("Failed to check in machine: {}", e),
+            }
+        });
     };
 
+    let location_names: HashMap<String, String> = locations
+        .read()
+        .iter()
+        .flatten()
+        .map(|l| (l.id.clone(), l.name.clone()))
+        .collect();
+
     rsx! {
-        div { class: "container mx-auto p-4 mt-8",
-            div { class: "mb-4 flex items-center gap-4",
-                select {
-                    class: "input",
-                    onchange: move |evt: Event<FormData>| {
-                        let value = evt.data.as_ref().value().to_string();
-                        if !value.is_empty() {
-                            let found = machines.read().iter().find(|m| m.host == value).cloned();
-                            *selected.write() = found;
+        div { class: "container mx-auto p-4",
+            Link { class: "text-sm text-blue-500", to: Route::People, "← People" }
+            match &*overview.read() {
+                None => rsx!(div { class: "text-center text-gray-500", "Loading..." }),
+                Some(Err(e)) => rsx!(
+                    div {
+                        class: "text-center text-red-500",
+                        div { "Error loading person" }
+                        div { {e.to_string()} }
+                    }
+                ),
+                Some(Ok(data)) => rsx!(
+                    div {
+                        class: "mt-2 mb-6",
+                        h1 { class: "text-2xl font-bold", {data.person.name.clone()} }
+                        div {
+                            class: "text-sm text-gray-500",
+                            {[
+                                data.person.email.clone(),
+                                data.person.department.clone(),
+                                data.location.as_ref().map(|l| l.name.clone()),
+                            ].into_iter().flatten().collect::<Vec<_>>().join(" · ")}
+                        }
+                        button {
+                            class: "mt-2 px-3 py-1 bg-gray-200 rounded text-sm hover:bg-gray-300",
+                            onclick: move |_| editing.toggle(),
+                            if *editing.read() { "Close" } else { "Edit" }
                         }
-                    },
-
-                    option { value: "", "Select a machine" }
-                    {machines.read().iter().map(|machine| rsx! {
-                        option { value: "{machine.host}", "{machine.name}" }
-                    })}
-                }
-
-                button {
-                    class: "button",
-                    disabled: selected.read().is_none(),
-                    onclick: fetch_info,
-                    "Show System Info"
-                }
-            }
-
-            table { class: "min-w-full border",
-                thead {
-                    tr {
-                        th { "Type" }
-                        th { "Model" }
-                        th { "Processor" }
-                        th { "Actions" }
                     }
-                }
-                tbody {
-                    {system_info.read().iter().map(|info| rsx! {
-                        tr {
-                            td { "{info.device_type}" }
-                            td { "{info.model}" }
-                            td { "{info.processor}" }
-                            td {
-                                Link {
-                                    to: Route::User {
-                                        id: info.mac_os.clone(),
-                                    },
-                                    "View"
+                    if *editing.read() {
+                        PersonForm {
+                            person: data.person.clone(),
+                            locations: locations.read().clone().unwrap_or_default(),
+                            on_save: move |person: Person| {
+                                spawn(async move {
+                                    match save_person(person).await {
+                                        Ok(_) => {
+                                            editing.set(false);
+                                            overview.restart();
+                                        }
+                                        Err(e) => log::error!("Failed to save person: {}", e),
+                                    }
+                                });
+                            },
+                        }
+                    }
+                    h2 { class: "text-lg font-semibold mb-2", "Assigned hardware" }
+                    AssignMachine {
+                        person_id: data.person.id.clone(),
+                        suggested: data.suggested_hosts.clone(),
+                        on_assigned: move |_| overview.restart(),
+                    }
+                    div {
+                        class: "mb-8 overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        table {
+                            class: "min-w-full divide-y divide-gray-300",
+                            thead {
+                                class: "bg-gray-50",
+                                tr {
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Machine" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Product" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Serial" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Location" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Since" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "License" }
+                                    th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
+                                }
+                            }
+                            tbody {
+                                class: "divide-y divide-gray-200 bg-white",
+                                for machine in data.machines.iter() {
+                                    tr {
+                                        key: "{machine.assignment.host}",
+                                        td { class: "px-6 py-4",
+                                            div { {machine.record.as_ref().map(|r| r.info.title.clone()).unwrap_or_default()} }
+                                            div { class: "text-sm text-gray-500", {machine.assignment.host.clone()} }
+                                        }
+                                        td { class: "px-6 py-4",
+                                            div { {machine.record.as_ref().map(|r| r.info.product_name.clone()).unwrap_or_default()} }
+                                            div { class: "text-xs text-gray-500", {machine.record.as_ref().map(|r| adobe_summary(&r.info.adobe)).unwrap_or_default()} }
+                                        }
+                                        td { class: "px-6 py-4", {machine.assignment.serial.clone().unwrap_or_default()} }
+                                        td { class: "px-6 py-4",
+                                            {machine.assignment.location_id.as_ref().and_then(|id| location_names.get(id).cloned()).unwrap_or_default()}
+                                        }
+                                        td { class: "px-6 py-4", {format_time(&machine.assignment.checked_out_at)} }
+                                        td { class: "px-6 py-4", LicenseStatus { host: machine.assignment.host.clone() } }
+                                        td { class: "px-6 py-4",
+                                            button {
+                                                class: "px-3 py-1 bg-blue-500 text-white rounded text-sm hover:bg-blue-600 transition duration-300 ease-in-out",
+                                                onclick: {
+                                                    let host = machine.assignment.host.clone();
+                                                    move |_| check_in(host.clone())
+                                                },
+                                                "Check in"
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
-                    })}
-                }
+                    }
+                    h2 { class: "text-lg font-semibold mb-2", "Assignment history" }
+                    div {
+                        class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
+                        HistoryTable { history: data.history.clone() }
+                    }
+                ),
             }
         }
     }
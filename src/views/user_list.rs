@@ -1,7 +1,38 @@
 use dioxus::prelude::*;
-use crate::server::resolve_network_info;
-use crate::utils::ThemeState;
+use std::collections::{ BTreeMap, HashMap, HashSet };
+use chrono::{ DateTime, Local, Utc };
+use crate::server::{
+    resolve_network_info,
+    resolve_computer,
+    get_custom_field_columns,
+    get_inventory_records,
+    refresh_all_inventory,
+    get_refresh_progress,
+    get_coverage_warnings,
+    get_active_assignments,
+    list_people,
+    list_host_tags,
+    set_host_tags,
+    search_inventory,
+    list_saved_searches,
+    save_search,
+    delete_saved_search,
+};
+use crate::Route;
+use crate::components::export_panel::ExportPanel;
+use crate::components::import_panel::ImportPanel;
+use crate::components::license_status::describe_status;
+use crate::server::inventory::{ compose_query, InventoryRecord, RecordSource, RefreshProgress, SavedSearch };
+use crate::utils::{ ThemeState, sleep_ms };
 use crate::server::resolve_computer::ComputerInfo;
+use crate::server::collectors::{
+    CollectorResult,
+    CustomFieldColumn,
+    PowerInfo,
+    StorageDetails,
+    UserAccount,
+    format_bytes,
+};
 
 #[component]
 pub fn StatusIndicator(status: String) -> Element {
@@ -21,9 +52,450 @@ pub fn StatusIndicator(status: String) -> Element {
 }
 
 #[component]
-fn Table(networks: Signal<Vec<String>>) -> Element {
+pub fn StorageCell(storage: String, details: StorageDetails) -> Element {
+    let root = details.root_volume().cloned();
+
+    rsx!(
+        div { {storage} }
+        if let Some(root) = root {
+            div {
+                class: "text-sm text-gray-500",
+                {format!("{} free · {}", format_bytes(root.free_bytes), details.disk_type)}
+            }
+        }
+        if details.low_free_space {
+            span { class: "text-red-500 font-medium text-sm", "Low disk space" }
+        }
+    )
+}
+
+#[component]
+pub fn PowerCell(power: PowerInfo) -> Element {
+    rsx!(
+        if power.has_battery {
+            div {
+                {power.condition.clone()}
+                if let Some(cycles) = power.cycle_count {
+                    span { class: "text-sm text-gray-500", {format!(" · {} cycles", cycles)} }
+                }
+                if let Some(capacity) = power.maximum_capacity {
+                    span { class: "text-sm text-gray-500", {format!(" · {}%", capacity)} }
+                }
+            }
+        }
+        if let Some(days) = power.uptime_days() {
+            div { class: "text-sm text-gray-500", {format!("Up {} days", days)} }
+        }
+        if power.battery_service_recommended {
+            div { class: "text-red-500 font-medium text-sm", "Battery service recommended" }
+        }
+        if power.uptime_exceeded {
+            div { class: "text-red-500 font-medium text-sm", "Reboot overdue" }
+        }
+    )
+}
+
+#[component]
+pub fn AdminsSummary(accounts: Vec<UserAccount>) -> Element {
+    let admins: Vec<&str> = accounts
+        .iter()
+        .filter(|a| a.is_admin && !a.hidden)
+        .map(|a| a.name.as_str())
+        .collect();
+    let disabled = accounts.iter().filter(|a| a.disabled).count();
+
+    rsx!(
+        if !admins.is_empty() {
+            div { class: "text-sm text-gray-500", {format!("Admins: {}", admins.join(", "))} }
+        }
+        if disabled > 0 {
+            div { class: "text-sm text-gray-500", {format!("{} disabled", disabled)} }
+        }
+    )
+}
+
+#[component]
+pub fn SectionStatus(results: Vec<CollectorResult>) -> Element {
+    let failed: Vec<&CollectorResult> = results
+        .iter()
+        .filter(|r| r.status.is_failure())
+        .collect();
+    let details = failed
+        .iter()
+        .map(|r| format!("{}: {}", r.name, r.status))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    rsx!(
+        if !failed.is_empty() {
+            div {
+                class: "text-yellow-600 font-medium text-sm",
+                title: "{details}",
+                {format!("⚠ {} section(s) failed", failed.len())}
+            }
+        }
+    )
+}
+
+#[component]
+pub fn TagsCell(host: String, tags: Vec<String>) -> Element {
+    let mut current = use_signal(|| tags.clone());
+    let mut editing = use_signal(|| false);
+    let mut draft = use_signal(String::new);
+
+    let save = move |_| {
+        let host = host.clone();
+        let tags: Vec<String> = draft().split(',').map(str::to_string).collect();
+        spawn(async move {
+            match set_host_tags(host, tags).await {
+                Ok(saved) => {
+                    current.set(saved.into_iter().collect());
+                    editing.set(false);
+                }
+                Err(e) => log::error!("Failed to save tags: {}", e),
+            }
+        });
+    };
+
+    rsx!(
+        if editing() {
+            input {
+                class: "rounded border border-gray-300 px-2 py-1 text-sm",
+                placeholder: "design, print-room",
+                value: draft(),
+                oninput: move |evt| draft.set(evt.value()),
+            }
+            button { class: "ml-1 text-sm text-blue-500 hover:underline", onclick: save, "Save" }
+        } else {
+            for tag in current.read().iter() {
+                span { key: "{tag}", class: "mr-1 px-2 py-0.5 bg-gray-100 rounded text-xs", {tag.clone()} }
+            }
+            button {
+                class: "text-sm text-blue-500 hover:underline",
+                onclick: move |_| {
+                    draft.set(current.read().join(", "));
+                    editing.set(true);
+                },
+                "Edit"
+            }
+        }
+    )
+}
+
+#[component]
+pub fn RefreshAllPanel(progress: RefreshProgress, on_refresh: EventHandler<()>) -> Element {
+    let percent = (progress.completed * 100).checked_div(progress.total).unwrap_or(0);
+    let failed_hosts = progress.failed.join("\n");
+
+    rsx!(
+        div {
+            class: "mb-4 flex items-center gap-4 text-sm",
+            button {
+                class: if progress.in_progress {
+                    "py-2 px-4 bg-blue-500 text-white rounded opacity-50 cursor-not-allowed"
+                } else {
+                    "py-2 px-4 bg-blue-500 text-white rounded hover:bg-blue-600 transition duration-300 ease-in-out"
+                },
+                disabled: progress.in_progress,
+                onclick: move |_| on_refresh.call(()),
+                if progress.in_progress { "Refreshing..." } else { "Refresh all" }
+            }
+            if progress.in_progress {
+                div {
+                    class: "w-48 h-2 bg-gray-200 rounded",
+                    div { class: "h-2 bg-blue-500 rounded", style: "width: {percent}%" }
+                }
+                span { {format!("{}/{} hosts", progress.completed, progress.total)} }
+            } else if let Some(finished) = progress.finished_at {
+                span {
+                    class: "text-gray-500",
+                    {format!("Last refresh finished {}", format_refresh_time(&finished))}
+                }
+            }
+            if !progress.failed.is_empty() {
+                span {
+                    class: "text-red-500",
+                    title: "{failed_hosts}",
+                    {format!("{} failed", progress.failed.len())}
+                }
+            }
+            if let Some(next) = progress.next_scheduled_run {
+                span { class: "text-gray-500", {format!("Next scheduled: {}", format_refresh_time(&next))} }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn SearchPanel(
+    text: Signal<String>,
+    query: String,
+    error: Option<String>,
+    summary: Option<String>,
+    on_search: EventHandler<String>,
+    on_load: EventHandler<String>
+) -> Element {
+    let mut saved = use_resource(|| async move { list_saved_searches().await.unwrap_or_default() });
+    let mut name = use_signal(String::new);
+    let mut save_error = use_signal(|| None::<String>);
+
+    let save_current = {
+        let query = query.clone();
+        move |_| {
+            let search = SavedSearch { id: String::new(), name: name(), query: query.clone() };
+            spawn(async move {
+                match save_search(search).await {
+                    Ok(_) => {
+                        name.set(String::new());
+                        save_error.set(None);
+                        saved.restart();
+                    }
+                    Err(e) => save_error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    rsx!(
+        div {
+            class: "mb-4 text-sm",
+            div {
+                class: "flex items-center gap-2",
+                input {
+                    class: "flex-1 rounded border border-gray-300 px-2 py-1 font-mono",
+                    placeholder: "Search, e.g. beam or version < 14 AND tag == design",
+                    value: text(),
+                    oninput: move |evt| text.set(evt.value()),
+                    onkeydown: move |evt: Event<KeyboardData>| {
+                        if evt.key() == Key::Enter {
+                            on_search.call(text());
+                        }
+                    },
+                }
+                button {
+                    class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600",
+                    onclick: move |_| on_search.call(text()),
+                    "Search"
+                }
+                button {
+                    class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300",
+                    onclick: move |_| {
+                        text.set(String::new());
+                        on_load.call(String::new());
+                    },
+                    "Clear"
+                }
+            }
+            if let Some(e) = error {
+                div { class: "mt-1 text-red-500", {e} }
+            } else if let Some(summary) = summary {
+                div { class: "mt-1 text-gray-500", {summary} }
+            }
+            div {
+                class: "mt-2 flex flex-wrap items-center gap-2",
+                span { class: "font-semibold", "Saved searches:" }
+                for search in saved.read().iter().flatten() {
+                    span {
+                        key: "{search.id}",
+                        class: "px-2 py-0.5 bg-gray-100 rounded",
+                        button {
+                            class: "text-blue-500 hover:underline",
+                            title: "{search.query}",
+                            onclick: {
+                                let query = search.query.clone();
+                                move |_| {
+                                    text.set(query.clone());
+                                    on_load.call(query.clone());
+                                }
+                            },
+                            {search.name.clone()}
+                        }
+                        button {
+                            class: "ml-1 text-gray-400 hover:text-red-500",
+                            onclick: {
+                                let id = search.id.clone();
+                                move |_| {
+                                    let id = id.clone();
+                                    spawn(async move {
+                                        match delete_saved_search(id).await {
+                                            Ok(_) => saved.restart(),
+                                            Err(e) => save_error.set(Some(e.to_string())),
+                                        }
+                                    });
+                                }
+                            },
+                            "×"
+                        }
+                    }
+                }
+                if !query.is_empty() {
+                    input {
+                        class: "rounded border border-gray-300 px-2 py-0.5",
+                        placeholder: "Name this search",
+                        value: name(),
+                        oninput: move |evt| name.set(evt.value()),
+                    }
+                    button { class: "text-blue-500 hover:underline", onclick: save_current, "Save" }
+                }
+                if let Some(e) = save_error.read().as_ref() {
+                    span { class: "text-red-500", {e.clone()} }
+                }
+            }
+        }
+    )
+}
+
+// Column filter under a table header; applied when the input changes
+#[component]
+fn FilterCell(field: String, filters: Signal<BTreeMap<String, String>>, on_apply: EventHandler<()>) -> Element {
+    let value = filters.read().get(&field).cloned().unwrap_or_default();
+
+    rsx!(
+        input {
+            class: "w-full rounded border border-gray-300 px-1 py-0.5 text-sm font-normal",
+            placeholder: "Filter",
+            value: value,
+            onchange: move |evt: Event<FormData>| {
+                let value = evt.value();
+                filters.with_mut(|f| {
+                    if value.trim().is_empty() {
+                        f.remove(&field);
+                    } else {
+                        f.insert(field.clone(), value);
+                    }
+                });
+                on_apply.call(());
+            },
+        }
+    )
+}
+
+fn format_refresh_time(time: &DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[component]
+fn Table(networks: Signal<Vec<String>>, query: ReadOnlySignal<String>) -> Element {
     let computer_info = use_signal(|| std::collections::HashMap::<String, ComputerInfo>::new());
     let fetching = use_signal(|| std::collections::HashSet::<String>::new());
+    let mut resolve_errors = use_signal(std::collections::HashMap::<String, String>::new);
+    let custom_columns = use_resource(|| async move {
+        get_custom_field_columns().await.unwrap_or_default()
+    });
+    let mut visible_fields = use_signal(std::collections::BTreeSet::<String>::new);
+    let mut last_refresh = use_signal(HashMap::<String, DateTime<Utc>>::new);
+    let mut refresh_progress = use_signal(RefreshProgress::default);
+    let owners = use_resource(|| async move {
+        let people = list_people().await.unwrap_or_default();
+        get_active_assignments()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|assignment| {
+                let person = people.iter().find(|p| Some(&p.id) == assignment.person_id.as_ref())?;
+                Some((assignment.host, person.clone()))
+            })
+            .collect::<HashMap<_, _>>()
+    });
+    let host_tags = use_resource(|| async move { list_host_tags().await.unwrap_or_default() });
+    let coverage_warnings = use_resource(|| async move {
+        get_coverage_warnings().await.unwrap_or_default()
+    });
+
+    // The applied query lives in the URL so searches can be bookmarked and shared
+    let navigator = navigator();
+    let search_text = use_signal(|| query.cloned());
+    let mut column_filters = use_signal(BTreeMap::<String, String>::new);
+    let search_results = use_resource(move || async move {
+        let query = query();
+        if query.trim().is_empty() {
+            return None;
+        }
+        Some(search_inventory(query).await.map_err(|e| e.to_string()))
+    });
+    let apply_search = move |text: String| {
+        let q = compose_query(&text, &column_filters.read());
+        navigator.replace(Route::UserList { q });
+    };
+    let matching: Option<HashSet<String>> = match &*search_results.read() {
+        Some(Some(Ok(hosts))) => Some(hosts.iter().cloned().collect()),
+        _ => None,
+    };
+    let search_error = match &*search_results.read() {
+        Some(Some(Err(e))) => Some(e.clone()),
+        _ => None,
+    };
+
+    // Show what the inventory already knows, and refresh it once a batch run finishes
+    let mut sources = use_signal(HashMap::<String, RecordSource>::new);
+    let mut load_inventory = move |records: Vec<InventoryRecord>| {
+        for record in records {
+            sources.with_mut(|map| {
+                map.insert(record.host.clone(), record.source);
+            });
+            if let Some(resolved) = record.last_resolved {
+                last_refresh.with_mut(|map| {
+                    map.insert(record.host.clone(), resolved);
+                });
+            }
+            computer_info.clone().with_mut(|map| {
+                map.insert(record.host, record.info);
+            });
+        }
+    };
+
+    let poll_progress = move || {
+        spawn(async move {
+            loop {
+                match get_refresh_progress().await {
+                    Ok(progress) => {
+                        let done = !progress.in_progress;
+                        refresh_progress.set(progress);
+                        if done {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Failed to get refresh progress: {}", e);
+                        break;
+                    }
+                }
+                sleep_ms(1000).await;
+            }
+            if let Ok(records) = get_inventory_records().await {
+                load_inventory(records);
+            }
+        });
+    };
+
+    use_hook(poll_progress);
+
+    let reload_inventory = move |_| {
+        spawn(async move {
+            if let Ok(records) = get_inventory_records().await {
+                load_inventory(records);
+            }
+        });
+    };
+
+    let refresh_all = move |_| {
+        spawn(async move {
+            match refresh_all_inventory().await {
+                Ok(progress) => {
+                    refresh_progress.set(progress);
+                    poll_progress();
+                }
+                Err(e) => log::error!("Failed to start inventory refresh: {}", e),
+            }
+        });
+    };
+
+    let all_columns: Vec<CustomFieldColumn> = custom_columns.read().clone().unwrap_or_default();
+    let shown_columns: Vec<CustomFieldColumn> = all_columns
+        .iter()
+        .filter(|column| visible_fields.read().contains(&column.field))
+        .cloned()
+        .collect();
 
     let get_info = move |host: String| {
         let computer_info = computer_info.clone();
@@ -34,14 +506,23 @@ fn Table(networks: Signal<Vec<String>>) -> Element {
         });
 
         spawn(async move {
-            match ComputerInfo::resolve(host.clone()).await {
+            match resolve_computer(host.clone()).await {
                 Ok(info) => {
+                    resolve_errors.with_mut(|errors| {
+                        errors.remove(&host);
+                    });
                     computer_info.clone().with_mut(|map| {
                         map.insert(host.clone(), info);
                     });
+                    last_refresh.with_mut(|map| {
+                        map.insert(host.clone(), Utc::now());
+                    });
                 }
                 Err(e) => {
                     log::error!("Failed to get computer info: {}", e);
+                    resolve_errors.with_mut(|errors| {
+                        errors.insert(host.clone(), e.to_string());
+                    });
                 }
             }
             fetching.clone().with_mut(|f| {
@@ -50,26 +531,111 @@ fn Table(networks: Signal<Vec<String>>) -> Element {
         });
     };
 
-    let networks_data = networks.read();
-    let iter_data: Vec<_> = networks_data.iter().enumerate().collect();
-    let rows = iter_data.into_iter().map(|(idx, host)| {
-        let host = host.to_string();
+    // Scanned hosts first, then machines only known from the inventory
+    let online_hosts = networks.read().clone();
+    let mut offline_hosts: Vec<String> = computer_info
+        .read()
+        .keys()
+        .filter(|host| !online_hosts.contains(host))
+        .cloned()
+        .collect();
+    offline_hosts.sort();
+    let all_hosts: Vec<(String, bool)> = online_hosts
+        .iter()
+        .map(|host| (host.clone(), true))
+        .chain(offline_hosts.into_iter().map(|host| (host, false)))
+        .filter(|(host, _)| matching.as_ref().is_none_or(|m| m.contains(host)))
+        .collect();
+    let search_summary = matching
+        .as_ref()
+        .map(|_| format!("{} host(s) match", all_hosts.len()));
+    let rows = all_hosts.into_iter().map(|(host, online)| {
         let host_ref = host.clone(); // Clone for disabled check
         rsx!(
             tr { 
-                key: {idx},
+                key: "{host}",
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.title.as_str()).unwrap_or_default()} }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.product_name.as_str()).unwrap_or_default()} }
-                td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.serial.as_str()).unwrap_or_default()} }
+                td { class: "px-6 py-4",
+                    if let Some(i) = computer_info.read().get(&host) {
+                        div { {i.serial.clone()} }
+                        for warning in coverage_warnings.read().iter().flatten().filter(|w| w.serial == i.serial) {
+                            div {
+                                class: "text-red-500 font-medium text-sm",
+                                title: "{describe_status(warning.status, Some(warning.expires), Some(warning.days_remaining))}",
+                                {format!("{} expires {}", warning.coverage, warning.expires)}
+                            }
+                        }
+                    }
+                }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.version.as_str()).unwrap_or_default()} }
-                td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.user.as_str()).unwrap_or_default()} }
-                td { class: "px-6 py-4", {host.clone()} }
+                td { class: "px-6 py-4",
+                    if let Some(i) = computer_info.read().get(&host) {
+                        div { {i.user.clone()} }
+                        AdminsSummary { accounts: i.user_accounts.clone() }
+                    }
+                    if let Some(person) = owners.read().as_ref().and_then(|o| o.get(&host)) {
+                        Link {
+                            class: "text-sm text-blue-500 hover:underline",
+                            to: Route::User { id: person.id.clone() },
+                            {format!("Assigned to {}", person.name)}
+                        }
+                    }
+                }
+                td { class: "px-6 py-4",
+                    div { {host.clone()} }
+                    if let Some(i) = computer_info.read().get(&host) {
+                        if !i.lan_ip.is_empty() {
+                            div { class: "text-sm text-gray-500", {format!("LAN {}", i.lan_ip)} }
+                        }
+                        if !i.wifi_ip.is_empty() {
+                            div { class: "text-sm text-gray-500", {format!("Wi-Fi {} ({})", i.wifi_ip, i.wifi_name)} }
+                        }
+                    }
+                }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.processor.as_str()).unwrap_or_default()} }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.architecture.as_str()).unwrap_or_default()} }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.memory.as_str()).unwrap_or_default()} }
                 td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.graphics.as_str()).unwrap_or_default()} }
-                td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.storage.as_str()).unwrap_or_default()} }
-                td { class: "px-6 py-4 text-center", StatusIndicator { status: "Online".to_string() } }
+                td { class: "px-6 py-4",
+                    if let Some(i) = computer_info.read().get(&host) {
+                        StorageCell { storage: i.storage.clone(), details: i.storage_details.clone() }
+                    }
+                }
+                td { class: "px-6 py-4",
+                    if let Some(i) = computer_info.read().get(&host) {
+                        PowerCell { power: i.power.clone() }
+                    }
+                }
+                td { class: "px-6 py-4", {computer_info.read().get(&host).map(|i| i.software_updates.len().to_string()).unwrap_or_default()} }
+                for column in shown_columns.iter() {
+                    td { class: "px-6 py-4",
+                        {computer_info.read().get(&host).and_then(|i| i.custom_fields.get(&column.field).cloned()).unwrap_or_default()}
+                    }
+                }
+                td { class: "px-6 py-4",
+                    if let Some(tags) = host_tags.read().as_ref() {
+                        TagsCell {
+                            host: host.clone(),
+                            tags: tags.get(&host).map(|t| t.iter().cloned().collect()).unwrap_or_default(),
+                        }
+                    }
+                }
+                td { class: "px-6 py-4 text-sm text-gray-500",
+                    {last_refresh.read().get(&host).map(format_refresh_time).unwrap_or_else(|| "Never".to_string())}
+                }
+                td { class: "px-6 py-4 text-center",
+                    StatusIndicator { status: if online { "Online".to_string() } else { "Offline".to_string() } }
+                    if sources.read().get(&host) == Some(&RecordSource::Manual) {
+                        div { class: "text-gray-500 text-sm", "Manual" }
+                    }
+                    if let Some(i) = computer_info.read().get(&host) {
+                        SectionStatus { results: i.collector_results.clone() }
+                    }
+                    if let Some(error) = resolve_errors.read().get(&host) {
+                        div { class: "text-red-500 text-sm", title: "{error}", "Resolve failed" }
+                    }
+                }
                 td { class: "px-6 py-4",
                     button {
                         class: if fetching.read().contains(&host_ref) {
@@ -87,6 +653,51 @@ fn Table(networks: Signal<Vec<String>>) -> Element {
     });
 
     rsx!(
+        RefreshAllPanel { progress: refresh_progress.read().clone(), on_refresh: refresh_all }
+        SearchPanel {
+            text: search_text,
+            query: query(),
+            error: search_error,
+            summary: search_summary,
+            on_search: apply_search,
+            // Saved searches and Clear replace the whole query, column filters included
+            on_load: move |text: String| {
+                column_filters.set(BTreeMap::new());
+                apply_search(text);
+            },
+        }
+        ExportPanel {}
+        ImportPanel { on_imported: reload_inventory }
+        if !all_columns.is_empty() {
+            div {
+                class: "mb-4 flex items-center gap-4 text-sm",
+                span { class: "font-semibold", "Optional columns:" }
+                for column in all_columns.iter() {
+                    label {
+                        key: "{column.field}",
+                        class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: visible_fields.read().contains(&column.field),
+                            onchange: {
+                                let field = column.field.clone();
+                                move |evt: Event<FormData>| {
+                                    let checked = evt.checked();
+                                    visible_fields.with_mut(|fields| {
+                                        if checked {
+                                            fields.insert(field.clone());
+                                        } else {
+                                            fields.remove(&field);
+                                        }
+                                    });
+                                }
+                            },
+                        }
+                        {column.label.clone()}
+                    }
+                }
+            }
+        }
         div { 
             class: "overflow-hidden shadow ring-1 ring-black ring-opacity-5 sm:rounded-lg",
             table { 
@@ -105,9 +716,39 @@ fn Table(networks: Signal<Vec<String>>) -> Element {
                         th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "RAM" }
                         th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "GPU" }
                         th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Storage" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Power" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Updates" }
+                        for column in shown_columns.iter() {
+                            th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", {column.label.clone()} }
+                        }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Tags" }
+                        th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Last Refresh" }
                         th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Status" }
                         th { class: "py-3.5 px-3 text-left text-sm font-semibold text-gray-900", "Action" }
                     }
+                    tr {
+                        for field in ["title", "product_name", "serial", "version", "user", "host", "processor", "architecture", "memory", "graphics", "storage", "battery_condition"] {
+                            th { key: "{field}", class: "px-3 pb-2",
+                                FilterCell { field: field.to_string(), filters: column_filters, on_apply: move |_| apply_search(search_text()) }
+                            }
+                        }
+                        th {}
+                        for column in shown_columns.iter() {
+                            th { key: "custom-{column.field}", class: "px-3 pb-2",
+                                FilterCell {
+                                    field: format!("custom.{}", column.field),
+                                    filters: column_filters,
+                                    on_apply: move |_| apply_search(search_text()),
+                                }
+                            }
+                        }
+                        th { class: "px-3 pb-2",
+                            FilterCell { field: "tag".to_string(), filters: column_filters, on_apply: move |_| apply_search(search_text()) }
+                        }
+                        th {}
+                        th {}
+                        th {}
+                    }
                 }
                 tbody { 
                     class: "divide-y divide-gray-200 bg-white",
@@ -119,18 +760,18 @@ fn Table(networks: Signal<Vec<String>>) -> Element {
 }
 
 #[component]
-fn MainView(hosts: Vec<String>) -> Element {
+fn MainView(hosts: Vec<String>, query: ReadOnlySignal<String>) -> Element {
     let hosts = use_signal(|| hosts);
 
     rsx!(
         div {
-            Table { networks: hosts }
+            Table { networks: hosts, query }
         }
     )
 }
 
 #[component]
-pub fn UserList() -> Element {
+pub fn UserList(q: ReadOnlySignal<String>) -> Element {
     let theme = use_signal(|| ThemeState::default());
     let network_info = use_signal(Vec::<String>::new);
     let scan_error = use_signal(|| None::<String>);
@@ -205,7 +846,7 @@ pub fn UserList() -> Element {
                         }
                     )
                 } else {
-                    rsx!(MainView { hosts: hosts.to_vec() })
+                    rsx!(MainView { hosts: hosts.to_vec(), query: q })
                 }
             }
         }